@@ -0,0 +1,143 @@
+use std::env;
+use std::io::{Error, Result};
+use std::sync::{Arc, RwLock};
+
+use clap::Subcommand;
+
+use crate::{
+    git::{
+        branch_name, checkout_branch, commit_message, current_branch,
+        issue_key_from_branch,
+    },
+    jira::{tasks::data::JiraIssue, utils::helpers::open_in_browser},
+    request_client::client::RequestClient,
+    Config,
+};
+
+/// Commands which are run without the interface.
+#[derive(Subcommand)]
+pub enum Command {
+    /// Creates or checks out git branch of the issue
+    /// in the current repository.
+    Branch {
+        /// Issue key, e.g. `TST-1`.
+        issue_key: String,
+    },
+    /// Shows the issue of the current git branch.
+    Issue {
+        /// Opens the issue in the browser.
+        #[arg(long)]
+        open: bool,
+    },
+    /// Prints commit message for the issue of the current git branch.
+    CommitMsg,
+}
+
+/// Runs the command with the company from `--company`,
+/// the last opened company or the only one in the config.
+pub fn run_command(
+    command: &Command,
+    config: &Config,
+    company_name: Option<&str>,
+) -> Result<()> {
+    let client = make_client(config, company_name)?;
+    match command {
+        Command::Branch { issue_key } => {
+            let issue = load_issue(&client, issue_key)?;
+            let branch = branch_name(
+                config.get_branch_template(),
+                &issue.key,
+                &issue.summary,
+                issue_type_name(&issue),
+            );
+            let is_created = checkout_branch(&env::current_dir()?, &branch)?;
+            if is_created {
+                println!("Switched to a new branch `{branch}`");
+            } else {
+                println!("Switched to branch `{branch}`");
+            }
+        }
+        Command::Issue { open } => {
+            let issue = load_issue(&client, &branch_issue_key()?)?;
+            let browse_url =
+                client.read().unwrap().browse_url(&issue.key).to_string();
+            if *open {
+                open_in_browser(&browse_url)?;
+                return Ok(());
+            }
+            let assignee = issue
+                .assignee
+                .as_ref()
+                .map(|assignee| assignee.display_name.as_str())
+                .unwrap_or("Unassigned");
+            println!("{} {}", issue.key, issue.summary);
+            println!("Status: {}", issue.status.name);
+            println!("Assignee: {}", assignee);
+            println!("{}", browse_url);
+        }
+        Command::CommitMsg => {
+            let issue = load_issue(&client, &branch_issue_key()?)?;
+            println!(
+                "{}",
+                commit_message(
+                    config.get_commit_template(),
+                    &issue.key,
+                    &issue.summary,
+                    issue_type_name(&issue),
+                )
+            );
+        }
+    }
+    Ok(())
+}
+
+fn make_client(
+    config: &Config,
+    company_name: Option<&str>,
+) -> Result<Arc<RwLock<RequestClient>>> {
+    let companies_names = config.companies_names();
+    let company_name = match (company_name, config.get_last_company()) {
+        (Some(company_name), _) | (None, Some(company_name)) => company_name,
+        (None, None) if companies_names.len() == 1 => &companies_names[0],
+        (None, None) => {
+            return Err(Error::other(
+                "Can't choose company, pass it with `--company`",
+            ))
+        }
+    };
+    let jira = config.get_jira_by_company(company_name).map_err(|_| {
+        Error::other(format!("Can't find company `{company_name}`"))
+    })?;
+    let mut client = RequestClient::new(
+        jira.get_encoded_creds().into(),
+        jira.get_url(),
+        jira.get_api_version(),
+    );
+    client.detect_deployment_type();
+    Ok(Arc::new(RwLock::new(client)))
+}
+
+fn load_issue(
+    client: &Arc<RwLock<RequestClient>>,
+    issue_key: &str,
+) -> Result<JiraIssue> {
+    JiraIssue::new(client.clone(), &issue_key.to_uppercase()).map_err(|err| {
+        Error::other(format!("Can't load issue {issue_key}: {err}"))
+    })
+}
+
+/// Returns key of the issue from the current git branch.
+fn branch_issue_key() -> Result<String> {
+    let branch = current_branch(&env::current_dir()?)?;
+    issue_key_from_branch(&branch).ok_or_else(|| {
+        Error::other(format!("Can't find issue key in branch `{branch}`"))
+    })
+}
+
+fn issue_type_name(issue: &JiraIssue) -> &str {
+    issue
+        .issue_type
+        .as_ref()
+        .map(|issue_type| issue_type.name.as_str())
+        .unwrap_or_default()
+}
@@ -1,15 +1,62 @@
 use crate::constance::*;
+use crate::git::{DEFAULT_BRANCH_TEMPLATE, DEFAULT_COMMIT_TEMPLATE};
+use crate::jira::filters::data::JqlFilter;
+use crate::keymap::Keymap;
+use crate::logger::LogLevel;
+use crate::theme::DEFAULT_THEME;
 use crate::utils::*;
 use home::home_dir;
 use serde::{Deserialize, Serialize};
 use std::io::{Error, ErrorKind, Result};
+use std::str::FromStr;
 extern crate base64;
 
+/// Version of Jira REST API.
+///
+/// Version 2 is used when company config doesn't specify it.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default,
+)]
+pub enum ApiVersion {
+    #[default]
+    #[serde(rename = "2")]
+    V2,
+    /// Rich text fields are Atlassian Document Format documents.
+    #[serde(rename = "3")]
+    V3,
+}
+
+impl ApiVersion {
+    /// Returns version as it's used in urls of the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V2 => "2",
+            Self::V3 => "3",
+        }
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = Error;
+
+    fn from_str(version: &str) -> Result<Self> {
+        match version.trim() {
+            "" | "2" => Ok(Self::V2),
+            "3" => Ok(Self::V3),
+            _ => Err(Error::other(format!(
+                "Unsupported Jira API version `{version}`, use 2 or 3"
+            ))),
+        }
+    }
+}
+
 /// Structure for main information about a jira company.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Jira {
     url: String,
     encoded_creds: String,
+    #[serde(default)]
+    api_version: ApiVersion,
 }
 
 impl Jira {
@@ -21,18 +68,33 @@ impl Jira {
     ///     "url_to_jira".to_string(),
     ///     "username".to_string(),
     ///     "password".to_string(),
+    ///     ApiVersion::V3,
     /// );
     /// ```
-    fn new(url: String, username: String, password: String) -> Self {
+    fn new(
+        url: String,
+        username: String,
+        password: String,
+        api_version: ApiVersion,
+    ) -> Self {
         let encoded_creds =
             base64::encode(format!("{}:{}", username, password));
-        Jira { url, encoded_creds }
+        Jira {
+            url,
+            encoded_creds,
+            api_version,
+        }
     }
 
     pub fn get_url(&self) -> &str {
         self.url.as_str()
     }
 
+    /// Returns version of Jira REST API.
+    pub fn get_api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
     pub fn get_encoded_creds(&self) -> &str {
         self.encoded_creds.as_str()
     }
@@ -43,11 +105,18 @@ impl Jira {
 struct Company {
     company_name: String,
     jira: Jira,
+    /// JQL queries saved in the app, shown in the menu.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    filters: Vec<JqlFilter>,
 }
 
 impl Company {
     fn new(company_name: String, jira: Jira) -> Self {
-        Company { company_name, jira }
+        Company {
+            company_name,
+            jira,
+            filters: Vec::new(),
+        }
     }
 }
 
@@ -55,12 +124,39 @@ impl Company {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Config {
     companies: Vec<Company>,
+    /// Key bindings of the Jira screen, action name to key chord.
+    #[serde(default)]
+    keymap: Keymap,
+    /// Name of the built-in or user theme.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    theme: Option<String>,
+    /// Name of the last opened company.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_company: Option<String>,
+    /// Opens the last company right away instead of the start screen.
+    #[serde(default)]
+    skip_start_screen: bool,
+    /// Template of git branch names, e.g. `{type}/{key}-{summary}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    branch_template: Option<String>,
+    /// Template of commit messages, e.g. `{key}: {summary}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    commit_template: Option<String>,
+    /// Minutes between checks for updated issues, disabled if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auto_refresh_minutes: Option<u64>,
+    /// Level of the log records, logging is disabled if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    log_level: Option<LogLevel>,
     #[serde(skip_serializing, skip_deserializing)]
     config_path: String,
 }
 
 impl Config {
     /// Creates new instance of Config.
+    ///
+    /// Returns error if the config can't be parsed
+    /// or keys in the keymap conflict with each other.
     /// Basic usage:
     ///
     /// ```
@@ -71,13 +167,13 @@ impl Config {
         let config_path = Self::get_config_path()?;
         match serde_json::from_str::<Config>(&app_config) {
             Ok(mut config) => {
+                config.keymap.validate()?;
                 config.config_path = config_path;
                 Ok(config)
             }
-            Err(_) => Err(Error::new(
-                ErrorKind::Other,
-                "Can't read config! Is it correct?",
-            )),
+            Err(err) => Err(Error::other(format!(
+                "Can't read config! Is it correct? {err}"
+            ))),
         }
     }
 
@@ -94,17 +190,22 @@ impl Config {
     /// Creates new instance of `Jira` and `Company`
     /// structures.
     /// Then serialize our new config to json and write it in file.
+    ///
+    /// Returns error if `api_version` isn't `2` or `3`,
+    /// empty version means `2`.
     pub fn add_new_company(
         &mut self,
         url: &str,
         company_name: &str,
         username: &str,
         password: &str,
+        api_version: &str,
     ) -> Result<()> {
         let jira_data = Jira::new(
             url.to_string(),
             username.to_string(),
             password.to_string(),
+            api_version.parse()?,
         );
         let company_data = Company::new(company_name.to_string(), jira_data);
         self.companies.push(company_data);
@@ -114,25 +215,119 @@ impl Config {
     /// Deletes exist company.
     pub fn delete_company(&mut self, company_name: &str) -> Result<()> {
         let mut to_remove_company_idx = usize::default();
-        let mut idx = 0;
-        for company in &self.companies {
+        for (idx, company) in self.companies.iter().enumerate() {
             if company.company_name == company_name {
                 to_remove_company_idx = idx;
             }
-            idx += 1;
         }
 
         self.companies.remove(to_remove_company_idx);
         std::fs::write(&self.config_path, serde_json::to_string_pretty(&self)?)
     }
 
+    /// Returns key bindings from the config.
+    pub fn get_keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    /// Returns name of the chosen theme.
+    pub fn get_theme(&self) -> &str {
+        self.theme.as_deref().unwrap_or(DEFAULT_THEME)
+    }
+
+    /// Remembers chosen theme in the config.
+    pub fn set_theme(&mut self, theme_name: &str) -> Result<()> {
+        self.theme = Some(theme_name.into());
+        std::fs::write(&self.config_path, serde_json::to_string_pretty(&self)?)
+    }
+
+    /// Returns name of the last opened company if it still exists.
+    pub fn get_last_company(&self) -> Option<&str> {
+        let last_company = self.last_company.as_deref()?;
+        self.get_jira_by_company(last_company).ok()?;
+        Some(last_company)
+    }
+
+    /// Remembers opened company in the config.
+    pub fn set_last_company(&mut self, company_name: &str) -> Result<()> {
+        self.last_company = Some(company_name.into());
+        std::fs::write(&self.config_path, serde_json::to_string_pretty(&self)?)
+    }
+
+    /// Returns company to open at startup instead of the start screen.
+    pub fn get_startup_company(&self) -> Option<&str> {
+        if self.skip_start_screen {
+            self.get_last_company()
+        } else {
+            None
+        }
+    }
+
+    /// Returns JQL filters saved for the company.
+    pub fn get_saved_filters(&self, company_name: &str) -> &[JqlFilter] {
+        self.companies
+            .iter()
+            .find(|company| company.company_name == company_name)
+            .map(|company| company.filters.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Saves JQL filter of the company,
+    /// filter with the same name is replaced.
+    pub fn save_filter(
+        &mut self,
+        company_name: &str,
+        filter: JqlFilter,
+    ) -> Result<()> {
+        let company = self
+            .companies
+            .iter_mut()
+            .find(|company| company.company_name == company_name)
+            .ok_or_else(|| Error::other("Not found!"))?;
+        match company
+            .filters
+            .iter_mut()
+            .find(|saved_filter| saved_filter.name == filter.name)
+        {
+            Some(saved_filter) => *saved_filter = filter,
+            None => company.filters.push(filter),
+        }
+        std::fs::write(&self.config_path, serde_json::to_string_pretty(&self)?)
+    }
+
+    /// Returns template of git branch names.
+    pub fn get_branch_template(&self) -> &str {
+        self.branch_template
+            .as_deref()
+            .unwrap_or(DEFAULT_BRANCH_TEMPLATE)
+    }
+
+    /// Returns template of commit messages.
+    pub fn get_commit_template(&self) -> &str {
+        self.commit_template
+            .as_deref()
+            .unwrap_or(DEFAULT_COMMIT_TEMPLATE)
+    }
+
+    /// Returns minutes between checks for updated issues.
+    ///
+    /// Returns `None` if auto refresh is disabled.
+    pub fn get_auto_refresh_minutes(&self) -> Option<u64> {
+        self.auto_refresh_minutes.filter(|minutes| *minutes > 0)
+    }
+
+    /// Returns level of the log records, `None` if logging is disabled.
+    pub fn get_log_level(&self) -> Option<LogLevel> {
+        self.log_level
+    }
+
     pub fn get_jira_by_company(&self, company_name: &str) -> Result<&Jira> {
         for company in &self.companies {
             if company.company_name == company_name {
                 return Ok(&company.jira);
             }
         }
-        Err(Error::new(ErrorKind::Other, "Not found!"))
+        Err(Error::other("Not found!"))
     }
 
     fn get_config_path() -> Result<String> {
@@ -1,2 +1,5 @@
 pub const APP_DIRECTORY: &str = ".rusji";
 pub const APP_CONFIG: &str = "rusji_config.json";
+pub const APP_STATE_DIRECTORY: &str = "state";
+pub const APP_THEMES_DIRECTORY: &str = "themes";
+pub const APP_LOGS_DIRECTORY: &str = "logs";
@@ -1,4 +1,4 @@
-use std::result;
+use std::{io, result};
 
 use reqwest::Error as reqError;
 use serde_json::Error as serdError;
@@ -11,6 +11,15 @@ pub enum RusjiError {
 
     #[error("Can't serialize incoming data")]
     SerializeError(#[from] serdError),
+
+    #[error("Jira responded with status {0}")]
+    BadResponseStatus(u16),
+
+    #[error("Project of the issue {0} isn't available")]
+    UnknownProject(String),
+
+    #[error("Can't access the file")]
+    FileError(#[from] io::Error),
 }
 
 pub type RusjiResult<T> = result::Result<T, RusjiError>;
@@ -0,0 +1,187 @@
+use std::io::{Error, Result};
+use std::path::Path;
+use std::process::Command;
+
+use crate::utils::find_issue_key;
+
+/// Template of the branch name used if the config doesn't set one.
+pub const DEFAULT_BRANCH_TEMPLATE: &str = "{key}-{summary}";
+
+/// Template of the commit message used if the config doesn't set one.
+pub const DEFAULT_COMMIT_TEMPLATE: &str = "{key}: {summary}";
+
+/// Max amount of chars of the summary in the branch name.
+const BRANCH_SUMMARY_LIMIT: usize = 40;
+
+/// Converts text to lowercase words joined with `-`,
+/// e.g. `Fix login page!` to `fix-login-page`.
+///
+/// Letters and digits of any language are kept,
+/// other chars split words.
+pub fn slugify(text: &str) -> String {
+    text.split(|char: char| !char.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+/// Cuts the slug to the limit of chars, the last word isn't split.
+fn truncate_slug(slug: &str, limit: usize) -> &str {
+    if slug.chars().count() <= limit {
+        return slug;
+    }
+    let end = slug
+        .char_indices()
+        .nth(limit)
+        .map(|(idx, _)| idx)
+        .unwrap_or(slug.len());
+    let cut_slug = &slug[..end];
+    if slug[end..].starts_with('-') {
+        return cut_slug;
+    }
+    match cut_slug.rfind('-') {
+        Some(word_end) => &cut_slug[..word_end],
+        None => cut_slug,
+    }
+}
+
+/// Fills placeholders of the template: `{key}`, `{summary}` and `{type}`.
+fn fill_template(
+    template: &str,
+    issue_key: &str,
+    summary: &str,
+    issue_type: &str,
+) -> String {
+    template
+        .replace("{key}", issue_key)
+        .replace("{summary}", summary)
+        .replace("{type}", issue_type)
+}
+
+/// Returns name of the branch for the issue by the template.
+///
+/// Summary and issue type are slugified, so `{type}/{key}-{summary}`
+/// gives `bug/TST-1-fix-login-page`.
+pub fn branch_name(
+    template: &str,
+    issue_key: &str,
+    summary: &str,
+    issue_type: &str,
+) -> String {
+    let summary = slugify(summary);
+    fill_template(
+        template,
+        issue_key,
+        truncate_slug(&summary, BRANCH_SUMMARY_LIMIT),
+        &slugify(issue_type),
+    )
+}
+
+/// Returns commit message for the issue by the template.
+pub fn commit_message(
+    template: &str,
+    issue_key: &str,
+    summary: &str,
+    issue_type: &str,
+) -> String {
+    fill_template(template, issue_key, summary, issue_type)
+}
+
+/// Finds issue key in the branch name, e.g. `TST-12` in
+/// `feature/TST-12-fix-login`.
+pub fn issue_key_from_branch(branch: &str) -> Option<String> {
+    find_issue_key(branch).map(|range| branch[range].to_string())
+}
+
+/// Runs git in the directory and returns its output.
+///
+/// Error has the message printed by git.
+fn run_git(repo_dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_dir)
+        .output()?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr);
+        return Err(Error::other(message.trim().to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Returns name of the checked out branch.
+pub fn current_branch(repo_dir: &Path) -> Result<String> {
+    run_git(repo_dir, &["rev-parse", "--abbrev-ref", "HEAD"])
+}
+
+/// Checks out the branch, it's created from `HEAD` if it doesn't exist.
+///
+/// Returns true if the branch was created.
+pub fn checkout_branch(repo_dir: &Path, branch: &str) -> Result<bool> {
+    let branch_ref = format!("refs/heads/{branch}");
+    let is_exist =
+        run_git(repo_dir, &["rev-parse", "--verify", "--quiet", &branch_ref])
+            .is_ok();
+    if is_exist {
+        run_git(repo_dir, &["switch", branch])?;
+    } else {
+        run_git(repo_dir, &["switch", "--create", branch])?;
+    }
+    Ok(!is_exist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_branch_name() {
+        assert_eq!(
+            branch_name(
+                DEFAULT_BRANCH_TEMPLATE,
+                "TST-1",
+                "Fix login page (again)!",
+                "Bug"
+            ),
+            "TST-1-fix-login-page-again"
+        );
+        assert_eq!(
+            branch_name(
+                "{type}/{key}_{summary}",
+                "TST-2",
+                "Обновить API",
+                "Story"
+            ),
+            "story/TST-2_обновить-api"
+        );
+        assert_eq!(
+            branch_name(
+                DEFAULT_BRANCH_TEMPLATE,
+                "TST-3",
+                "Upgrade dependencies of the server and the client apps",
+                ""
+            ),
+            "TST-3-upgrade-dependencies-of-the-server-and"
+        );
+    }
+
+    #[test]
+    fn test_issue_key_from_branch() {
+        for (branch, issue_key) in [
+            ("TST-12-fix-login", Some("TST-12")),
+            ("feature/TST-12-fix-login", Some("TST-12")),
+            ("bugfix/ABC_2-7", Some("ABC_2-7")),
+            ("tst-12-fix-login", None),
+            ("release-2-fix", None),
+            ("v2-TST-3", Some("TST-3")),
+            ("fix-login", None),
+            ("main", None),
+        ] {
+            assert_eq!(
+                issue_key_from_branch(branch).as_deref(),
+                issue_key,
+                "{branch}"
+            );
+        }
+    }
+}
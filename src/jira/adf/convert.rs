@@ -0,0 +1,418 @@
+use serde_json::{json, Value};
+
+use super::render::{
+    adf_to_plain_text, attr, attr_number, children, node_type,
+};
+
+/// Converts plain text from editors into ADF document.
+///
+/// Blocks are separated by an empty line.
+/// Supports a small markdown-like syntax:
+/// `# heading`, `- item` or `* item`, `1. item`, `> quote`
+/// and code blocks fenced with three backticks.
+/// Other lines become paragraphs, line breaks are kept.
+pub fn text_to_adf(text: &str) -> Value {
+    let mut content: Vec<Value> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut lines = text.lines();
+
+    while let Some(line) = lines.next() {
+        if let Some(language) = line.strip_prefix("```") {
+            flush_paragraph(&mut content, &mut paragraph);
+            let mut code_lines: Vec<&str> = Vec::new();
+            for code_line in lines.by_ref() {
+                if code_line.starts_with("```") {
+                    break;
+                }
+                code_lines.push(code_line);
+            }
+            content.push(code_block(language.trim(), &code_lines.join("\n")));
+        } else if line.trim().is_empty() {
+            flush_paragraph(&mut content, &mut paragraph);
+        } else if let Some((level, heading)) = parse_heading(line) {
+            flush_paragraph(&mut content, &mut paragraph);
+            content.push(json!({
+                "type": "heading",
+                "attrs": {"level": level},
+                "content": [text_node(heading)],
+            }));
+        } else if let Some(item) = parse_bullet_item(line) {
+            flush_paragraph(&mut content, &mut paragraph);
+            push_list_item(&mut content, "bulletList", item);
+        } else if let Some(item) = parse_ordered_item(line) {
+            flush_paragraph(&mut content, &mut paragraph);
+            push_list_item(&mut content, "orderedList", item);
+        } else if let Some(quote) = line.strip_prefix("> ") {
+            flush_paragraph(&mut content, &mut paragraph);
+            content.push(json!({
+                "type": "blockquote",
+                "content": [paragraph_node(&[quote])],
+            }));
+        } else {
+            paragraph.push(line);
+        }
+    }
+    flush_paragraph(&mut content, &mut paragraph);
+
+    json!({
+        "type": "doc",
+        "version": 1,
+        "content": content,
+    })
+}
+
+/// Builds rich text field value for request to Jira.
+///
+/// API version 3 expects ADF document, version 2 expects plain string.
+pub fn text_to_request_body(text: &str, is_adf_api: bool) -> Value {
+    if is_adf_api {
+        text_to_adf(text)
+    } else {
+        Value::String(text.into())
+    }
+}
+
+/// Converts ADF document into text for editors.
+///
+/// Uses the same syntax as `text_to_adf`, so the text can be
+/// edited and converted back. Nodes without such syntax
+/// (tables, panels, etc.) are kept as they are shown on the screen.
+pub fn adf_to_text(document: &Value) -> String {
+    children(document)
+        .iter()
+        .map(block_to_text)
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+/// Returns true if `adf_to_text` keeps everything of the document,
+/// so the text can be converted back without losses.
+///
+/// Tables, panels, nested lists, marks, mentions, links and other
+/// nodes without editor syntax are lost by the conversion.
+pub fn is_text_convertible(document: &Value) -> bool {
+    children(document).iter().all(is_block_convertible)
+}
+
+fn is_block_convertible(node: &Value) -> bool {
+    match node_type(node) {
+        "paragraph" | "heading" => {
+            children(node).iter().all(is_inline_convertible)
+        }
+        "bulletList" => children(node).iter().all(is_list_item_convertible),
+        "orderedList" => {
+            attr_number(node, "order").unwrap_or(1) == 1
+                && children(node).iter().all(is_list_item_convertible)
+        }
+        "codeBlock" => true,
+        "blockquote" => children(node).iter().all(|block| {
+            node_type(block) == "paragraph" && is_block_convertible(block)
+        }),
+        _ => false,
+    }
+}
+
+/// List item can be edited only if it's a single paragraph.
+fn is_list_item_convertible(item: &Value) -> bool {
+    match children(item) {
+        [paragraph] => {
+            node_type(paragraph) == "paragraph"
+                && children(paragraph)
+                    .iter()
+                    .all(|node| node_type(node) == "text" && no_marks(node))
+        }
+        _ => false,
+    }
+}
+
+fn is_inline_convertible(node: &Value) -> bool {
+    match node_type(node) {
+        "text" => no_marks(node),
+        "hardBreak" => true,
+        _ => false,
+    }
+}
+
+fn no_marks(node: &Value) -> bool {
+    node.get("marks")
+        .and_then(Value::as_array)
+        .is_none_or(Vec::is_empty)
+}
+
+fn block_to_text(node: &Value) -> String {
+    match node_type(node) {
+        "paragraph" => inline_to_text(children(node)),
+        "heading" => format!(
+            "{} {}",
+            "#".repeat(attr_number(node, "level").unwrap_or(1) as usize),
+            inline_to_text(children(node)),
+        ),
+        "bulletList" => children(node)
+            .iter()
+            .map(|item| format!("- {}", list_item_to_text(item)))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        "orderedList" => {
+            let start = attr_number(node, "order").unwrap_or(1);
+            children(node)
+                .iter()
+                .enumerate()
+                .map(|(idx, item)| {
+                    format!(
+                        "{}. {}",
+                        start + idx as u64,
+                        list_item_to_text(item)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+        "codeBlock" => {
+            let code: String = children(node)
+                .iter()
+                .filter_map(|text| text.get("text").and_then(Value::as_str))
+                .collect();
+            format!(
+                "```{}\n{}\n```",
+                attr(node, "language").unwrap_or(""),
+                code
+            )
+        }
+        "blockquote" => children(node)
+            .iter()
+            .map(block_to_text)
+            .flat_map(|block| {
+                block
+                    .lines()
+                    .map(|line| format!("> {line}"))
+                    .collect::<Vec<String>>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        _ => adf_to_plain_text(&json!({"type": "doc", "content": [node]})),
+    }
+}
+
+/// Converts list item into a single line.
+///
+/// Nested lists can't be expressed with the editor syntax,
+/// so their items are joined to the parent item.
+fn list_item_to_text(item: &Value) -> String {
+    children(item)
+        .iter()
+        .map(block_to_text)
+        .collect::<Vec<String>>()
+        .join(" ")
+        .replace('\n', " ")
+}
+
+fn inline_to_text(nodes: &[Value]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node_type(node) {
+            "text" => node
+                .get("text")
+                .and_then(Value::as_str)
+                .unwrap_or("")
+                .into(),
+            "hardBreak" => "\n".to_string(),
+            "mention" | "emoji" => attr(node, "text").unwrap_or("").into(),
+            "inlineCard" => attr(node, "url").unwrap_or("").into(),
+            _ => String::default(),
+        })
+        .collect()
+}
+
+fn text_node(text: &str) -> Value {
+    json!({"type": "text", "text": text})
+}
+
+/// Builds paragraph from lines joined with `hardBreak`.
+fn paragraph_node(lines: &[&str]) -> Value {
+    let mut inline_content: Vec<Value> = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        if idx > 0 {
+            inline_content.push(json!({"type": "hardBreak"}));
+        }
+        inline_content.push(text_node(line));
+    }
+    json!({"type": "paragraph", "content": inline_content})
+}
+
+fn code_block(language: &str, code: &str) -> Value {
+    let mut node = json!({"type": "codeBlock", "content": []});
+    if !code.is_empty() {
+        node["content"] = json!([text_node(code)]);
+    }
+    if !language.is_empty() {
+        node["attrs"] = json!({ "language": language });
+    }
+    node
+}
+
+/// Adds collected lines as paragraph and clears them.
+fn flush_paragraph(content: &mut Vec<Value>, paragraph: &mut Vec<&str>) {
+    if !paragraph.is_empty() {
+        content.push(paragraph_node(paragraph));
+        paragraph.clear();
+    }
+}
+
+/// Adds item to the list at the end of content or creates new list.
+fn push_list_item(content: &mut Vec<Value>, list_type: &str, item: &str) {
+    let list_item = json!({
+        "type": "listItem",
+        "content": [paragraph_node(&[item])],
+    });
+    if let Some(last) = content.last_mut() {
+        if last["type"] == list_type {
+            last["content"].as_array_mut().unwrap().push(list_item);
+            return;
+        }
+    }
+    content.push(json!({"type": list_type, "content": [list_item]}));
+}
+
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|char| *char == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..]
+        .strip_prefix(' ')
+        .map(|heading| (level, heading))
+}
+
+fn parse_bullet_item(line: &str) -> Option<&str> {
+    line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))
+}
+
+fn parse_ordered_item(line: &str) -> Option<&str> {
+    let (number, item) = line.split_once(". ")?;
+    if !number.is_empty() && number.chars().all(|char| char.is_ascii_digit()) {
+        Some(item)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_to_adf() {
+        let text = "# Title\n\
+            first line\n\
+            second line\n\
+            \n\
+            - one\n\
+            - two\n\
+            1. first\n\
+            ```sh\n\
+            cargo  test\n\
+            ```";
+
+        let document = text_to_adf(text);
+
+        assert_eq!(
+            document,
+            json!({
+                "type": "doc",
+                "version": 1,
+                "content": [
+                    {"type": "heading", "attrs": {"level": 1},
+                        "content": [{"type": "text", "text": "Title"}]},
+                    {"type": "paragraph", "content": [
+                        {"type": "text", "text": "first line"},
+                        {"type": "hardBreak"},
+                        {"type": "text", "text": "second line"}
+                    ]},
+                    {"type": "bulletList", "content": [
+                        {"type": "listItem", "content": [{"type": "paragraph",
+                            "content": [{"type": "text", "text": "one"}]}]},
+                        {"type": "listItem", "content": [{"type": "paragraph",
+                            "content": [{"type": "text", "text": "two"}]}]}
+                    ]},
+                    {"type": "orderedList", "content": [
+                        {"type": "listItem", "content": [{"type": "paragraph",
+                            "content": [{"type": "text", "text": "first"}]}]}
+                    ]},
+                    {"type": "codeBlock", "attrs": {"language": "sh"},
+                        "content": [{"type": "text", "text": "cargo  test"}]}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_is_text_convertible() {
+        let text = "# Title\n\n- one\n- two\n\n> quote\n\n```\ncode\n```";
+        assert!(is_text_convertible(&text_to_adf(text)));
+
+        let marked = json!({"type": "doc", "content": [
+            {"type": "paragraph", "content": [
+                {"type": "text", "text": "bold",
+                    "marks": [{"type": "strong"}]}
+            ]}
+        ]});
+        let nested_list = json!({"type": "doc", "content": [
+            {"type": "bulletList", "content": [
+                {"type": "listItem", "content": [
+                    {"type": "paragraph", "content": [
+                        {"type": "text", "text": "one"}
+                    ]},
+                    {"type": "bulletList", "content": []}
+                ]}
+            ]}
+        ]});
+        let mention = json!({"type": "doc", "content": [
+            {"type": "paragraph", "content": [
+                {"type": "mention", "attrs": {"id": "1", "text": "@Alice"}}
+            ]}
+        ]});
+        let table = json!({"type": "doc", "content": [
+            {"type": "table", "content": []}
+        ]});
+        for document in [marked, nested_list, mention, table] {
+            assert!(!is_text_convertible(&document));
+        }
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let text = "## Steps\n\
+            \n\
+            first\n\
+            second\n\
+            \n\
+            - one\n\
+            - two\n\
+            \n\
+            > quote\n\
+            \n\
+            ```\n\
+            let a = 1;\n\
+            ```";
+
+        let converted = adf_to_text(&text_to_adf(text));
+
+        assert_eq!(
+            converted,
+            "## Steps\n\
+            \n\
+            first\n\
+            second\n\
+            \n\
+            - one\n\
+            - two\n\
+            \n\
+            > quote\n\
+            \n\
+            ```\n\
+            let a = 1;\n\
+            ```"
+        );
+    }
+}
@@ -0,0 +1,779 @@
+use serde_json::{json, Map, Value};
+
+/// Elements which never have children.
+const VOID_ELEMENTS: [&str; 8] =
+    ["br", "hr", "img", "input", "meta", "link", "col", "wbr"];
+
+/// Elements which start a new block, other elements are inline.
+const BLOCK_ELEMENTS: [&str; 18] = [
+    "p",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "ul",
+    "ol",
+    "li",
+    "pre",
+    "blockquote",
+    "table",
+    "hr",
+    "dl",
+    "dd",
+    "dt",
+];
+
+/// Element which isn't closed yet: tag, attributes and children.
+type OpenElement = (String, Vec<(String, String)>, Vec<HtmlNode>);
+
+/// Node of the parsed HTML.
+#[derive(Debug, Clone, PartialEq)]
+enum HtmlNode {
+    Element {
+        tag: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<HtmlNode>,
+    },
+    Text(String),
+}
+
+impl HtmlNode {
+    fn tag(&self) -> &str {
+        match self {
+            Self::Element { tag, .. } => tag,
+            Self::Text(_) => "",
+        }
+    }
+
+    fn children(&self) -> &[HtmlNode] {
+        match self {
+            Self::Element { children, .. } => children,
+            Self::Text(_) => &[],
+        }
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        match self {
+            Self::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(attr_name, _)| attr_name == name)
+                .map(|(_, value)| value.as_str()),
+            Self::Text(_) => None,
+        }
+    }
+
+    fn has_class(&self, class: &str) -> bool {
+        self.attr("class").is_some_and(|classes| {
+            classes.split_whitespace().any(|name| name == class)
+        })
+    }
+
+    fn is_block(&self) -> bool {
+        BLOCK_ELEMENTS.contains(&self.tag())
+    }
+
+    /// Returns text of the node and its children as is.
+    fn text_content(&self) -> String {
+        match self {
+            Self::Element { children, .. } => {
+                children.iter().map(Self::text_content).collect()
+            }
+            Self::Text(text) => text.clone(),
+        }
+    }
+
+    /// Returns the first node found by `predicate` among the children
+    /// and their children.
+    fn find<P>(&self, predicate: &P) -> Option<&HtmlNode>
+    where
+        P: Fn(&HtmlNode) -> bool,
+    {
+        self.children().iter().find_map(|child| {
+            if predicate(child) {
+                Some(child)
+            } else {
+                child.find(predicate)
+            }
+        })
+    }
+}
+
+/// Converts HTML rendered by Jira into ADF document.
+///
+/// Jira renders wiki markup into HTML with its own classes,
+/// they are used to find code blocks, panels, mentions and links
+/// to issues.
+pub fn html_to_adf(html: &str) -> Value {
+    json!({
+        "type": "doc",
+        "version": 1,
+        "content": blocks(&parse_html(html)),
+    })
+}
+
+/// Parses HTML into the tree of nodes.
+///
+/// Parser is forgiving: unknown closing tags are skipped
+/// and unclosed elements are closed at the end.
+fn parse_html(html: &str) -> Vec<HtmlNode> {
+    // Every open element with its children, the first one is the root.
+    let mut stack: Vec<OpenElement> =
+        vec![(String::default(), Vec::new(), Vec::new())];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map_or("", |end| &comment[end + "-->".len()..]);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            let tag_name = tag[..end].trim().to_lowercase();
+            rest = tag.get(end + 1..).unwrap_or_default();
+            let position = stack
+                .iter()
+                .skip(1)
+                .rposition(|(open_tag, _, _)| *open_tag == tag_name);
+            if let Some(position) = position {
+                while stack.len() > position + 1 {
+                    close_element(&mut stack);
+                }
+            }
+        } else if rest.starts_with('<')
+            && rest[1..].starts_with(|char: char| char.is_ascii_alphabetic())
+        {
+            let end = tag_end(rest);
+            let (tag_name, attrs) = parse_tag(&rest[1..end]);
+            let is_self_closing = rest[..end].ends_with('/');
+            rest = rest.get(end + 1..).unwrap_or_default();
+            if is_self_closing || VOID_ELEMENTS.contains(&tag_name.as_str()) {
+                stack.last_mut().unwrap().2.push(HtmlNode::Element {
+                    tag: tag_name,
+                    attrs,
+                    children: Vec::new(),
+                });
+            } else {
+                stack.push((tag_name, attrs, Vec::new()));
+            }
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else {
+            // `<` which doesn't start a tag is a text.
+            let end = rest[1..].find('<').map_or(rest.len(), |end| end + 1);
+            stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(HtmlNode::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+
+    while stack.len() > 1 {
+        close_element(&mut stack);
+    }
+    stack
+        .pop()
+        .map(|(_, _, children)| children)
+        .unwrap_or_default()
+}
+
+/// Moves the last open element into children of its parent.
+fn close_element(stack: &mut Vec<OpenElement>) {
+    if let Some((tag, attrs, children)) = stack.pop() {
+        if let Some((_, _, parent_children)) = stack.last_mut() {
+            parent_children.push(HtmlNode::Element {
+                tag,
+                attrs,
+                children,
+            });
+        }
+    }
+}
+
+/// Returns position of `>` which closes the tag at the start of `html`,
+/// `>` inside quoted attributes is skipped.
+fn tag_end(html: &str) -> usize {
+    let mut quote: Option<char> = None;
+    for (idx, char) in html.char_indices() {
+        match (quote, char) {
+            (None, '"' | '\'') => quote = Some(char),
+            (Some(open_quote), _) if open_quote == char => quote = None,
+            (None, '>') => return idx,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+/// Parses name and attributes of the tag, e.g. `a href="/browse/TST-1"`.
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>) {
+    let tag = tag.trim_end_matches('/');
+    let name_end = tag
+        .find(|char: char| char.is_whitespace())
+        .unwrap_or(tag.len());
+    let name = tag[..name_end].to_lowercase();
+
+    let mut attrs = Vec::new();
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let attr_end = rest
+            .find(|char: char| char.is_whitespace() || char == '=')
+            .unwrap_or(rest.len());
+        let attr_name = rest[..attr_end].to_lowercase();
+        rest = rest[attr_end..].trim_start();
+        let mut value = String::default();
+        if let Some(value_start) = rest.strip_prefix('=') {
+            let value_start = value_start.trim_start();
+            let (raw_value, value_rest) = match value_start.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let quoted = &value_start[1..];
+                    let end = quoted.find(quote).unwrap_or(quoted.len());
+                    (&quoted[..end], quoted.get(end + 1..).unwrap_or_default())
+                }
+                _ => {
+                    let end = value_start
+                        .find(char::is_whitespace)
+                        .unwrap_or(value_start.len());
+                    (&value_start[..end], &value_start[end..])
+                }
+            };
+            value = decode_entities(raw_value);
+            rest = value_rest.trim_start();
+        }
+        if !attr_name.is_empty() {
+            attrs.push((attr_name, value));
+        }
+    }
+    (name, attrs)
+}
+
+/// Replaces HTML entities with their chars, unknown entities are kept.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+        match entity {
+            Some((char, end)) => {
+                decoded.push(char);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Returns char of the entity name, e.g. `<` for `lt` or `'` for `#39`.
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(code) = name.strip_prefix('#') {
+        let code = match code.strip_prefix(['x', 'X']) {
+            Some(hex_code) => u32::from_str_radix(hex_code, 16).ok()?,
+            None => code.parse::<u32>().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    let char = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "rarr" => '→',
+        "larr" => '←',
+        "copy" => '©',
+        _ => return None,
+    };
+    Some(char)
+}
+
+/// Converts nodes into ADF blocks,
+/// inline nodes between blocks become paragraphs.
+fn blocks(nodes: &[HtmlNode]) -> Vec<Value> {
+    let mut content: Vec<Value> = Vec::new();
+    let mut inline_nodes: Vec<HtmlNode> = Vec::new();
+    for node in nodes {
+        if node.is_block() {
+            push_paragraph(&mut content, &inline_nodes);
+            inline_nodes.clear();
+            content.extend(block(node));
+        } else {
+            inline_nodes.push(node.clone());
+        }
+    }
+    push_paragraph(&mut content, &inline_nodes);
+    content
+}
+
+/// Adds paragraph of the inline nodes if it has any text.
+fn push_paragraph(content: &mut Vec<Value>, nodes: &[HtmlNode]) {
+    let paragraph = inline(nodes, &[]);
+    if !paragraph.is_empty() {
+        content.push(json!({"type": "paragraph", "content": paragraph}));
+    }
+}
+
+/// Converts block element into ADF blocks.
+fn block(node: &HtmlNode) -> Vec<Value> {
+    let tag = node.tag();
+    match tag {
+        "p" => {
+            let paragraph = inline(node.children(), &[]);
+            if paragraph.is_empty() {
+                return Vec::new();
+            }
+            vec![json!({"type": "paragraph", "content": paragraph})]
+        }
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            let level = tag[1..].parse::<u64>().unwrap_or(1);
+            vec![json!({
+                "type": "heading",
+                "attrs": {"level": level},
+                "content": inline(node.children(), &[]),
+            })]
+        }
+        "ul" | "ol" => {
+            let list_type = if tag == "ol" {
+                "orderedList"
+            } else {
+                "bulletList"
+            };
+            let items: Vec<Value> = node
+                .children()
+                .iter()
+                .filter(|child| child.tag() == "li")
+                .map(|item| {
+                    json!({
+                        "type": "listItem",
+                        "content": blocks(item.children()),
+                    })
+                })
+                .collect();
+            vec![json!({"type": list_type, "content": items})]
+        }
+        "pre" => vec![code_block(node)],
+        "blockquote" => vec![json!({
+            "type": "blockquote",
+            "content": blocks(node.children()),
+        })],
+        "hr" => vec![json!({"type": "rule"})],
+        "table" => vec![table(node)],
+        "div" if node.has_class("code") || node.has_class("preformatted") => {
+            match node.find(&|child: &HtmlNode| child.tag() == "pre") {
+                Some(pre) => vec![code_block(pre)],
+                None => blocks(node.children()),
+            }
+        }
+        "div" if node.has_class("panel") => vec![panel(node)],
+        "div"
+            if node.has_class("aui-message")
+                || node.has_class("information-macro") =>
+        {
+            vec![message_panel(node)]
+        }
+        _ => blocks(node.children()),
+    }
+}
+
+/// Converts `pre` into code block, whitespaces are kept.
+///
+/// Language is taken from the class, e.g. `code-java`.
+fn code_block(pre: &HtmlNode) -> Value {
+    let code = pre.text_content();
+    let code = code.strip_prefix('\n').unwrap_or(&code).trim_end();
+    let language = pre.attr("class").and_then(|classes| {
+        classes
+            .split_whitespace()
+            .find_map(|class| class.strip_prefix("code-"))
+    });
+    let mut node = json!({
+        "type": "codeBlock",
+        "content": [{"type": "text", "text": code}],
+    });
+    if let Some(language) = language {
+        node["attrs"] = json!({"language": language});
+    }
+    node
+}
+
+/// Converts `{panel}` into ADF panel with the title from its header.
+fn panel(node: &HtmlNode) -> Value {
+    let title = node
+        .find(&|child: &HtmlNode| child.has_class("panelHeader"))
+        .map(|header| collapse_whitespace(&header.text_content()))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty());
+    let content =
+        match node.find(&|child: &HtmlNode| child.has_class("panelContent")) {
+            Some(panel_content) => blocks(panel_content.children()),
+            None => blocks(node.children()),
+        };
+    panel_node("custom", title, content)
+}
+
+/// Converts `{info}`, `{note}`, `{warning}` or `{tip}` into ADF panel.
+///
+/// Type of the panel is taken from the class of the message,
+/// e.g. `aui-message-warning` or `warning`.
+fn message_panel(node: &HtmlNode) -> Value {
+    let panel_type = ["error", "warning", "success"]
+        .into_iter()
+        .find(|message_type| {
+            node.has_class(message_type)
+                || node.has_class(&format!("aui-message-{message_type}"))
+        })
+        .unwrap_or("info");
+    let title = node
+        .find(&|child: &HtmlNode| child.has_class("title"))
+        .map(|title| collapse_whitespace(&title.text_content()))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty());
+    let content = match node
+        .find(&|child: &HtmlNode| child.has_class("message-container"))
+    {
+        Some(message) => blocks(message.children()),
+        None => {
+            let children: Vec<HtmlNode> = node
+                .children()
+                .iter()
+                .filter(|child| {
+                    !child.has_class("title") && !child.has_class("aui-icon")
+                })
+                .cloned()
+                .collect();
+            blocks(&children)
+        }
+    };
+    panel_node(panel_type, title, content)
+}
+
+fn panel_node(
+    panel_type: &str,
+    title: Option<String>,
+    content: Vec<Value>,
+) -> Value {
+    let mut attrs = Map::new();
+    attrs.insert("panelType".into(), panel_type.into());
+    if let Some(title) = title {
+        attrs.insert("title".into(), title.into());
+    }
+    json!({"type": "panel", "attrs": attrs, "content": content})
+}
+
+/// Converts table into ADF table, `th` cells are headers.
+fn table(node: &HtmlNode) -> Value {
+    let mut rows: Vec<&HtmlNode> = Vec::new();
+    collect_rows(node.children(), &mut rows);
+    let rows: Vec<Value> = rows
+        .into_iter()
+        .map(|row| {
+            let cells: Vec<Value> = row
+                .children()
+                .iter()
+                .filter(|cell| matches!(cell.tag(), "th" | "td"))
+                .map(|cell| {
+                    let cell_type = if cell.tag() == "th" {
+                        "tableHeader"
+                    } else {
+                        "tableCell"
+                    };
+                    json!({"type": cell_type, "content": blocks(cell.children())})
+                })
+                .collect();
+            json!({"type": "tableRow", "content": cells})
+        })
+        .collect();
+    json!({"type": "table", "content": rows})
+}
+
+/// Collects rows of the table from `thead`, `tbody` and so on,
+/// rows of nested tables are skipped.
+fn collect_rows<'a>(nodes: &'a [HtmlNode], rows: &mut Vec<&'a HtmlNode>) {
+    for node in nodes {
+        match node.tag() {
+            "tr" => rows.push(node),
+            "table" => {}
+            _ => collect_rows(node.children(), rows),
+        }
+    }
+}
+
+/// Converts inline nodes into ADF inline nodes with `marks`.
+///
+/// Whitespaces at the start and the end of lines are removed.
+fn inline(nodes: &[HtmlNode], marks: &[Value]) -> Vec<Value> {
+    let mut content = Vec::new();
+    push_inline(nodes, marks, &mut content);
+    trim_inline(content)
+}
+
+fn push_inline(nodes: &[HtmlNode], marks: &[Value], content: &mut Vec<Value>) {
+    for node in nodes {
+        let mark = match node {
+            HtmlNode::Text(text) => {
+                let text = collapse_whitespace(text);
+                if !text.is_empty() {
+                    content.push(text_node(&text, marks));
+                }
+                continue;
+            }
+            HtmlNode::Element { .. } => match node.tag() {
+                "br" => {
+                    content.push(json!({"type": "hardBreak"}));
+                    continue;
+                }
+                "img" => {
+                    let alt = node.attr("alt").unwrap_or_default();
+                    if node.has_class("emoticon") {
+                        content.push(text_node(alt, marks));
+                    } else {
+                        content.push(text_node(
+                            "[image]",
+                            &with_mark(marks, json!({"type": "em"})),
+                        ));
+                    }
+                    continue;
+                }
+                "a" if is_mention(node) => {
+                    let name = collapse_whitespace(&node.text_content());
+                    content.push(json!({
+                        "type": "mention",
+                        "attrs": {
+                            "id": node.attr("rel").unwrap_or_default(),
+                            "text": format!("@{}", name.trim()),
+                        },
+                    }));
+                    continue;
+                }
+                "a" => link_mark(node),
+                "b" | "strong" => Some(json!({"type": "strong"})),
+                "em" | "i" | "cite" => Some(json!({"type": "em"})),
+                "u" | "ins" => Some(json!({"type": "underline"})),
+                "del" | "s" | "strike" => Some(json!({"type": "strike"})),
+                "tt" | "code" | "kbd" | "samp" => {
+                    Some(json!({"type": "code"}))
+                }
+                _ => None,
+            },
+        };
+        match mark {
+            Some(mark) => {
+                push_inline(node.children(), &with_mark(marks, mark), content)
+            }
+            None => push_inline(node.children(), marks, content),
+        }
+    }
+}
+
+/// Checks if the link is a mention of the user.
+fn is_mention(node: &HtmlNode) -> bool {
+    node.has_class("user-hover")
+        || node
+            .attr("href")
+            .is_some_and(|href| href.contains("ViewProfile.jspa"))
+}
+
+/// Returns link mark of the `a`, links to issues lead to `/browse/KEY`.
+fn link_mark(node: &HtmlNode) -> Option<Value> {
+    let href = match node.attr("data-issue-key") {
+        Some(issue_key) => format!("/browse/{issue_key}"),
+        None => node.attr("href")?.to_string(),
+    };
+    Some(json!({"type": "link", "attrs": {"href": href}}))
+}
+
+fn with_mark(marks: &[Value], mark: Value) -> Vec<Value> {
+    let mut marks = marks.to_vec();
+    marks.push(mark);
+    marks
+}
+
+fn text_node(text: &str, marks: &[Value]) -> Value {
+    if marks.is_empty() {
+        json!({"type": "text", "text": text})
+    } else {
+        json!({"type": "text", "text": text, "marks": marks})
+    }
+}
+
+/// Replaces every sequence of whitespaces with a single space.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut is_space = false;
+    for char in text.chars() {
+        if char.is_whitespace() {
+            if !is_space {
+                collapsed.push(' ');
+            }
+            is_space = true;
+        } else {
+            collapsed.push(char);
+            is_space = false;
+        }
+    }
+    collapsed
+}
+
+/// Removes spaces around line breaks and line breaks
+/// at the start and the end.
+fn trim_inline(mut content: Vec<Value>) -> Vec<Value> {
+    let is_break = |node: Option<&Value>| {
+        node.is_none_or(|node| node["type"] == "hardBreak")
+    };
+    for idx in 0..content.len() {
+        if content[idx]["type"] != "text" {
+            continue;
+        }
+        let text = content[idx]["text"].as_str().unwrap_or_default();
+        let mut trimmed = text;
+        if idx == 0 || is_break(content.get(idx - 1)) {
+            trimmed = trimmed.trim_start();
+        }
+        if is_break(content.get(idx + 1)) {
+            trimmed = trimmed.trim_end();
+        }
+        if trimmed.len() != text.len() {
+            content[idx]["text"] = trimmed.to_string().into();
+        }
+    }
+    content.retain(|node| node["text"] != "");
+    while is_break(content.first()) && !content.is_empty() {
+        content.remove(0);
+    }
+    while is_break(content.last()) && !content.is_empty() {
+        content.pop();
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira::adf::render::adf_to_plain_text;
+
+    #[test]
+    fn test_parse_html() {
+        let nodes = parse_html(
+            "<p class='x'>a &amp; <b>b</b><br/>c<!-- note --></p><hr>d &lt;",
+        );
+        let element = |tag: &str, attrs: Vec<(&str, &str)>, children| {
+            HtmlNode::Element {
+                tag: tag.into(),
+                attrs: attrs
+                    .into_iter()
+                    .map(|(name, value)| (name.into(), value.into()))
+                    .collect(),
+                children,
+            }
+        };
+        assert_eq!(
+            nodes,
+            vec![
+                element(
+                    "p",
+                    vec![("class", "x")],
+                    vec![
+                        HtmlNode::Text("a & ".into()),
+                        element("b", vec![], vec![HtmlNode::Text("b".into())]),
+                        element("br", vec![], vec![]),
+                        HtmlNode::Text("c".into()),
+                    ]
+                ),
+                element("hr", vec![], vec![]),
+                HtmlNode::Text("d <".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_html_to_adf() {
+        let html = r#"<h2><a name="Steps"></a>Steps</h2>
+<p>Ask <a href="http://jira.test/secure/ViewProfile.jspa?name=alice" class="user-hover" rel="alice">Alice Doe</a> about
+<a href="http://jira.test/browse/TST-2" class="issue-link" data-issue-key="TST-2">TST-2</a><br/>
+then deploy</p>
+<ol>
+<li>one
+<ul>
+<li>nested</li>
+</ul>
+</li>
+<li>two</li>
+</ol>
+<div class="code panel" style="border-width: 1px;"><div class="codeContent panelContent pdl">
+<pre class="code-rust"><span class="code-keyword">fn</span> main() {
+    run();
+}</pre>
+</div></div>
+<div class="panel" style="border-width: 1px;"><div class="panelHeader"><b>Release</b></div><div class="panelContent">
+<p>Friday</p>
+</div></div>
+<div class="aui-message aui-message-warning shadowed information-macro">
+<span class="aui-icon icon-warning">Icon</span>
+<div class="message-container">
+<p>Careful</p>
+</div>
+</div>
+<div class='table-wrap'>
+<table class='confluenceTable'><tbody>
+<tr><th class='confluenceTh'>Key</th><th class='confluenceTh'>Summary</th></tr>
+<tr><td class='confluenceTd'>TST-1</td><td class='confluenceTd'>Fix <em>login</em></td></tr>
+</tbody></table>
+</div>"#;
+
+        let document = html_to_adf(html);
+
+        assert_eq!(
+            document["content"][1]["content"][3]["marks"][0],
+            json!({"type": "link", "attrs": {"href": "/browse/TST-2"}})
+        );
+        assert_eq!(
+            adf_to_plain_text(&document),
+            "Steps\n\
+            \n\
+            Ask @Alice Doe about TST-2\n\
+            then deploy\n\
+            \n\
+            1. one\n   \
+            • nested\n\
+            2. two\n\
+            \n\
+            ┌ rust\n\
+            │ fn main() {\n\
+            │     run();\n\
+            │ }\n\
+            \n\
+            ┃ Release\n\
+            ┃ Friday\n\
+            \n\
+            ┃ WARNING\n\
+            ┃ Careful\n\
+            \n\
+            ┌───────┬───────────┐\n\
+            │ Key   │ Summary   │\n\
+            ├───────┼───────────┤\n\
+            │ TST-1 │ Fix login │\n\
+            └───────┴───────────┘"
+        );
+    }
+}
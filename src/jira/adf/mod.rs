@@ -0,0 +1,10 @@
+//! Support of Atlassian Document Format.
+//!
+//! Jira Cloud REST API version 3 returns and accepts rich text fields
+//! (descriptions, comments) as ADF JSON documents.
+//! HTML rendered by Jira and raw wiki markup are converted into ADF,
+//! so every description is shown by the same renderer.
+pub mod convert;
+pub mod html;
+pub mod render;
+pub mod wiki;
@@ -0,0 +1,692 @@
+use cursive::{
+    theme::{BaseColor, Color, Effect, Style},
+    utils::markup::StyledString,
+    Vec2,
+};
+use cursive_markup::{Element, RenderedDocument, Renderer};
+use serde_json::Value;
+
+use crate::utils::find_issue_key;
+
+/// Min width of the text, narrower views get cut text.
+const MIN_TEXT_WIDTH: usize = 10;
+
+/// Single piece of text with its style
+/// and the key of the issue if it's a link to the issue.
+type Span = (String, Style, Option<String>);
+
+/// Single rendered line.
+type Line = Vec<Span>;
+
+/// Renders ADF document into styled text for cursive views.
+pub fn render_adf(document: &Value) -> StyledString {
+    let mut styled = StyledString::new();
+    let lines = render_blocks(children(document), usize::MAX);
+
+    for (idx, line) in lines.iter().enumerate() {
+        if idx > 0 {
+            styled.append_plain("\n");
+        }
+        for (text, style, _) in line {
+            styled.append_styled(text, *style);
+        }
+    }
+    styled
+}
+
+/// Returns plain text of ADF document as it is shown on the screen.
+pub fn adf_to_plain_text(document: &Value) -> String {
+    render_adf(document).source().to_string()
+}
+
+/// Renders ADF document for `MarkupView`.
+///
+/// Text is wrapped by the width of the view
+/// and links to issues can be selected.
+pub struct AdfRenderer {
+    document: Value,
+}
+
+impl AdfRenderer {
+    pub fn new(document: Value) -> Self {
+        Self { document }
+    }
+}
+
+impl Renderer for AdfRenderer {
+    fn render(&self, constraint: Vec2) -> RenderedDocument {
+        let mut rendered = RenderedDocument::new(constraint);
+        let width = constraint.x.max(MIN_TEXT_WIDTH);
+        for line in render_blocks(children(&self.document), width) {
+            rendered.push_line(
+                line.into_iter().map(|(text, style, link)| {
+                    Element::new(text, style, link)
+                }),
+            );
+        }
+        rendered
+    }
+}
+
+/// Returns key of the issue if the url leads to it,
+/// e.g. `TST-1` for `https://jira.test/browse/TST-1`.
+pub(super) fn issue_key_from_url(url: &str) -> Option<String> {
+    let (_, path) = url.split_once("/browse/")?;
+    let issue_key = path.split(['/', '?', '#']).next().unwrap_or_default();
+    let range = find_issue_key(issue_key)?;
+    (range.len() == issue_key.len()).then(|| issue_key.to_string())
+}
+/// Returns `content` array of the node.
+pub(super) fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Returns node type.
+pub(super) fn node_type(node: &Value) -> &str {
+    node.get("type").and_then(Value::as_str).unwrap_or_default()
+}
+
+/// Returns string attribute of the node.
+pub(super) fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    node.get("attrs")?.get(name)?.as_str()
+}
+
+/// Renders block nodes separating them with an empty line.
+///
+/// Text is wrapped by `width`.
+fn render_blocks(nodes: &[Value], width: usize) -> Vec<Line> {
+    let mut lines: Vec<Line> = Vec::new();
+    for node in nodes {
+        let block_lines = render_block(node, width);
+        if block_lines.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(Line::new());
+        }
+        lines.extend(block_lines);
+    }
+    lines
+}
+
+/// Renders single block node.
+fn render_block(node: &Value, width: usize) -> Vec<Line> {
+    match node_type(node) {
+        "paragraph" => render_inline(children(node), Style::none(), width),
+        "heading" => {
+            let mut style = Style::from(Effect::Bold);
+            if attr_number(node, "level").unwrap_or(1) <= 2 {
+                style = style.combine(Effect::Underline);
+            }
+            render_inline(children(node), style, width)
+        }
+        "bulletList" => render_list(node, false, width),
+        "orderedList" => render_list(node, true, width),
+        "codeBlock" => render_code_block(node, width),
+        "blockquote" => prefix_lines(
+            render_blocks(children(node), inner_width(width, 2)),
+            span("│ ", Color::Dark(BaseColor::White)),
+            None,
+        ),
+        "panel" => render_panel(node, width),
+        "rule" => vec![vec![span(&"─".repeat(width.min(40)), Style::none())]],
+        "table" => render_table(node),
+        "mediaSingle" | "mediaGroup" => {
+            vec![vec![span("[attachment]", Effect::Italic)]]
+        }
+        _ => {
+            if children(node).iter().any(is_inline) {
+                render_inline(children(node), Style::none(), width)
+            } else {
+                render_blocks(children(node), width)
+            }
+        }
+    }
+}
+
+/// Returns number attribute of the node.
+pub(super) fn attr_number(node: &Value, name: &str) -> Option<u64> {
+    node.get("attrs")?.get(name)?.as_u64()
+}
+
+/// Returns span of the text which isn't a link.
+fn span<S: Into<Style>>(text: &str, style: S) -> Span {
+    (text.into(), style.into(), None)
+}
+
+/// Returns width left for the content after a prefix of `prefix_width`.
+fn inner_width(width: usize, prefix_width: usize) -> usize {
+    width.saturating_sub(prefix_width).max(1)
+}
+
+/// Checks if node is an inline node.
+fn is_inline(node: &Value) -> bool {
+    matches!(
+        node_type(node),
+        "text"
+            | "hardBreak"
+            | "mention"
+            | "emoji"
+            | "inlineCard"
+            | "status"
+            | "date"
+    )
+}
+
+/// Renders inline nodes, `hardBreak` starts a new line.
+///
+/// Links to issues keep the issue key, so they can be opened.
+fn render_inline(
+    nodes: &[Value],
+    base_style: Style,
+    width: usize,
+) -> Vec<Line> {
+    let mut lines: Vec<Line> = vec![Line::new()];
+    for node in nodes {
+        let span: Span = match node_type(node) {
+            "text" => {
+                let text =
+                    node.get("text").and_then(Value::as_str).unwrap_or("");
+                (
+                    text.into(),
+                    base_style.combine(marks_style(node)),
+                    link_issue_key(node),
+                )
+            }
+            "hardBreak" => {
+                lines.push(Line::new());
+                continue;
+            }
+            "mention" => {
+                let name = attr(node, "text").unwrap_or("@unknown");
+                let name = if name.starts_with('@') {
+                    name.to_string()
+                } else {
+                    format!("@{name}")
+                };
+                span(&name, base_style.combine(Color::Light(BaseColor::Blue)))
+            }
+            "emoji" => span(
+                attr(node, "text")
+                    .or_else(|| attr(node, "shortName"))
+                    .unwrap_or_default(),
+                base_style,
+            ),
+            "inlineCard" => {
+                let url = attr(node, "url").unwrap_or_default();
+                (
+                    url.into(),
+                    base_style.combine(Effect::Underline),
+                    issue_key_from_url(url),
+                )
+            }
+            "status" => span(
+                &format!("[{}]", attr(node, "text").unwrap_or_default()),
+                base_style.combine(Effect::Bold),
+            ),
+            "date" => {
+                span(attr(node, "timestamp").unwrap_or_default(), base_style)
+            }
+            _ => continue,
+        };
+        lines.last_mut().unwrap().push(span);
+    }
+    lines
+        .into_iter()
+        .flat_map(|line| wrap_line(line, width))
+        .collect()
+}
+
+/// Builds style from `marks` of the text node.
+fn marks_style(node: &Value) -> Style {
+    let mut style = Style::none();
+    for mark in marks(node) {
+        style = match node_type(mark) {
+            "strong" => style.combine(Effect::Bold),
+            "em" => style.combine(Effect::Italic),
+            "underline" => style.combine(Effect::Underline),
+            "strike" => style.combine(Effect::Strikethrough),
+            "code" => style.combine(Color::Dark(BaseColor::Yellow)),
+            "link" => style
+                .combine(Effect::Underline)
+                .combine(Color::Light(BaseColor::Blue)),
+            _ => style,
+        };
+    }
+    style
+}
+
+/// Returns `marks` array of the text node.
+fn marks(node: &Value) -> &[Value] {
+    node.get("marks")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Returns key of the issue if the text node is a link to it.
+fn link_issue_key(node: &Value) -> Option<String> {
+    marks(node)
+        .iter()
+        .filter(|mark| node_type(mark) == "link")
+        .find_map(|mark| attr(mark, "href").and_then(issue_key_from_url))
+}
+
+/// Wraps the line by words, so it fits into `width`.
+///
+/// Words longer than the width are split.
+fn wrap_line(line: Line, width: usize) -> Vec<Line> {
+    let line_width: usize =
+        line.iter().map(|(text, _, _)| text.chars().count()).sum();
+    if line_width <= width {
+        return vec![line];
+    }
+
+    let mut lines: Vec<Line> = vec![Line::new()];
+    let mut line_width = 0;
+    for (text, style, link) in line {
+        for word in text.split_inclusive(' ') {
+            // Spaces at the start of the wrapped line are dropped.
+            if line_width == 0 && lines.len() > 1 && word.trim().is_empty() {
+                continue;
+            }
+            let mut word = word.to_string();
+            loop {
+                let word_width = word.trim_end().chars().count();
+                if line_width > 0 && line_width + word_width > width {
+                    trim_line_end(lines.last_mut().unwrap());
+                    lines.push(Line::new());
+                    line_width = 0;
+                }
+                if line_width == 0 && word_width > width {
+                    let head: String = word.chars().take(width).collect();
+                    word = word.chars().skip(width).collect();
+                    lines.last_mut().unwrap().push((
+                        head,
+                        style,
+                        link.clone(),
+                    ));
+                    lines.push(Line::new());
+                    continue;
+                }
+                line_width += word.chars().count();
+                lines.last_mut().unwrap().push((word, style, link.clone()));
+                break;
+            }
+        }
+    }
+    lines
+}
+
+/// Removes spaces at the end of the line.
+fn trim_line_end(line: &mut Line) {
+    if let Some((text, _, _)) = line.last_mut() {
+        let trimmed_len = text.trim_end().len();
+        text.truncate(trimmed_len);
+    }
+}
+
+/// Renders bullet or ordered list with nested lists.
+fn render_list(node: &Value, is_ordered: bool, width: usize) -> Vec<Line> {
+    let start = attr_number(node, "order").unwrap_or(1);
+    let mut lines = Vec::new();
+    for (idx, item) in children(node).iter().enumerate() {
+        let marker = if is_ordered {
+            format!("{}. ", start + idx as u64)
+        } else {
+            "• ".to_string()
+        };
+        let indent = " ".repeat(marker.chars().count());
+        let item_lines =
+            render_list_item(item, inner_width(width, indent.len()));
+        lines.extend(prefix_lines(
+            item_lines,
+            span(&marker, Effect::Bold),
+            Some(span(&indent, Style::none())),
+        ));
+    }
+    lines
+}
+
+/// Renders list item without empty lines between its blocks.
+fn render_list_item(item: &Value, width: usize) -> Vec<Line> {
+    children(item)
+        .iter()
+        .flat_map(|block| render_block(block, width))
+        .collect()
+}
+
+/// Adds `first` prefix to the first line and `rest` prefix
+/// (or `first` if it's `None`) to other lines.
+fn prefix_lines(
+    lines: Vec<Line>,
+    first: Span,
+    rest: Option<Span>,
+) -> Vec<Line> {
+    let rest = rest.unwrap_or_else(|| first.clone());
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let prefix = if idx == 0 {
+                first.clone()
+            } else {
+                rest.clone()
+            };
+            let mut prefixed = vec![prefix];
+            prefixed.extend(line);
+            prefixed
+        })
+        .collect()
+}
+
+/// Renders code block preserving whitespaces.
+///
+/// Long lines are split by chars, so indentation isn't lost.
+fn render_code_block(node: &Value, width: usize) -> Vec<Line> {
+    let code: String = children(node)
+        .iter()
+        .filter_map(|text| text.get("text").and_then(Value::as_str))
+        .collect();
+    let code_style = Style::from(Color::Dark(BaseColor::Yellow));
+    let border_style = Style::from(Color::Dark(BaseColor::White));
+    let code_width = inner_width(width, 2);
+
+    let mut lines = Vec::new();
+    if let Some(language) = attr(node, "language") {
+        lines.push(vec![span(&format!("┌ {language}"), border_style)]);
+    }
+    for code_line in code.split('\n') {
+        let chars: Vec<char> = code_line.chars().collect();
+        let mut parts: Vec<String> = chars
+            .chunks(code_width)
+            .map(|part| part.iter().collect())
+            .collect();
+        if parts.is_empty() {
+            parts.push(String::default());
+        }
+        for part in parts {
+            lines.push(vec![
+                span("│ ", border_style),
+                (part, code_style, None),
+            ]);
+        }
+    }
+    lines
+}
+
+/// Renders panel as a box with colored border and its title or type.
+///
+/// `custom` panels without title have no header.
+fn render_panel(node: &Value, width: usize) -> Vec<Line> {
+    let panel_type = attr(node, "panelType").unwrap_or("info");
+    let color = match panel_type {
+        "note" => Color::Light(BaseColor::Magenta),
+        "warning" => Color::Light(BaseColor::Yellow),
+        "error" => Color::Light(BaseColor::Red),
+        "success" => Color::Light(BaseColor::Green),
+        "custom" => Color::Dark(BaseColor::White),
+        _ => Color::Light(BaseColor::Blue),
+    };
+    let border_style = Style::from(color);
+
+    let title = match attr(node, "title") {
+        Some(title) => Some(title.to_string()),
+        None if panel_type == "custom" => None,
+        None => Some(panel_type.to_uppercase()),
+    };
+    let mut lines: Vec<Line> = title
+        .into_iter()
+        .map(|title| {
+            vec![span(
+                &format!("┃ {title}"),
+                border_style.combine(Effect::Bold),
+            )]
+        })
+        .collect();
+    lines.extend(prefix_lines(
+        render_blocks(children(node), inner_width(width, 2)),
+        span("┃ ", border_style),
+        None,
+    ));
+    lines
+}
+
+/// Renders table with borders and aligned columns,
+/// header cells are bold.
+///
+/// Cells aren't wrapped, so rows are never broken.
+fn render_table(node: &Value) -> Vec<Line> {
+    let rows: Vec<Vec<(Line, bool)>> = children(node)
+        .iter()
+        .map(|row| {
+            children(row)
+                .iter()
+                .map(|cell| {
+                    let mut cell_line = Line::new();
+                    let cell_lines = render_blocks(children(cell), usize::MAX)
+                        .into_iter()
+                        .filter(|line| !line.is_empty());
+                    for line in cell_lines {
+                        if !cell_line.is_empty() {
+                            cell_line.push(span(" ", Style::none()));
+                        }
+                        cell_line.extend(line);
+                    }
+                    (cell_line, node_type(cell) == "tableHeader")
+                })
+                .collect()
+        })
+        .collect();
+
+    let columns_amount = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let mut widths = vec![0; columns_amount];
+    for row in &rows {
+        for (idx, (line, _)) in row.iter().enumerate() {
+            widths[idx] = widths[idx].max(line_width(line));
+        }
+    }
+
+    let border_style = Style::from(Color::Dark(BaseColor::White));
+    let border = |left: &str, middle: &str, right: &str| -> Line {
+        let inner = widths
+            .iter()
+            .map(|width| "─".repeat(width + 2))
+            .collect::<Vec<String>>()
+            .join(middle);
+        vec![span(&format!("{left}{inner}{right}"), border_style)]
+    };
+
+    let mut lines = vec![border("┌", "┬", "┐")];
+    for (row_idx, row) in rows.iter().enumerate() {
+        let mut line = vec![span("│", border_style)];
+        for (idx, width) in widths.iter().enumerate() {
+            let (cell_line, is_header) = row
+                .get(idx)
+                .cloned()
+                .unwrap_or_else(|| (Line::new(), false));
+            let padding = width - line_width(&cell_line);
+            line.push(span(" ", Style::none()));
+            for (text, style, link) in cell_line {
+                let style = if is_header {
+                    style.combine(Effect::Bold)
+                } else {
+                    style
+                };
+                line.push((text, style, link));
+            }
+            line.push(span(&" ".repeat(padding + 1), Style::none()));
+            line.push(span("│", border_style));
+        }
+        lines.push(line);
+
+        let is_header_row = row.iter().all(|(_, is_header)| *is_header);
+        if row_idx == 0 && is_header_row && rows.len() > 1 {
+            lines.push(border("├", "┼", "┤"));
+        }
+    }
+    lines.push(border("└", "┴", "┘"));
+    lines
+}
+
+/// Returns amount of chars in the line.
+fn line_width(line: &Line) -> usize {
+    line.iter().map(|(text, _, _)| text.chars().count()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_adf() {
+        let document = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                {
+                    "type": "heading",
+                    "attrs": {"level": 2},
+                    "content": [{"type": "text", "text": "Steps"}]
+                },
+                {
+                    "type": "paragraph",
+                    "content": [
+                        {"type": "text", "text": "Ask "},
+                        {"type": "mention", "attrs": {"id": "1", "text": "@Alice"}},
+                        {"type": "hardBreak"},
+                        {"type": "text", "text": "bold", "marks": [{"type": "strong"}]}
+                    ]
+                },
+                {
+                    "type": "orderedList",
+                    "content": [
+                        {"type": "listItem", "content": [
+                            {"type": "paragraph", "content": [{"type": "text", "text": "one"}]},
+                            {"type": "bulletList", "content": [
+                                {"type": "listItem", "content": [
+                                    {"type": "paragraph", "content": [{"type": "text", "text": "nested"}]}
+                                ]}
+                            ]}
+                        ]},
+                        {"type": "listItem", "content": [
+                            {"type": "paragraph", "content": [{"type": "text", "text": "two"}]}
+                        ]}
+                    ]
+                },
+                {
+                    "type": "codeBlock",
+                    "attrs": {"language": "rust"},
+                    "content": [{"type": "text", "text": "fn main() {\n    run();\n}"}]
+                },
+                {
+                    "type": "panel",
+                    "attrs": {"panelType": "warning"},
+                    "content": [
+                        {"type": "paragraph", "content": [{"type": "text", "text": "Careful"}]}
+                    ]
+                }
+            ]
+        });
+
+        let rendered = adf_to_plain_text(&document);
+
+        assert_eq!(
+            rendered,
+            "Steps\n\
+            \n\
+            Ask @Alice\n\
+            bold\n\
+            \n\
+            1. one\n   \
+            • nested\n\
+            2. two\n\
+            \n\
+            ┌ rust\n\
+            │ fn main() {\n\
+            │     run();\n\
+            │ }\n\
+            \n\
+            ┃ WARNING\n\
+            ┃ Careful"
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let cell = |cell_type: &str, text: &str| {
+            json!({"type": cell_type, "content": [
+                {"type": "paragraph", "content": [{"type": "text", "text": text}]}
+            ]})
+        };
+        let document = json!({
+            "type": "doc",
+            "content": [{
+                "type": "table",
+                "content": [
+                    {"type": "tableRow", "content": [
+                        cell("tableHeader", "Key"), cell("tableHeader", "Summary")
+                    ]},
+                    {"type": "tableRow", "content": [
+                        cell("tableCell", "TST-1"), cell("tableCell", "Fix")
+                    ]}
+                ]
+            }]
+        });
+
+        assert_eq!(
+            adf_to_plain_text(&document),
+            "┌───────┬─────────┐\n\
+            │ Key   │ Summary │\n\
+            ├───────┼─────────┤\n\
+            │ TST-1 │ Fix     │\n\
+            └───────┴─────────┘"
+        );
+    }
+
+    #[test]
+    fn test_wrap_and_issue_links() {
+        let document = json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    {"type": "text", "text": "Blocked by "},
+                    {
+                        "type": "text",
+                        "text": "TST-2",
+                        "marks": [{
+                            "type": "link",
+                            "attrs": {"href": "https://jira.test/browse/TST-2"}
+                        }]
+                    },
+                    {"type": "text", "text": " until release"}
+                ]
+            }]
+        });
+
+        let lines = render_blocks(children(&document), 12);
+        let texts: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                line.iter().map(|(text, _, _)| text.as_str()).collect()
+            })
+            .collect();
+        assert_eq!(texts, vec!["Blocked by", "TST-2 until", "release"]);
+        let links: Vec<&str> = lines
+            .iter()
+            .flatten()
+            .filter_map(|(_, _, link)| link.as_deref())
+            .collect();
+        assert_eq!(links, vec!["TST-2"]);
+        assert_eq!(
+            issue_key_from_url("https://jira.test/browse/TST-2?x=1"),
+            Some("TST-2".into())
+        );
+        assert_eq!(issue_key_from_url("https://jira.test/browse/tst"), None);
+    }
+}
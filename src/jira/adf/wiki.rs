@@ -0,0 +1,553 @@
+use serde_json::{json, Map, Value};
+
+use crate::utils::find_issue_key;
+
+/// Macros with blocks inside, e.g. `{panel:title=Release}...{panel}`.
+const BLOCK_MACROS: [&str; 6] =
+    ["panel", "info", "note", "warning", "tip", "quote"];
+
+/// Converts Jira wiki markup into ADF document.
+///
+/// It's used if Jira didn't send rendered HTML of the description.
+/// Supports headings, lists, tables, code and noformat blocks,
+/// panels, quotes, text effects, mentions and links.
+/// Keys of issues in the text become links to them.
+pub fn wiki_to_adf(text: &str) -> Value {
+    let lines: Vec<&str> = text.lines().collect();
+    json!({
+        "type": "doc",
+        "version": 1,
+        "content": blocks(&lines),
+    })
+}
+
+/// Converts lines into ADF blocks.
+///
+/// Lines of the same paragraph are joined with line breaks.
+fn blocks(lines: &[&str]) -> Vec<Value> {
+    let mut content: Vec<Value> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut idx = 0;
+
+    while idx < lines.len() {
+        let line = lines[idx];
+        let trimmed = line.trim();
+        idx += 1;
+
+        if let Some((name, params, rest)) = parse_macro(trimmed) {
+            flush_paragraph(&mut content, &mut paragraph);
+            let (inner_lines, next_idx) =
+                macro_content(name, rest, lines, idx);
+            idx = next_idx;
+            content.push(macro_block(name, params, &inner_lines));
+        } else if trimmed.is_empty() {
+            flush_paragraph(&mut content, &mut paragraph);
+        } else if let Some((level, heading)) = parse_heading(trimmed) {
+            flush_paragraph(&mut content, &mut paragraph);
+            content.push(json!({
+                "type": "heading",
+                "attrs": {"level": level},
+                "content": inline(heading),
+            }));
+        } else if let Some(quote) = trimmed.strip_prefix("bq. ") {
+            flush_paragraph(&mut content, &mut paragraph);
+            content.push(json!({
+                "type": "blockquote",
+                "content": [paragraph_node(&[quote])],
+            }));
+        } else if trimmed == "----" {
+            flush_paragraph(&mut content, &mut paragraph);
+            content.push(json!({"type": "rule"}));
+        } else if trimmed.starts_with('|') {
+            flush_paragraph(&mut content, &mut paragraph);
+            let mut rows = vec![trimmed];
+            while idx < lines.len() && lines[idx].trim().starts_with('|') {
+                rows.push(lines[idx].trim());
+                idx += 1;
+            }
+            content.push(table(&rows));
+        } else if parse_list_item(trimmed).is_some() {
+            flush_paragraph(&mut content, &mut paragraph);
+            let mut items = vec![parse_list_item(trimmed).unwrap()];
+            while let Some(item) =
+                lines.get(idx).and_then(|line| parse_list_item(line.trim()))
+            {
+                items.push(item);
+                idx += 1;
+            }
+            content.extend(lists(&items, 0));
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    flush_paragraph(&mut content, &mut paragraph);
+    content
+}
+
+/// Parses opening of the block macro,
+/// e.g. `{code:java}` to (`code`, `java`, text after the macro).
+fn parse_macro(line: &str) -> Option<(&str, &str, &str)> {
+    let tag = line.strip_prefix('{')?;
+    let end = tag.find('}')?;
+    let (name, params) =
+        tag[..end].split_once(':').unwrap_or((&tag[..end], ""));
+    let is_block_macro =
+        matches!(name, "code" | "noformat") || BLOCK_MACROS.contains(&name);
+    is_block_macro.then_some((name, params, &tag[end + 1..]))
+}
+
+/// Returns lines inside the macro and index of the line after it.
+///
+/// Content can start on the line of the macro and end on the line
+/// of the closing `{name}`.
+fn macro_content<'a>(
+    name: &str,
+    rest: &'a str,
+    lines: &[&'a str],
+    start_idx: usize,
+) -> (Vec<&'a str>, usize) {
+    let closing = format!("{{{name}}}");
+    if let Some(end) = rest.find(&closing) {
+        return (vec![&rest[..end]], start_idx);
+    }
+    let mut inner_lines = Vec::new();
+    if !rest.trim().is_empty() {
+        inner_lines.push(rest);
+    }
+    let mut idx = start_idx;
+    while idx < lines.len() {
+        let line = lines[idx];
+        idx += 1;
+        if let Some(end) = line.find(&closing) {
+            if !line[..end].trim().is_empty() {
+                inner_lines.push(&line[..end]);
+            }
+            break;
+        }
+        inner_lines.push(line);
+    }
+    (inner_lines, idx)
+}
+
+/// Converts content of the macro into ADF block.
+fn macro_block(name: &str, params: &str, lines: &[&str]) -> Value {
+    match name {
+        "code" | "noformat" => {
+            let mut node = json!({
+                "type": "codeBlock",
+                "content": [{"type": "text", "text": lines.join("\n")}],
+            });
+            let language = params
+                .split('|')
+                .find_map(|param| match param.split_once('=') {
+                    Some(("language", language)) => Some(language),
+                    Some(_) => None,
+                    None => Some(param),
+                })
+                .filter(|language| !language.is_empty());
+            if let (Some(language), "code") = (language, name) {
+                node["attrs"] = json!({"language": language});
+            }
+            node
+        }
+        "quote" => json!({"type": "blockquote", "content": blocks(lines)}),
+        _ => {
+            let panel_type = match name {
+                "note" => "warning",
+                "warning" => "error",
+                "tip" => "success",
+                "info" => "info",
+                _ => "custom",
+            };
+            let mut attrs = Map::new();
+            attrs.insert("panelType".into(), panel_type.into());
+            let title = params
+                .split('|')
+                .find_map(|param| param.strip_prefix("title=").map(str::trim));
+            if let Some(title) = title.filter(|title| !title.is_empty()) {
+                attrs.insert("title".into(), title.into());
+            }
+            json!({"type": "panel", "attrs": attrs, "content": blocks(lines)})
+        }
+    }
+}
+
+/// Parses heading, e.g. `h2. Steps` to (2, `Steps`).
+fn parse_heading(line: &str) -> Option<(u64, &str)> {
+    let rest = line.strip_prefix('h')?;
+    let level = rest.chars().next()?.to_digit(10)?;
+    let heading = rest[1..].strip_prefix(". ")?;
+    (1..=6)
+        .contains(&level)
+        .then_some((level as u64, heading.trim()))
+}
+
+/// Parses list item, e.g. `#* nested` to (`#*`, `nested`).
+///
+/// `*` and `-` are bullets, `#` is a number.
+fn parse_list_item(line: &str) -> Option<(&str, &str)> {
+    let markers_end = line
+        .find(|char: char| !matches!(char, '*' | '#' | '-'))
+        .unwrap_or(line.len());
+    let markers = &line[..markers_end];
+    let text = line[markers_end..].strip_prefix(' ')?;
+    let is_list_item = !markers.is_empty()
+        && (markers == "-" || !markers.contains('-'))
+        && !text.trim().is_empty();
+    is_list_item.then_some((markers, text.trim()))
+}
+
+/// Builds lists of the items at the `depth`,
+/// items with longer markers become nested lists.
+fn lists(items: &[(&str, &str)], depth: usize) -> Vec<Value> {
+    let mut built_lists = Vec::new();
+    let mut idx = 0;
+    while idx < items.len() {
+        let marker = items[idx].0.chars().nth(depth);
+        let mut list_items = Vec::new();
+        while idx < items.len() && items[idx].0.chars().nth(depth) == marker {
+            let mut item_content = Vec::new();
+            if items[idx].0.chars().count() == depth + 1 {
+                item_content.push(paragraph_node(&[items[idx].1]));
+                idx += 1;
+            }
+            let nested_start = idx;
+            while idx < items.len()
+                && items[idx].0.chars().count() > depth + 1
+                && items[idx].0.chars().nth(depth) == marker
+            {
+                idx += 1;
+            }
+            item_content.extend(lists(&items[nested_start..idx], depth + 1));
+            list_items.push(json!({
+                "type": "listItem",
+                "content": item_content,
+            }));
+        }
+        let list_type = if marker == Some('#') {
+            "orderedList"
+        } else {
+            "bulletList"
+        };
+        built_lists.push(json!({"type": list_type, "content": list_items}));
+    }
+    built_lists
+}
+
+/// Builds table of the rows, cells starting with `||` are headers.
+fn table(rows: &[&str]) -> Value {
+    let rows: Vec<Value> = rows
+        .iter()
+        .map(|row| {
+            let cells: Vec<Value> = split_cells(row)
+                .into_iter()
+                .map(|(text, is_header)| {
+                    let cell_type = if is_header {
+                        "tableHeader"
+                    } else {
+                        "tableCell"
+                    };
+                    json!({
+                        "type": cell_type,
+                        "content": [paragraph_node(&[text.trim()])],
+                    })
+                })
+                .collect();
+            json!({"type": "tableRow", "content": cells})
+        })
+        .collect();
+    json!({"type": "table", "content": rows})
+}
+
+/// Splits row into cells, `|` inside links and macros doesn't split.
+fn split_cells(row: &str) -> Vec<(&str, bool)> {
+    let mut cells = Vec::new();
+    let mut rest = row;
+    while !rest.is_empty() {
+        let (is_header, cell) = match rest.strip_prefix("||") {
+            Some(cell) => (true, cell),
+            None => (false, rest.strip_prefix('|').unwrap_or(rest)),
+        };
+        let mut depth = 0;
+        let end = cell
+            .char_indices()
+            .find(|(_, char)| {
+                match char {
+                    '[' | '{' => depth += 1,
+                    ']' | '}' => depth -= 1,
+                    '|' if depth <= 0 => return true,
+                    _ => {}
+                }
+                false
+            })
+            .map_or(cell.len(), |(idx, _)| idx);
+        if end > 0 || cell.len() > end {
+            cells.push((&cell[..end], is_header));
+        }
+        rest = &cell[end..];
+        // The last separator closes the row.
+        if rest.trim() == "|" || rest.trim() == "||" {
+            break;
+        }
+    }
+    cells
+}
+
+fn paragraph_node(lines: &[&str]) -> Value {
+    let mut content: Vec<Value> = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        if idx > 0 {
+            content.push(json!({"type": "hardBreak"}));
+        }
+        content.extend(inline(line));
+    }
+    json!({"type": "paragraph", "content": content})
+}
+
+fn flush_paragraph(content: &mut Vec<Value>, paragraph: &mut Vec<&str>) {
+    if !paragraph.is_empty() {
+        content.push(paragraph_node(paragraph));
+        paragraph.clear();
+    }
+}
+
+/// Converts text with wiki effects into ADF inline nodes.
+fn inline(text: &str) -> Vec<Value> {
+    let mut content = Vec::new();
+    push_inline(text, &[], &mut content);
+    content
+}
+
+/// Parses text effects (`*bold*`, `_italic_`, `+underline+`,
+/// `-strike-`, `{{monospace}}`), `[links]`, `[~mentions]`
+/// and `\\` line breaks.
+fn push_inline(text: &str, marks: &[Value], content: &mut Vec<Value>) {
+    let mut plain_start = 0;
+    let mut idx = 0;
+    while idx < text.len() {
+        let rest = &text[idx..];
+        let parsed = if rest.starts_with("\\\\") {
+            Some((vec![json!({"type": "hardBreak"})], 2))
+        } else if let Some(code) = rest.strip_prefix("{{") {
+            code.find("}}").map(|end| {
+                let code_mark = json!({"type": "code"});
+                (
+                    vec![text_node(
+                        &code[..end],
+                        &with_mark(marks, code_mark),
+                    )],
+                    end + 4,
+                )
+            })
+        } else if rest.starts_with("{color") {
+            // Colors aren't shown, the text is kept.
+            rest.find('}').map(|end| (Vec::new(), end + 1))
+        } else if rest.starts_with('[') {
+            rest.find(']')
+                .map(|end| (link(&rest[1..end], marks), end + 1))
+        } else if let Some(escaped) = rest.strip_prefix('\\') {
+            escaped.chars().next().map(|char| {
+                (
+                    vec![text_node(&char.to_string(), marks)],
+                    char.len_utf8() + 1,
+                )
+            })
+        } else {
+            effect(text, idx).map(|(mark, inner, length)| {
+                let mut nodes = Vec::new();
+                push_inline(inner, &with_mark(marks, mark), &mut nodes);
+                (nodes, length)
+            })
+        };
+
+        match parsed {
+            Some((nodes, length)) => {
+                push_text(&text[plain_start..idx], marks, content);
+                content.extend(nodes);
+                idx += length;
+                plain_start = idx;
+            }
+            None => {
+                idx += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+    push_text(&text[plain_start..], marks, content);
+}
+
+/// Parses text effect at `idx`, returns its mark, text inside
+/// and length with the effect chars.
+///
+/// Effect must start at the start of a word and end at the end of one,
+/// so `well-known` isn't a strikethrough.
+fn effect(text: &str, idx: usize) -> Option<(Value, &str, usize)> {
+    let char = text[idx..].chars().next()?;
+    let mark_type = match char {
+        '*' => "strong",
+        '_' => "em",
+        '+' => "underline",
+        '-' => "strike",
+        _ => return None,
+    };
+    let is_word_char = |char: char| char.is_alphanumeric();
+    let is_start = text[..idx]
+        .chars()
+        .next_back()
+        .is_none_or(|prev| !is_word_char(prev));
+    let inner_start = idx + 1;
+    let first = text[inner_start..].chars().next()?;
+    if !is_start || first.is_whitespace() || first == char {
+        return None;
+    }
+    let end = text[inner_start..]
+        .char_indices()
+        .map(|(end, _)| inner_start + end)
+        .find(|end| {
+            text[*end..].starts_with(char)
+                && !text[..*end].ends_with(char::is_whitespace)
+                && text[end + 1..]
+                    .chars()
+                    .next()
+                    .is_none_or(|next| !is_word_char(next))
+        })?;
+    Some((
+        json!({"type": mark_type}),
+        &text[inner_start..end],
+        end + 1 - idx,
+    ))
+}
+
+/// Converts `[...]` into mention, link or text.
+///
+/// `[~alice]` is a mention, `[Docs|https://...]`, `[https://...]`
+/// and `[TST-1]` are links.
+fn link(inner: &str, marks: &[Value]) -> Vec<Value> {
+    if let Some(user) = inner.strip_prefix('~') {
+        let name = user.strip_prefix("accountid:").unwrap_or(user);
+        return vec![json!({
+            "type": "mention",
+            "attrs": {"id": name, "text": format!("@{name}")},
+        })];
+    }
+    let (text, target) = inner.split_once('|').unwrap_or((inner, inner));
+    let target = target.trim();
+    let href = if is_issue_key(target) {
+        format!("/browse/{target}")
+    } else {
+        target.to_string()
+    };
+    let link_mark = json!({"type": "link", "attrs": {"href": href}});
+    vec![text_node(text.trim(), &with_mark(marks, link_mark))]
+}
+
+/// Adds plain text, keys of issues in it become links.
+fn push_text(text: &str, marks: &[Value], content: &mut Vec<Value>) {
+    let mut rest = text;
+    while let Some(range) = find_issue_key(rest) {
+        if range.start > 0 {
+            content.push(text_node(&rest[..range.start], marks));
+        }
+        let issue_key = &rest[range.clone()];
+        let link_mark = json!({
+            "type": "link",
+            "attrs": {"href": format!("/browse/{issue_key}")},
+        });
+        content.push(text_node(issue_key, &with_mark(marks, link_mark)));
+        rest = &rest[range.end..];
+    }
+    if !rest.is_empty() {
+        content.push(text_node(rest, marks));
+    }
+}
+
+fn is_issue_key(text: &str) -> bool {
+    find_issue_key(text).is_some_and(|range| range.len() == text.len())
+}
+
+fn with_mark(marks: &[Value], mark: Value) -> Vec<Value> {
+    let mut marks = marks.to_vec();
+    marks.push(mark);
+    marks
+}
+
+fn text_node(text: &str, marks: &[Value]) -> Value {
+    if marks.is_empty() {
+        json!({"type": "text", "text": text})
+    } else {
+        json!({"type": "text", "text": text, "marks": marks})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira::adf::render::adf_to_plain_text;
+
+    #[test]
+    fn test_wiki_to_adf() {
+        let wiki = "h2. Steps\n\
+            Ask [~alice] about TST-2\n\
+            then *deploy* a well-known -old- build\n\
+            \n\
+            # one\n\
+            #* nested\n\
+            # two\n\
+            \n\
+            {code:rust}\n\
+            fn main() {\n    run();\n}\n\
+            {code}\n\
+            {panel:title=Release}\n\
+            Friday\n\
+            {panel}\n\
+            {note}Careful{note}\n\
+            ||Key||Summary||\n\
+            |TST-1|Fix [login|https://jira.test/login]|";
+
+        let document = wiki_to_adf(wiki);
+
+        assert_eq!(
+            document["content"][1]["content"][3],
+            json!({
+                "type": "text",
+                "text": "TST-2",
+                "marks": [{"type": "link", "attrs": {"href": "/browse/TST-2"}}],
+            })
+        );
+        assert_eq!(
+            document["content"][1]["content"][6],
+            json!({
+                "type": "text",
+                "text": "deploy",
+                "marks": [{"type": "strong"}],
+            })
+        );
+        assert_eq!(
+            adf_to_plain_text(&document),
+            "Steps\n\
+            \n\
+            Ask @alice about TST-2\n\
+            then deploy a well-known old build\n\
+            \n\
+            1. one\n   \
+            • nested\n\
+            2. two\n\
+            \n\
+            ┌ rust\n\
+            │ fn main() {\n\
+            │     run();\n\
+            │ }\n\
+            \n\
+            ┃ Release\n\
+            ┃ Friday\n\
+            \n\
+            ┃ WARNING\n\
+            ┃ Careful\n\
+            \n\
+            ┌───────┬───────────┐\n\
+            │ Key   │ Summary   │\n\
+            ├───────┼───────────┤\n\
+            │ TST-1 │ Fix login │\n\
+            └───────┴───────────┘"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use std::sync::RwLock;
+use std::time::Duration;
+
+use crate::{
+    errors::RusjiResult,
+    jira::{
+        jira_data::JiraData,
+        tasks::{changelog::IssueChangelog, data::JiraIssues},
+        tasks_actions::data::JiraUser,
+    },
+};
+
+/// Returns JQL of the issues of the projects
+/// updated in the last `minutes`.
+pub fn updated_issues_jql(minutes: u64, project_keys: &[String]) -> String {
+    let projects = project_keys
+        .iter()
+        .map(|key| format!("\"{key}\""))
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!(
+        "project in ({projects}) AND updated >= -{minutes}m \
+        ORDER BY updated DESC"
+    )
+}
+
+/// Returns minutes of the check window which covers the time
+/// since the last successful check.
+///
+/// A minute is added, so issues updated while the last check was
+/// running and by a Jira with a bit different clock aren't missed.
+pub fn check_window_minutes(since_last_check: Duration) -> u64 {
+    since_last_check.as_secs().div_ceil(60) + 1
+}
+
+/// Issues found by a check for updates with the data
+/// needed to show them.
+///
+/// Everything is loaded in the refresh thread,
+/// so the UI isn't blocked by requests while the issues are applied.
+#[derive(Debug, Default)]
+pub struct UpdatedIssues {
+    pub issues: JiraIssues,
+    /// Current user if it isn't requested by the company yet.
+    pub current_user: Option<JiraUser>,
+}
+
+impl UpdatedIssues {
+    /// Finds issues of the projects with loaded tasks
+    /// updated in the last `minutes`.
+    ///
+    /// Transitions of the selected issue and its changelog,
+    /// if the history tab is shown, are loaded too.
+    /// They are left empty if they can't be loaded.
+    pub fn load(
+        jira_data: &RwLock<JiraData>,
+        minutes: u64,
+    ) -> RusjiResult<Self> {
+        let (client, project_keys, selected_key, has_history, has_user) = {
+            let jira_data_guard = jira_data.read().unwrap();
+            (
+                jira_data_guard.client.clone(),
+                jira_data_guard.get_loaded_project_keys(),
+                jira_data_guard.selected_task.clone(),
+                jira_data_guard.history_tab.is_some(),
+                jira_data_guard.has_current_user(),
+            )
+        };
+        if project_keys.is_empty() {
+            return Ok(Self::default());
+        }
+        let jql = updated_issues_jql(minutes, &project_keys);
+        let mut issues = JiraIssues::search(client.clone(), &jql)?;
+        for issue in issues.iter_mut() {
+            if issue.key != selected_key {
+                continue;
+            }
+            let _ = issue.add_transitions(client.clone());
+            if has_history {
+                issue.changelog =
+                    IssueChangelog::new(client.clone(), &issue.key).ok();
+            }
+        }
+        let current_user = if has_user {
+            None
+        } else {
+            JiraUser::current(client.clone()).ok()
+        };
+        Ok(Self {
+            issues,
+            current_user,
+        })
+    }
+}
+
+/// Changes found by a check for updated issues.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RefreshSummary {
+    /// Keys of the issues which are assigned to the current user now.
+    pub assigned: Vec<String>,
+    /// Keys of other changed or created issues.
+    pub updated: Vec<String>,
+    /// True if the issue shown in the task information changed.
+    pub is_selected_changed: bool,
+    /// True if tasks of the selected project changed.
+    pub is_list_changed: bool,
+}
+
+impl RefreshSummary {
+    /// Returns true if no issue changed.
+    pub fn is_empty(&self) -> bool {
+        self.assigned.is_empty() && self.updated.is_empty()
+    }
+
+    /// Returns text of the notification,
+    /// e.g. `Assigned to you: TST-2 | Updated: TST-3, TST-7`.
+    pub fn notification_text(&self) -> String {
+        [
+            ("Assigned to you", &self.assigned),
+            ("Updated", &self.updated),
+        ]
+        .into_iter()
+        .filter(|(_, keys)| !keys.is_empty())
+        .map(|(title, keys)| format!("{}: {}", title, keys.join(", ")))
+        .collect::<Vec<String>>()
+        .join(" | ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_text() {
+        let mut summary = RefreshSummary {
+            assigned: vec!["TST-2".into()],
+            updated: vec!["TST-3".into(), "TST-7".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            summary.notification_text(),
+            "Assigned to you: TST-2 | Updated: TST-3, TST-7"
+        );
+
+        summary.assigned.clear();
+        assert_eq!(summary.notification_text(), "Updated: TST-3, TST-7");
+        assert!(RefreshSummary::default().is_empty());
+    }
+
+    #[test]
+    fn test_check_window_minutes() {
+        assert_eq!(check_window_minutes(Duration::ZERO), 1);
+        assert_eq!(check_window_minutes(Duration::from_secs(300)), 6);
+        assert_eq!(check_window_minutes(Duration::from_secs(301)), 7);
+    }
+}
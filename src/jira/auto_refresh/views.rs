@@ -0,0 +1,164 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cursive::{
+    theme::Effect,
+    utils::markup::StyledString,
+    view::{Nameable, ViewWrapper},
+    views::{NamedView, TextView},
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::jira::{
+    common::views::{ChangeJiraView, JiraViewWithName},
+    constance::NOTIFICATION_SECONDS,
+    jira_data::JiraData,
+    tasks::views::{InfoView, TasksView},
+};
+
+use super::data::{check_window_minutes, UpdatedIssues};
+
+/// Line next to the bottom menu with the last notification.
+///
+/// Notification is hidden after `NOTIFICATION_SECONDS`.
+#[derive(ViewWrapper)]
+pub(crate) struct NotificationView {
+    inner_view: NamedView<TextView>,
+}
+
+impl Default for NotificationView {
+    fn default() -> Self {
+        Self {
+            inner_view: TextView::empty().with_name(Self::view_name()),
+        }
+    }
+}
+
+impl NotificationView {
+    fn view_name() -> String {
+        "NotificationView".into()
+    }
+
+    /// Shows the notification and hides it after a while.
+    ///
+    /// Newer notification isn't hidden by the timer of the older one.
+    pub fn show(cursive: &mut Cursive, text: &str) {
+        let notification = format!(" {text} ");
+        cursive.call_on_name(
+            &Self::view_name(),
+            |text_view: &mut TextView| {
+                text_view.set_content(StyledString::styled(
+                    notification.as_str(),
+                    Effect::Reverse,
+                ))
+            },
+        );
+        let cb_sink = cursive.cb_sink().clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_secs(NOTIFICATION_SECONDS));
+            let _ = cb_sink.send(Box::new(move |cursive| {
+                cursive.call_on_name(
+                    &Self::view_name(),
+                    |text_view: &mut TextView| {
+                        if text_view.get_content().source() == notification {
+                            text_view.set_content("");
+                        }
+                    },
+                );
+            }));
+        });
+    }
+}
+
+/// Starts checking for updated issues of the opened company
+/// every `auto_refresh_minutes` from the config.
+///
+/// Check of the company opened before is stopped.
+/// Does nothing if auto refresh is disabled.
+pub(crate) fn start_auto_refresh(cursive: &mut Cursive) {
+    let jira_data: Arc<RwLock<JiraData>> = cursive
+        .user_data::<Arc<RwLock<JiraData>>>()
+        .unwrap()
+        .clone();
+    let (minutes, stop) = {
+        let mut jira_data_guard = jira_data.write().unwrap();
+        let Some(minutes) = jira_data_guard.auto_refresh_minutes else {
+            return;
+        };
+        let stop = Arc::new(AtomicBool::new(false));
+        stop_auto_refresh(&mut jira_data_guard);
+        jira_data_guard.auto_refresh_stop = Some(stop.clone());
+        (minutes, stop)
+    };
+    let cb_sink = cursive.cb_sink().clone();
+    thread::spawn(move || {
+        // Issues are up to date when the refresh is started.
+        let mut last_check = Instant::now();
+        loop {
+            thread::sleep(Duration::from_secs(minutes * 60));
+            if stop.load(Ordering::Relaxed) {
+                break;
+            }
+            let check_start = Instant::now();
+            let window = check_window_minutes(last_check.elapsed());
+            // Failed check isn't shown, the next one can succeed
+            // and covers the time of the failed one.
+            let Ok(updated_issues) = UpdatedIssues::load(&jira_data, window)
+            else {
+                continue;
+            };
+            last_check = check_start;
+            let jira_data = jira_data.clone();
+            let send_result = cb_sink.send(Box::new(move |cursive| {
+                // The company could be closed while issues were loading.
+                let is_opened =
+                    cursive.user_data::<Arc<RwLock<JiraData>>>().is_some_and(
+                        |opened_data| Arc::ptr_eq(opened_data, &jira_data),
+                    );
+                if is_opened {
+                    apply_updated_issues(cursive, updated_issues);
+                }
+            }));
+            if send_result.is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Stops checking for updated issues of the company.
+pub(crate) fn stop_auto_refresh(jira_data: &mut JiraData) {
+    if let Some(stop) = jira_data.auto_refresh_stop.take() {
+        stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Puts updated issues into the opened company and shows changes:
+/// tasks list and task information are updated
+/// and changed issues are listed in the notification.
+pub(crate) fn apply_updated_issues(
+    cursive: &mut Cursive,
+    updated_issues: UpdatedIssues,
+) {
+    let jira_data: Arc<RwLock<JiraData>> = cursive
+        .user_data::<Arc<RwLock<JiraData>>>()
+        .unwrap()
+        .clone();
+    let summary = jira_data
+        .write()
+        .unwrap()
+        .apply_updated_issues(updated_issues);
+    if summary.is_empty() {
+        return;
+    }
+    if summary.is_selected_changed {
+        InfoView::get_view(cursive).update_view_content(cursive);
+    }
+    if summary.is_list_changed {
+        TasksView::refresh_tasks_list(cursive);
+    }
+    NotificationView::show(cursive, &summary.notification_text());
+}
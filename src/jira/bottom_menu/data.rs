@@ -1,35 +1,58 @@
-use cursive::{event::Event, Cursive};
+use cursive::Cursive;
 
-use crate::jira::{
-    common::button::{CallbackText, ShowOnlyCallback, StaticCallback},
-    menu::views::MenuView,
+use crate::{
+    jira::{
+        common::button::{
+            CallbackSection, CallbackText, ShowOnlyCallback, StaticCallback,
+        },
+        help::views::HelpView,
+        menu::views::MenuView,
+    },
+    keymap::{KeyAction, KeyChord, Keymap},
 };
 
-use super::helpers::build_tasks_action_view;
+use super::helpers::{build_tasks_action_view, toggle_history_tab};
 
 pub struct BottomButtons<'a> {
-    pub buttons: Vec<StaticCallback<'a, Event>>,
+    pub buttons: Vec<StaticCallback<'a, KeyChord>>,
 }
 
 impl<'a> BottomButtons<'a> {
-    pub fn new() -> Self {
-        let mut buttons: Vec<StaticCallback<'a, Event>> = Vec::default();
+    /// Creates buttons with keys from the keymap.
+    pub fn new(keymap: &Keymap) -> Self {
+        let mut buttons: Vec<StaticCallback<'a, KeyChord>> = Vec::default();
         buttons.push(StaticCallback::new(
-            Event::Char('a'),
-            "a - task actions",
+            keymap.key(KeyAction::TaskActions),
+            "task actions",
             build_tasks_action_view,
         ));
+        buttons.push(
+            StaticCallback::new(
+                keymap.key(KeyAction::History),
+                "history",
+                toggle_history_tab,
+            )
+            .in_section(CallbackSection::InfoView),
+        );
         buttons.push(StaticCallback::new(
-            Event::Char('m'),
-            "m - menu",
+            keymap.key(KeyAction::Menu),
+            "menu",
             |cursive: &mut Cursive| {
                 let menu = MenuView::new(cursive);
                 cursive.add_layer(menu);
             },
         ));
         buttons.push(StaticCallback::new(
-            Event::Char('q'),
-            "q - quit",
+            keymap.key(KeyAction::Help),
+            "help",
+            |cursive: &mut Cursive| {
+                let help_view = HelpView::new(cursive);
+                cursive.add_layer(help_view);
+            },
+        ));
+        buttons.push(StaticCallback::new(
+            keymap.key(KeyAction::Quit),
+            "quit",
             |cursive: &mut Cursive| {
                 cursive.quit();
             },
@@ -4,7 +4,8 @@ use cursive::{view::Nameable, Cursive};
 
 use crate::{
     jira::{
-        common::views::JiraViewWithName,
+        common::views::{ChangeJiraView, JiraViewWithName},
+        tasks::views::InfoView,
         tasks_actions::main_view::ActionsView,
         utils::views::FailedAttemptView,
     },
@@ -29,3 +30,30 @@ pub fn build_tasks_action_view(cursive: &mut Cursive) {
         cursive.add_layer(no_task_view);
     }
 }
+
+/// Switches `InfoView` between task details and history tabs.
+pub fn toggle_history_tab(cursive: &mut Cursive) {
+    let toggle_result = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let mut jira_data_guard = jira_data.write().unwrap();
+
+        if jira_data_guard.selected_task.is_empty() {
+            None
+        } else {
+            Some(jira_data_guard.toggle_history_tab())
+        }
+    };
+
+    match toggle_result {
+        Some(Ok(_)) => {
+            InfoView::get_view(cursive).update_view_content(cursive)
+        }
+        Some(Err(_)) => cursive.add_layer(FailedAttemptView::new(
+            "Can't load history of the task",
+        )),
+        None => {
+            cursive.add_layer(FailedAttemptView::new("Please select task"))
+        }
+    }
+}
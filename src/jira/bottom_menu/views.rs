@@ -5,6 +5,8 @@ use cursive::{
 };
 use rusji_derive::ViewWrapper;
 
+use crate::keymap::Keymap;
+
 use super::data::BottomButtons;
 
 #[derive(ViewWrapper)]
@@ -12,11 +14,12 @@ pub(crate) struct BottomMenuView {
     inner_view: TextView,
 }
 
-impl<'a> BottomMenuView {
-    pub fn default() -> Self {
+impl BottomMenuView {
+    /// Creates menu with keys from the keymap.
+    pub fn new(keymap: &Keymap) -> Self {
         Self {
             inner_view: TextView::new_with_content(TextContent::new(
-                BottomButtons::new().buttons_text(),
+                BottomButtons::new(keymap).buttons_text(),
             )),
         }
     }
@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use crate::keymap::{KeyAction, KeyContext};
+
+/// Command entered after `:`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Runs global action, the name is the same as in the keymap.
+    Action(KeyAction),
+    /// Opens issue by its key.
+    OpenIssue(String),
+}
+
+impl Command {
+    /// Returns commands with their descriptions, they're listed in the help.
+    pub fn help() -> [(&'static str, &'static str); 3] {
+        [
+            ("open KEY, o KEY", "open issue by its key"),
+            ("q", "quit"),
+            ("ACTION", "run global action by its name, e.g. history"),
+        ]
+    }
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    /// Parses command like `quit`, `task_actions` or `open TST-1`.
+    fn from_str(raw_command: &str) -> Result<Self, Self::Err> {
+        let mut words = raw_command.split_whitespace();
+        let command = match (words.next(), words.next(), words.next()) {
+            (Some("q"), None, _) => Self::Action(KeyAction::Quit),
+            (Some("open" | "o"), Some(issue_key), None) => {
+                Self::OpenIssue(issue_key.to_uppercase())
+            }
+            (Some(name), None, _) => KeyAction::from_config_name(name)
+                .filter(|action| {
+                    action.context() == KeyContext::Global
+                        && *action != KeyAction::Command
+                })
+                .map(Self::Action)
+                .ok_or_else(|| format!("Unknown command `{raw_command}`"))?,
+            _ => return Err(format!("Unknown command `{raw_command}`")),
+        };
+        Ok(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            "q".parse::<Command>().unwrap(),
+            Command::Action(KeyAction::Quit)
+        );
+        assert_eq!(
+            " task_actions ".parse::<Command>().unwrap(),
+            Command::Action(KeyAction::TaskActions)
+        );
+        assert_eq!(
+            "open tst-6".parse::<Command>().unwrap(),
+            Command::OpenIssue("TST-6".into())
+        );
+        assert_eq!(
+            "change_status".parse::<Command>().unwrap_err(),
+            "Unknown command `change_status`"
+        );
+        assert!("open".parse::<Command>().is_err());
+        assert!("".parse::<Command>().is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use cursive::{
+    view::{Nameable, Resizable, ViewWrapper},
+    views::{Dialog, EditView, NamedView, ResizedView},
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::jira::{
+    common::views::{JiraViewWithName, ToggleableView},
+    global_callbacks::run_global_action,
+    tasks::views::InfoView,
+    utils::{helpers::calculate_view_size, views::FailedAttemptView},
+};
+
+use super::data::Command;
+
+/// View for entering commands after `:`.
+#[derive(ViewWrapper)]
+pub struct CommandLineView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for CommandLineView {}
+
+impl JiraViewWithName for CommandLineView {
+    /// Returns name of the `CommandLineView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "CommandLineView".into()
+    }
+
+    /// Returns instance of `CommandLineView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl CommandLineView {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let command_view = EditView::new().on_submit(Self::on_submit_command);
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Enter command, e.g. `open TST-1` or `quit`")
+                .content(command_view)
+                .fixed_size(calculate_view_size(cursive, 1, 2))
+                .with_name(Self::view_name()),
+        }
+    }
+
+    /// Closes the command line and runs the command.
+    fn on_submit_command(cursive: &mut Cursive, raw_command: &str) {
+        let command = match raw_command.parse::<Command>() {
+            Ok(command) => command,
+            Err(error) => {
+                cursive.add_layer(FailedAttemptView::new(&error));
+                return;
+            }
+        };
+
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        match command {
+            Command::Action(action) => run_global_action(cursive, action),
+            Command::OpenIssue(issue_key) => {
+                InfoView::open_issue(cursive, &issue_key)
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::Cursive;
+
+use crate::{
+    jira::{
+        bottom_menu::data::BottomButtons,
+        global_callbacks::GlobalCallbacks,
+        menu::{data::MenuVariant, views::MenuView},
+        tasks_actions::buttons::build_buttons,
+        utils::helpers::fuzzy_match,
+    },
+    jira_data::JiraData,
+    keymap::{KeyAction, KeyChord, Keymap},
+};
+
+/// Action shown in the command palette.
+#[derive(Clone)]
+pub struct PaletteAction {
+    /// Where the action comes from, e.g. `task actions`.
+    pub group: &'static str,
+    pub name: String,
+    pub key: KeyChord,
+    /// Task actions can be run only if a task is selected.
+    pub is_task_required: bool,
+    pub action_fn: fn(&mut Cursive),
+}
+
+impl PaletteAction {
+    pub fn display_text(&self) -> String {
+        format!("{} - {} ({})", self.key, self.name, self.group)
+    }
+}
+
+/// All actions of the app.
+///
+/// Actions are collected from the global callbacks, the bottom menu,
+/// the task actions and the menu, so every action added there
+/// is shown in the palette too.
+pub struct PaletteActions {
+    pub actions: Vec<PaletteAction>,
+}
+
+impl PaletteActions {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        let keymap: Keymap = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.keymap.clone()
+        };
+
+        let global_actions = GlobalCallbacks::new(&keymap)
+            .callbacks
+            .into_iter()
+            .chain(BottomButtons::new(&keymap).buttons)
+            .map(|callback| PaletteAction {
+                group: "global",
+                name: callback.name.to_string(),
+                key: callback.event,
+                is_task_required: false,
+                action_fn: callback.action_fn,
+            });
+        let task_actions =
+            build_buttons(cursive).buttons.into_iter().map(|button| {
+                PaletteAction {
+                    group: "task actions",
+                    name: button.name.to_string(),
+                    key: button.event,
+                    is_task_required: true,
+                    action_fn: button.action_fn,
+                }
+            });
+        // Menu items are chosen in the menu itself.
+        let menu_actions =
+            MenuVariant::get_menu_variants()
+                .into_iter()
+                .map(|menu_variant| PaletteAction {
+                    group: "menu",
+                    name: menu_variant.to_string(),
+                    key: keymap.key(KeyAction::Menu),
+                    is_task_required: false,
+                    action_fn: |cursive: &mut Cursive| {
+                        let menu = MenuView::new(cursive);
+                        cursive.add_layer(menu);
+                    },
+                });
+
+        Self {
+            actions: global_actions
+                .chain(task_actions)
+                .chain(menu_actions)
+                .collect(),
+        }
+    }
+
+    /// Returns actions matching the `pattern`, the best matches go first.
+    ///
+    /// Name, group and key of the action are searched.
+    pub fn filter(&self, pattern: &str) -> Vec<PaletteAction> {
+        let mut scored_actions: Vec<(i64, &PaletteAction)> = self
+            .actions
+            .iter()
+            .filter_map(|action| {
+                fuzzy_match(pattern, &action.name)
+                    .or_else(|| fuzzy_match(pattern, &action.display_text()))
+                    .map(|score| (score, action))
+            })
+            .collect();
+        // Sort is stable, so equal matches keep the original order.
+        scored_actions
+            .sort_by(|(score, _), (other_score, _)| other_score.cmp(score));
+        scored_actions
+            .into_iter()
+            .map(|(_, action)| action.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, Scrollable, ViewWrapper},
+    views::{
+        Dialog, EditView, LinearLayout, NamedView, ResizedView, SelectView,
+    },
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::views::{JiraViewWithName, ToggleableView},
+        constance::INNER_LEFT_TOP_VIEW_ALIGN,
+        utils::{helpers::calculate_view_size, views::FailedAttemptView},
+    },
+    jira_data::JiraData,
+};
+
+use super::data::{PaletteAction, PaletteActions};
+
+/// View with fuzzy search through all actions of the app.
+#[derive(ViewWrapper)]
+pub struct CommandPaletteView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for CommandPaletteView {}
+
+impl JiraViewWithName for CommandPaletteView {
+    /// Returns name of the `CommandPaletteView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "CommandPaletteView".into()
+    }
+
+    /// Returns instance of `CommandPaletteView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl CommandPaletteView {
+    /// Creates new `CommandPaletteView`.
+    ///
+    /// Submit in the search field runs the best match.
+    pub fn new(cursive: &mut Cursive) -> Self {
+        let palette_actions = PaletteActions::new(cursive);
+        Self::toggle_on_view(cursive);
+
+        let actions_view = SelectView::<PaletteAction>::new()
+            .align(INNER_LEFT_TOP_VIEW_ALIGN)
+            .with_all(Self::make_select_items(palette_actions.filter("")))
+            .on_submit(Self::run_action)
+            .with_name(Self::select_view_name());
+        let search_view = EditView::new()
+            .on_edit(move |cursive: &mut Cursive, pattern: &str, _: usize| {
+                let select_items =
+                    Self::make_select_items(palette_actions.filter(pattern));
+                cursive.call_on_name(
+                    &Self::select_view_name(),
+                    |select_view: &mut SelectView<PaletteAction>| {
+                        select_view.clear();
+                        select_view.add_all(select_items);
+                    },
+                );
+            })
+            .on_submit(|cursive: &mut Cursive, _: &str| {
+                let best_action = cursive
+                    .call_on_name(
+                        &Self::select_view_name(),
+                        |select_view: &mut SelectView<PaletteAction>| {
+                            select_view
+                                .get_item(0)
+                                .map(|(_, action)| action.clone())
+                        },
+                    )
+                    .flatten();
+                if let Some(action) = best_action {
+                    Self::run_action(cursive, &action);
+                }
+            });
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Command palette")
+                .content(
+                    LinearLayout::vertical()
+                        .child(
+                            Dialog::new()
+                                .title("Search action")
+                                .content(search_view),
+                        )
+                        .child(actions_view.scrollable()),
+                )
+                .fixed_size(calculate_view_size(cursive, 3, 5))
+                .with_name(Self::view_name()),
+        }
+    }
+
+    /// Returns name of the SelectView with actions.
+    fn select_view_name() -> String {
+        "CommandPaletteSelectView".into()
+    }
+
+    fn make_select_items(
+        actions: Vec<PaletteAction>,
+    ) -> Vec<(String, PaletteAction)> {
+        actions
+            .into_iter()
+            .map(|action| (action.display_text(), action))
+            .collect()
+    }
+
+    /// Closes the palette and runs the action.
+    fn run_action(cursive: &mut Cursive, action: &PaletteAction) {
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+
+        let is_task_selected = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            !jira_data_guard.selected_task.is_empty()
+        };
+        if action.is_task_required && !is_task_selected {
+            cursive.add_layer(FailedAttemptView::new("Please select task"));
+            return;
+        }
+        (action.action_fn)(cursive);
+    }
+}
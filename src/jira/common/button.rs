@@ -1,7 +1,61 @@
+use std::fmt::Display;
+
 use cursive::{event::Event, Cursive};
 
+use crate::keymap::KeyChord;
+
 use super::buttons_variants::ButtonVariant;
 
+/// Part of the screen where a callback is used.
+///
+/// Key bindings are grouped by it in the help.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CallbackSection {
+    /// Keys which work everywhere.
+    #[default]
+    Global,
+    /// Keys which change the tasks list.
+    TaskList,
+    /// Keys which change the task information.
+    InfoView,
+    /// Keys of the task actions list, every one opens an action dialog.
+    TaskActions,
+    /// Keys which work in the action dialogs.
+    ActionDialogs,
+    /// Keys of the recent issues and bookmarks view.
+    RecentIssues,
+    /// Commands of the command line, they aren't keys.
+    Commands,
+}
+
+impl CallbackSection {
+    /// Returns all sections in the order they are shown in the help.
+    pub fn all() -> [Self; 7] {
+        [
+            Self::Global,
+            Self::TaskList,
+            Self::InfoView,
+            Self::TaskActions,
+            Self::ActionDialogs,
+            Self::RecentIssues,
+            Self::Commands,
+        ]
+    }
+
+    /// Returns title of the section in the help.
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Global => "Global",
+            Self::TaskList => "Task list",
+            Self::InfoView => "Task information",
+            Self::TaskActions => "Task actions",
+            Self::ActionDialogs => "Action dialogs",
+            Self::RecentIssues => "Recent issues",
+            Self::Commands => "Commands",
+        }
+    }
+}
+
 /// Every callback must have display text.
 pub trait CallbackText {
     fn display_text(&self) -> String;
@@ -52,7 +106,7 @@ where
     Variant: ButtonVariant<'a>,
 {
     pub variant: Variant,
-    pub event: char,
+    pub event: KeyChord,
     pub name: &'a str,
     pub action_fn: fn(&mut Cursive),
 }
@@ -67,14 +121,14 @@ where
     }
 }
 
-impl<'a, Variant> ClickableCallback<'a, Variant, char>
+impl<'a, Variant> ClickableCallback<'a, Variant, KeyChord>
     for CallbackWithButton<'a, Variant>
 where
     Variant: ButtonVariant<'a>,
 {
     fn new<ToStr>(
         variant: Variant,
-        event: char,
+        event: KeyChord,
         name: ToStr,
         action_fn: fn(&mut Cursive),
     ) -> Self
@@ -101,21 +155,35 @@ where
     pub event: CursiveEvent,
     pub name: &'a str,
     pub action_fn: fn(&mut Cursive),
+    /// Section of the help where the callback is listed.
+    pub section: CallbackSection,
 }
 
-impl<'a, CursiveEvent> CallbackText for StaticCallback<'a, CursiveEvent>
+impl<'a, CursiveEvent> StaticCallback<'a, CursiveEvent>
 where
     CursiveEvent: Into<Event>,
 {
+    /// Moves the callback into the `section` of the help.
+    pub fn in_section(mut self, section: CallbackSection) -> Self {
+        self.section = section;
+        self
+    }
+}
+
+impl<'a, CursiveEvent> CallbackText for StaticCallback<'a, CursiveEvent>
+where
+    CursiveEvent: Into<Event> + Display,
+{
+    /// Returns text with the key, so it's always actual.
     fn display_text(&self) -> String {
-        self.name.into()
+        format!("{} - {}", self.event, self.name)
     }
 }
 
 impl<'a, CursiveEvent> ShowOnlyCallback<'a, CursiveEvent>
     for StaticCallback<'a, CursiveEvent>
 where
-    CursiveEvent: Into<Event>,
+    CursiveEvent: Into<Event> + Display,
 {
     fn new<ToStr>(
         event: CursiveEvent,
@@ -130,6 +198,7 @@ where
             event,
             name: name.into(),
             action_fn,
+            section: CallbackSection::default(),
         }
     }
 }
@@ -33,11 +33,6 @@ pub trait ChangeJiraView {
     ///
     /// Default implementation does nothing.
     fn update_view_content(&mut self, _cursive: &mut Cursive) {}
-
-    /// Extends view content with passed `content`.
-    ///
-    /// Default implementation does nothing.
-    fn add_content_to_view(&mut self, _content: Vec<&str>) {}
 }
 
 // TODO: Move ToggleableView into derive.
@@ -8,7 +8,15 @@ pub(crate) const INNER_LEFT_TOP_VIEW_ALIGN: align::Align = align::Align {
     h: align::HAlign::Left,
     v: align::VAlign::Top,
 };
-pub(crate) const INNER_RIGHT_TOP_VIEW_ALIGN: align::Align = align::Align {
-    h: align::HAlign::Right,
-    v: align::VAlign::Top,
-};
+
+/// Max amount of issues loaded for one project.
+pub(crate) const ISSUES_LOAD_LIMIT: usize = 1000;
+
+/// Amount of changes shown on one page of the issue history.
+pub(crate) const HISTORY_PAGE_SIZE: usize = 10;
+
+/// Max amount of changelog histories Jira returns in one response.
+pub(crate) const CHANGELOG_REQUEST_SIZE: usize = 100;
+
+/// Seconds before the notification is hidden.
+pub(crate) const NOTIFICATION_SECONDS: u64 = 10;
@@ -0,0 +1,33 @@
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::RusjiResult, request_client::client::RequestClient};
+
+/// JQL query with a name.
+///
+/// Local filters are saved per company in the config,
+/// favourite filters come from Jira.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct JqlFilter {
+    pub name: String,
+    pub jql: String,
+}
+
+impl JqlFilter {
+    pub fn new(name: &str, jql: &str) -> Self {
+        Self {
+            name: name.into(),
+            jql: jql.into(),
+        }
+    }
+
+    /// Returns favourite filters of the current user from Jira.
+    pub fn favourite(
+        request_client: Arc<RwLock<RequestClient>>,
+    ) -> RusjiResult<Vec<Self>> {
+        let response =
+            request_client.read().unwrap().get_favourite_filters()?;
+        response.parse::<Vec<Self>>()
+    }
+}
@@ -0,0 +1,217 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Finder, Nameable, Resizable, ViewWrapper},
+    views::{
+        Dialog, EditView, LinearLayout, NamedView, ScrollView, SelectView,
+        TextView,
+    },
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::views::{
+            JiraViewWithName, JiraWithDialogView, ToggleableView,
+        },
+        tasks::{
+            data::{JiraIssue, JiraIssues},
+            indicators::task_label,
+            views::InfoView,
+        },
+        utils::views::FailedAttemptView,
+    },
+    jira_data::JiraData,
+    Config,
+};
+
+use super::data::JqlFilter;
+
+/// View with issues found by the filter.
+#[derive(ViewWrapper)]
+pub(crate) struct FilterView {
+    inner_view: NamedView<Dialog>,
+}
+
+impl JiraViewWithName for FilterView {
+    fn view_name() -> String {
+        "FilterView".into()
+    }
+
+    fn get_view(cursive: &mut Cursive) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl JiraWithDialogView for FilterView {
+    fn main_dialog_name() -> String {
+        "FilterViewDialogName".into()
+    }
+
+    fn get_main_dialog(&mut self) -> cursive::views::ViewRef<Dialog> {
+        self.find_name(&Self::main_dialog_name()).unwrap()
+    }
+}
+
+impl ToggleableView for FilterView {}
+
+impl FilterView {
+    /// Runs the filter and shows found issues.
+    pub fn show(cursive: &mut Cursive, filter: &JqlFilter) {
+        let client = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.client.clone()
+        };
+        match JiraIssues::search(client, &filter.jql) {
+            Ok(issues) => {
+                let filter_view =
+                    Self::new(cursive, &filter.name, issues.into_iter());
+                cursive.add_layer(filter_view);
+            }
+            Err(_) => cursive.add_layer(FailedAttemptView::new(&format!(
+                "Can't run filter `{}`",
+                filter.name
+            ))),
+        }
+    }
+
+    pub fn new<I>(cursive: &mut Cursive, filter_name: &str, issues: I) -> Self
+    where
+        I: Iterator<Item = JiraIssue>,
+    {
+        Self::toggle_on_view(cursive);
+        let colors = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.colors
+        };
+
+        let issues_select_view =
+            SelectView::<String>::new()
+                .with_all(issues.map(|issue| {
+                    (task_label(&issue, &[], &colors), issue.key)
+                }))
+                .on_submit(|cursive, issue_key: &String| {
+                    Self::open_issue(cursive, issue_key)
+                });
+        let dialog = Dialog::new().title(filter_name).padding_lrtb(1, 1, 1, 1);
+        let dialog = if issues_select_view.is_empty() {
+            dialog.content(TextView::new("No issues found"))
+        } else {
+            dialog.content(ScrollView::new(issues_select_view))
+        };
+
+        Self {
+            inner_view: dialog.with_name(Self::main_dialog_name()),
+        }
+    }
+
+    /// Opens the issue, it can be from another project.
+    fn open_issue(cursive: &mut Cursive, issue_key: &str) {
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        InfoView::open_issue(cursive, issue_key);
+    }
+}
+
+/// View to save JQL query as a filter of the company.
+#[derive(ViewWrapper)]
+pub(crate) struct SaveFilterView {
+    inner_view: NamedView<Dialog>,
+}
+
+impl JiraViewWithName for SaveFilterView {
+    fn view_name() -> String {
+        "SaveFilterView".into()
+    }
+
+    fn get_view(cursive: &mut Cursive) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl JiraWithDialogView for SaveFilterView {
+    fn main_dialog_name() -> String {
+        "SaveFilterViewDialogName".into()
+    }
+
+    fn get_main_dialog(&mut self) -> cursive::views::ViewRef<Dialog> {
+        self.find_name(&Self::main_dialog_name()).unwrap()
+    }
+}
+
+impl ToggleableView for SaveFilterView {}
+
+impl SaveFilterView {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let inner_layout = LinearLayout::vertical()
+            .child(TextView::new("Name"))
+            .child(EditView::new().with_name(Self::name_view_name()))
+            .child(TextView::new("JQL"))
+            .child(EditView::new().with_name(Self::jql_view_name()));
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Save JQL filter")
+                .padding_lrtb(1, 1, 1, 1)
+                .content(inner_layout.min_width(60))
+                .button("Save", Self::on_save)
+                .with_name(Self::main_dialog_name()),
+        }
+    }
+
+    fn name_view_name() -> String {
+        "SaveFilterNameView".into()
+    }
+
+    fn jql_view_name() -> String {
+        "SaveFilterJqlView".into()
+    }
+
+    /// Saves the filter in the config, so it's shown in the menu.
+    fn on_save(cursive: &mut Cursive) {
+        let mut get_content = |view_name: &str| {
+            cursive
+                .call_on_name(view_name, |edit_view: &mut EditView| {
+                    edit_view.get_content().trim().to_string()
+                })
+                .unwrap_or_default()
+        };
+        let filter = JqlFilter::new(
+            &get_content(&Self::name_view_name()),
+            &get_content(&Self::jql_view_name()),
+        );
+        if filter.name.is_empty() || filter.jql.is_empty() {
+            cursive.add_layer(FailedAttemptView::new(
+                "Name and JQL of the filter can't be empty",
+            ));
+            return;
+        }
+
+        let jira_data: Arc<RwLock<JiraData>> = cursive
+            .user_data::<Arc<RwLock<JiraData>>>()
+            .unwrap()
+            .clone();
+        let company_name = jira_data.read().unwrap().company_name.clone();
+        let save_result = Config::new().and_then(|mut config| {
+            config.save_filter(&company_name, filter)?;
+            Ok(config)
+        });
+        match save_result {
+            Ok(config) => {
+                jira_data.write().unwrap().saved_filters =
+                    config.get_saved_filters(&company_name).to_vec();
+                Self::toggle_off_view(cursive);
+                cursive.pop_layer();
+            }
+            Err(err) => cursive.add_layer(FailedAttemptView::new(&format!(
+                "Can't save the filter: {err}"
+            ))),
+        }
+    }
+}
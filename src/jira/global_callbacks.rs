@@ -1,8 +1,21 @@
 use std::sync::{Arc, RwLock};
 
 use crate::{
-    jira::{projects::views::ProjectsView, tasks::views::TasksView},
+    jira::{
+        command_line::views::CommandLineView,
+        command_palette::views::CommandPaletteView,
+        common::button::{CallbackSection, ShowOnlyCallback, StaticCallback},
+        common::views::{ChangeJiraView, JiraViewWithName},
+        input_mode::{is_normal_mode, set_input_mode, InputMode},
+        projects::views::ProjectsView,
+        recent_issues::views::RecentIssuesView,
+        tasks::{
+            ordering::TaskListOrder,
+            views::{InfoView, TasksSelectView, TasksView},
+        },
+    },
     jira_data::JiraData,
+    keymap::{KeyAction, KeyChord, Keymap},
 };
 use cursive::{
     event::{Event, Key},
@@ -11,37 +24,278 @@ use cursive::{
 
 use super::bottom_menu::data::BottomButtons;
 
-pub(crate) fn add_global_callbacks(cursive: &mut Cursive) {
-    cursive.add_global_callback('p', |cursive| {
-        cursive
-            .focus_name(&ProjectsView::search_view_name())
-            .unwrap();
-    });
-    cursive.add_global_callback('i', |cursive| {
-        cursive.focus_name(&TasksView::search_view_name()).unwrap();
-    });
-
-    cursive.add_global_callback(
-        Event::Key(Key::Esc),
-        |cursive: &mut Cursive| {
-            let is_need_to_hide: bool = {
-                let jira_data: &mut Arc<RwLock<JiraData>> =
-                    cursive.user_data().unwrap();
-                let mut jira_data_guard = jira_data.write().unwrap();
-                jira_data_guard.activated_views.pop().is_some()
-            };
-            if is_need_to_hide {
-                cursive.pop_layer();
-            }
-        },
+/// Global callbacks which aren't shown in the bottom menu.
+pub struct GlobalCallbacks<'a> {
+    pub callbacks: Vec<StaticCallback<'a, KeyChord>>,
+}
+
+impl<'a> GlobalCallbacks<'a> {
+    /// Creates callbacks with keys from the keymap.
+    pub fn new(keymap: &Keymap) -> Self {
+        let callbacks: Vec<StaticCallback<'a, KeyChord>> = vec![
+            StaticCallback::new(
+                keymap.key(KeyAction::FocusProjects),
+                "search projects",
+                focus_projects_search,
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::FocusTasks),
+                "search tasks",
+                focus_tasks_search,
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::Search),
+                "search in the current list",
+                focus_search,
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::Command),
+                "command line",
+                |cursive: &mut Cursive| {
+                    let command_line = CommandLineView::new(cursive);
+                    cursive.add_layer(command_line);
+                },
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::CommandPalette),
+                "command palette",
+                |cursive: &mut Cursive| {
+                    let command_palette = CommandPaletteView::new(cursive);
+                    cursive.add_layer(command_palette);
+                },
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::CloseView),
+                "close view or leave search",
+                close_view,
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::MoveDown),
+                "next item",
+                |cursive: &mut Cursive| cursive.on_event(Key::Down.into()),
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::MoveUp),
+                "previous item",
+                |cursive: &mut Cursive| cursive.on_event(Key::Up.into()),
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::MoveTop),
+                "first item",
+                |cursive: &mut Cursive| cursive.on_event(Key::Home.into()),
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::MoveBottom),
+                "last item",
+                |cursive: &mut Cursive| cursive.on_event(Key::End.into()),
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::PageDown),
+                "scroll description down",
+                |cursive: &mut Cursive| {
+                    InfoView::scroll_description(cursive, true)
+                },
+            )
+            .in_section(CallbackSection::InfoView),
+            StaticCallback::new(
+                keymap.key(KeyAction::PageUp),
+                "scroll description up",
+                |cursive: &mut Cursive| {
+                    InfoView::scroll_description(cursive, false)
+                },
+            )
+            .in_section(CallbackSection::InfoView),
+            StaticCallback::new(
+                keymap.key(KeyAction::SortTasks),
+                "change tasks sort",
+                |cursive: &mut Cursive| {
+                    change_task_list_order(cursive, |order| {
+                        order.sort = order.sort.next()
+                    })
+                },
+            )
+            .in_section(CallbackSection::TaskList),
+            StaticCallback::new(
+                keymap.key(KeyAction::GroupTasks),
+                "change tasks grouping",
+                |cursive: &mut Cursive| {
+                    change_task_list_order(cursive, |order| {
+                        order.group = order.group.next()
+                    })
+                },
+            )
+            .in_section(CallbackSection::TaskList),
+            StaticCallback::new(
+                keymap.key(KeyAction::RecentIssues),
+                "recent issues and bookmarks",
+                |cursive: &mut Cursive| {
+                    let recent_issues_view = RecentIssuesView::new(cursive);
+                    cursive.add_layer(recent_issues_view);
+                },
+            ),
+            StaticCallback::new(
+                keymap.key(KeyAction::ToggleBookmark),
+                "bookmark task",
+                toggle_bookmark,
+            )
+            .in_section(CallbackSection::TaskList),
+        ];
+
+        Self { callbacks }
+    }
+}
+
+/// Adds callbacks for the keys from the keymap.
+///
+/// Callbacks work only in normal mode,
+/// except the one for closing views which also leaves insert mode.
+pub(crate) fn add_global_callbacks(cursive: &mut Cursive, keymap: &Keymap) {
+    let close_view_key = keymap.key(KeyAction::CloseView);
+    let callbacks = GlobalCallbacks::new(keymap)
+        .callbacks
+        .into_iter()
+        .chain(BottomButtons::new(keymap).buttons);
+
+    for callback in callbacks {
+        let action_fn = callback.action_fn;
+        if callback.event == close_view_key {
+            cursive
+                .add_global_callback(Event::from(callback.event), action_fn);
+        } else {
+            cursive.add_global_callback(
+                Event::from(callback.event),
+                move |cursive: &mut Cursive| {
+                    if is_normal_mode(cursive) {
+                        action_fn(cursive);
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Removes callbacks added by `add_global_callbacks`.
+pub(crate) fn remove_global_callbacks(cursive: &mut Cursive, keymap: &Keymap) {
+    let events = GlobalCallbacks::new(keymap)
+        .callbacks
+        .into_iter()
+        .chain(BottomButtons::new(keymap).buttons)
+        .map(|callback| callback.event);
+    for event in events {
+        cursive.clear_global_callbacks(Event::from(event));
+    }
+}
+
+/// Runs callback of the global action, e.g. from the command line.
+///
+/// Callback is found by the key, so the same one as for the key is run.
+pub(crate) fn run_global_action(cursive: &mut Cursive, action: KeyAction) {
+    let keymap: Keymap = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let jira_data_guard = jira_data.read().unwrap();
+        jira_data_guard.keymap.clone()
+    };
+    let key = keymap.key(action);
+    let action_fn = GlobalCallbacks::new(&keymap)
+        .callbacks
+        .into_iter()
+        .chain(BottomButtons::new(&keymap).buttons)
+        .find(|callback| callback.event == key)
+        .map(|callback| callback.action_fn);
+    if let Some(action_fn) = action_fn {
+        action_fn(cursive);
+    }
+}
+
+fn focus_projects_search(cursive: &mut Cursive) {
+    cursive
+        .focus_name(&ProjectsView::search_view_name())
+        .unwrap();
+    set_input_mode(
+        cursive,
+        InputMode::Insert(ProjectsView::select_view_name()),
     );
+}
 
-    add_menu_callbacks(cursive);
+fn focus_tasks_search(cursive: &mut Cursive) {
+    cursive.focus_name(&TasksView::search_view_name()).unwrap();
+    set_input_mode(cursive, InputMode::Insert(TasksSelectView::view_name()));
 }
 
-pub(crate) fn add_menu_callbacks(cursive: &mut Cursive) {
-    for bottom_button in BottomButtons::new().buttons.into_iter() {
-        cursive
-            .add_global_callback(bottom_button.event, bottom_button.action_fn)
+/// Focuses search of tasks if a project is selected,
+/// otherwise search of projects.
+fn focus_search(cursive: &mut Cursive) {
+    let is_project_selected = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let jira_data_guard = jira_data.read().unwrap();
+        jira_data_guard.get_selected_project().is_some()
+    };
+    if is_project_selected {
+        focus_tasks_search(cursive);
+    } else {
+        focus_projects_search(cursive);
+    }
+}
+
+/// Changes order of the tasks list if a project is selected.
+fn change_task_list_order<F>(cursive: &mut Cursive, change_fn: F)
+where
+    F: FnOnce(&mut TaskListOrder),
+{
+    let is_changed = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let mut jira_data_guard = jira_data.write().unwrap();
+        let is_tasks_loaded = jira_data_guard
+            .get_selected_project()
+            .is_some_and(|project| project.tasks.is_some());
+        if is_tasks_loaded {
+            jira_data_guard.change_task_list_order(change_fn);
+        }
+        is_tasks_loaded
+    };
+    if is_changed {
+        TasksView::get_view(cursive).update_view_content(cursive);
+    }
+}
+
+/// Bookmarks the selected task or removes its bookmark.
+fn toggle_bookmark(cursive: &mut Cursive) {
+    let is_toggled = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let mut jira_data_guard = jira_data.write().unwrap();
+        jira_data_guard.toggle_bookmark().is_some()
+    };
+    if is_toggled {
+        InfoView::get_view(cursive).update_view_content(cursive);
+    }
+}
+
+/// Leaves insert mode or closes the last opened view.
+fn close_view(cursive: &mut Cursive) {
+    let input_mode = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let jira_data_guard = jira_data.read().unwrap();
+        jira_data_guard.input_mode.clone()
+    };
+    if let InputMode::Insert(list_view_name) = input_mode {
+        // List can't be focused if it's empty, the mode is left anyway.
+        let _ = cursive.focus_name(&list_view_name);
+        set_input_mode(cursive, InputMode::Normal);
+        return;
+    }
+
+    let is_need_to_hide: bool = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let mut jira_data_guard = jira_data.write().unwrap();
+        jira_data_guard.activated_views.pop().is_some()
+    };
+    if is_need_to_hide {
+        cursive.pop_layer();
     }
 }
@@ -0,0 +1,253 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::Cursive;
+
+use crate::{
+    jira::{
+        bottom_menu::data::BottomButtons,
+        command_line::data::Command,
+        common::button::{CallbackSection, StaticCallback},
+        global_callbacks::GlobalCallbacks,
+        recent_issues::views::RecentIssuesView,
+        tasks_actions::{
+            buttons::build_buttons, change_assignee_views::ChangeAssigneeView,
+        },
+    },
+    jira_data::JiraData,
+    keymap::{KeyChord, Keymap},
+};
+
+/// Key binding shown in the help.
+///
+/// Commands are shown in the same way, the command is used as the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpBinding {
+    pub key: String,
+    pub name: String,
+}
+
+/// Key bindings of the app grouped by sections.
+///
+/// Bindings are collected from the same callbacks which are added
+/// to the app, so the help always shows the actual keys.
+pub struct HelpSections {
+    pub sections: Vec<(CallbackSection, Vec<HelpBinding>)>,
+}
+
+impl HelpSections {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        let keymap: Keymap = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.keymap.clone()
+        };
+        // Task actions are named as in their list.
+        let task_actions =
+            build_buttons(cursive).buttons.into_iter().map(|button| {
+                let name: &str = button.variant.into();
+                (
+                    CallbackSection::TaskActions,
+                    HelpBinding {
+                        key: button.event.to_string(),
+                        name: name.to_string(),
+                    },
+                )
+            });
+        Self::from_bindings(
+            Self::global_bindings(&keymap)
+                .into_iter()
+                .chain(task_actions)
+                .chain(Self::dialog_bindings(&keymap)),
+        )
+    }
+
+    /// Returns bindings of the global callbacks and the bottom menu.
+    fn global_bindings(
+        keymap: &Keymap,
+    ) -> Vec<(CallbackSection, HelpBinding)> {
+        Self::callback_bindings(
+            GlobalCallbacks::new(keymap)
+                .callbacks
+                .into_iter()
+                .chain(BottomButtons::new(keymap).buttons),
+        )
+    }
+
+    /// Returns bindings of the dialogs and commands of the command line.
+    fn dialog_bindings(
+        keymap: &Keymap,
+    ) -> Vec<(CallbackSection, HelpBinding)> {
+        let commands = Command::help().into_iter().map(|(command, name)| {
+            (
+                CallbackSection::Commands,
+                HelpBinding {
+                    key: format!(":{command}"),
+                    name: name.to_string(),
+                },
+            )
+        });
+        Self::callback_bindings(
+            ChangeAssigneeView::callbacks(keymap)
+                .into_iter()
+                .chain(RecentIssuesView::callbacks()),
+        )
+        .into_iter()
+        .chain(commands)
+        .collect()
+    }
+
+    /// Returns bindings of the callbacks in their sections.
+    fn callback_bindings<'a, I>(
+        callbacks: I,
+    ) -> Vec<(CallbackSection, HelpBinding)>
+    where
+        I: IntoIterator<Item = StaticCallback<'a, KeyChord>>,
+    {
+        callbacks
+            .into_iter()
+            .map(|callback| {
+                (
+                    callback.section,
+                    HelpBinding {
+                        key: callback.event.to_string(),
+                        name: callback.name.to_string(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Groups bindings by sections, sections without bindings are skipped.
+    fn from_bindings<I>(bindings: I) -> Self
+    where
+        I: IntoIterator<Item = (CallbackSection, HelpBinding)>,
+    {
+        let bindings: Vec<(CallbackSection, HelpBinding)> =
+            bindings.into_iter().collect();
+        let sections = CallbackSection::all()
+            .into_iter()
+            .map(|section| {
+                let section_bindings: Vec<HelpBinding> = bindings
+                    .iter()
+                    .filter(|(binding_section, _)| *binding_section == section)
+                    .map(|(_, binding)| binding.clone())
+                    .collect();
+                (section, section_bindings)
+            })
+            .filter(|(_, section_bindings)| !section_bindings.is_empty())
+            .collect();
+        Self { sections }
+    }
+
+    /// Returns width of the longest key of the section,
+    /// so names of the section are aligned.
+    pub fn key_width(bindings: &[HelpBinding]) -> usize {
+        bindings
+            .iter()
+            .map(|binding| binding.key.chars().count())
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::keymap::KeyAction;
+
+    use super::*;
+
+    #[test]
+    fn test_help_sections() {
+        let keymap =
+            serde_json::from_str::<Keymap>(r#"{"sort_tasks": "S"}"#).unwrap();
+        let help_sections = HelpSections::from_bindings(
+            HelpSections::global_bindings(&keymap),
+        );
+        let section_names: Vec<(CallbackSection, Vec<String>)> = help_sections
+            .sections
+            .iter()
+            .map(|(section, bindings)| {
+                let names = bindings
+                    .iter()
+                    .map(|binding| format!("{} {}", binding.key, binding.name))
+                    .collect();
+                (*section, names)
+            })
+            .collect();
+
+        assert_eq!(section_names.len(), 3);
+        assert_eq!(section_names[0].0, CallbackSection::Global);
+        assert!(section_names[0].1.contains(&"? help".to_string()));
+        assert_eq!(
+            section_names[1],
+            (
+                CallbackSection::TaskList,
+                vec![
+                    "S change tasks sort".to_string(),
+                    "b change tasks grouping".to_string(),
+                    "f bookmark task".to_string(),
+                ]
+            )
+        );
+        assert_eq!(
+            section_names[2],
+            (
+                CallbackSection::InfoView,
+                vec![
+                    "ctrl+d scroll description down".to_string(),
+                    "ctrl+u scroll description up".to_string(),
+                    "h history".to_string(),
+                ]
+            )
+        );
+        assert_eq!(
+            HelpSections::key_width(&help_sections.sections[0].1),
+            keymap.key(KeyAction::CommandPalette).to_string().len()
+        );
+    }
+
+    #[test]
+    fn test_dialog_help_sections() {
+        let keymap =
+            serde_json::from_str::<Keymap>(r#"{"unassign": "ctrl+n"}"#)
+                .unwrap();
+        let help_sections = HelpSections::from_bindings(
+            HelpSections::dialog_bindings(&keymap),
+        );
+        let sections: Vec<CallbackSection> = help_sections
+            .sections
+            .iter()
+            .map(|(section, _)| *section)
+            .collect();
+        assert_eq!(
+            sections,
+            vec![
+                CallbackSection::ActionDialogs,
+                CallbackSection::RecentIssues,
+                CallbackSection::Commands,
+            ]
+        );
+
+        let binding_names = |idx: usize| -> Vec<String> {
+            help_sections.sections[idx]
+                .1
+                .iter()
+                .map(|binding| format!("{} {}", binding.key, binding.name))
+                .collect()
+        };
+        assert_eq!(
+            binding_names(0),
+            vec![
+                "ctrl+a assign issue to me".to_string(),
+                "ctrl+n unassign issue".to_string(),
+            ]
+        );
+        let recent_issues = binding_names(1);
+        assert_eq!(recent_issues.len(), 9);
+        assert_eq!(recent_issues[0], "1 open issue 1");
+        assert_eq!(recent_issues[8], "9 open issue 9");
+        assert!(binding_names(2)
+            .contains(&":open KEY, o KEY open issue by its key".to_string()));
+    }
+}
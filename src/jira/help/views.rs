@@ -0,0 +1,82 @@
+use cursive::{
+    theme::Effect,
+    utils::markup::StyledString,
+    view::{Finder, Nameable, ViewWrapper},
+    views::{Dialog, NamedView, ScrollView, TextView},
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::jira::common::views::{
+    JiraViewWithName, JiraWithDialogView, ToggleableView,
+};
+
+use super::data::HelpSections;
+
+/// View with every key binding of the app grouped by sections.
+#[derive(ViewWrapper)]
+pub(crate) struct HelpView {
+    inner_view: NamedView<Dialog>,
+}
+
+impl JiraViewWithName for HelpView {
+    fn view_name() -> String {
+        "HelpView".into()
+    }
+
+    fn get_view(cursive: &mut Cursive) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl JiraWithDialogView for HelpView {
+    fn main_dialog_name() -> String {
+        "HelpViewDialogName".into()
+    }
+
+    fn get_main_dialog(&mut self) -> cursive::views::ViewRef<Dialog> {
+        self.find_name(&Self::main_dialog_name()).unwrap()
+    }
+}
+
+impl ToggleableView for HelpView {}
+
+impl HelpView {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let help_sections = HelpSections::new(cursive);
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Key bindings")
+                .padding_lrtb(1, 1, 0, 0)
+                .content(ScrollView::new(TextView::new(Self::help_text(
+                    &help_sections,
+                ))))
+                .with_name(Self::main_dialog_name()),
+        }
+    }
+
+    /// Returns sections with bold titles and a key binding per row,
+    /// e.g. `ctrl+p  command palette`.
+    fn help_text(help_sections: &HelpSections) -> StyledString {
+        let mut text = StyledString::new();
+        for (idx, (section, bindings)) in
+            help_sections.sections.iter().enumerate()
+        {
+            let key_width = HelpSections::key_width(bindings);
+            if idx > 0 {
+                text.append_plain("\n\n");
+            }
+            text.append_styled(section.title(), Effect::Bold);
+            for binding in bindings {
+                text.append_plain(format!(
+                    "\n  {:<key_width$}  {}",
+                    binding.key.to_string(),
+                    binding.name
+                ));
+            }
+        }
+        text
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    event::{Event, EventResult},
+    view::ViewWrapper,
+    views::EditView,
+    Cursive, View,
+};
+
+use crate::jira_data::JiraData;
+
+/// Input mode of the Jira screen.
+///
+/// Global keys work only in normal mode,
+/// so they can be typed into search fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputMode {
+    #[default]
+    Normal,
+    /// Search field is focused.
+    ///
+    /// Contains name of the list to focus when the mode is left.
+    Insert(String),
+}
+
+/// Returns true if global keys must be handled.
+pub fn is_normal_mode(cursive: &mut Cursive) -> bool {
+    let jira_data: &mut Arc<RwLock<JiraData>> = cursive.user_data().unwrap();
+    let jira_data_guard = jira_data.read().unwrap();
+    jira_data_guard.input_mode == InputMode::Normal
+}
+
+pub fn set_input_mode(cursive: &mut Cursive, input_mode: InputMode) {
+    let jira_data: &mut Arc<RwLock<JiraData>> = cursive.user_data().unwrap();
+    let mut jira_data_guard = jira_data.write().unwrap();
+    jira_data_guard.input_mode = input_mode;
+}
+
+/// Search field which switches the screen into insert mode.
+///
+/// Only the focused view receives key events, so any consumed event
+/// means that the user types into the field.
+/// Normal mode is returned when the field loses the focus.
+pub struct SearchInput {
+    inner_view: EditView,
+    list_view_name: String,
+}
+
+impl SearchInput {
+    /// Wraps `edit_view`, `list_view_name` is the list
+    /// filtered by this field.
+    pub fn new(edit_view: EditView, list_view_name: String) -> Self {
+        Self {
+            inner_view: edit_view,
+            list_view_name,
+        }
+    }
+}
+
+impl ViewWrapper for SearchInput {
+    cursive::wrap_impl!(self.inner_view: EditView);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        if event == Event::FocusLost {
+            return EventResult::with_cb(|cursive| {
+                set_input_mode(cursive, InputMode::Normal)
+            })
+            .and(self.inner_view.on_event(event));
+        }
+
+        let result = self.inner_view.on_event(event);
+        if result.is_consumed() {
+            let input_mode = InputMode::Insert(self.list_view_name.clone());
+            EventResult::with_cb(move |cursive| {
+                set_input_mode(cursive, input_mode.clone())
+            })
+            .and(result)
+        } else {
+            result
+        }
+    }
+}
@@ -1,12 +1,34 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, RwLock};
 
-use crate::errors::RusjiError;
+use crate::config::ApiVersion;
+use crate::errors::{RusjiError, RusjiResult};
+use crate::git::DEFAULT_BRANCH_TEMPLATE;
 use crate::jira::{
+    auto_refresh::data::{RefreshSummary, UpdatedIssues},
+    filters::data::JqlFilter,
+    input_mode::InputMode,
     projects::data::JiraProject,
-    tasks::data::{JiraIssue, JiraIssues},
+    tasks::changelog::{HistoryTab, IssueChangelog},
+    tasks::data::{IssueAttachment, JiraFields, JiraIssue, JiraIssues},
+    tasks::indicators::task_label,
+    tasks::ordering::{TaskListFields, TaskListItem, TaskListOrder},
+    tasks::search::{self, task_name_order, SearchMatch, TaskQuery},
+    tasks_actions::data::JiraUser,
+    utils::helpers::{fuzzy_match, fuzzy_match_indices, highlight_chars},
 };
-use crate::request_client::request_client::RequestClient;
+use crate::keymap::Keymap;
+use crate::logger;
+use crate::request_client::client::RequestClient;
+use crate::state::{CompanyState, RecentAssignee, RecentIssue};
+use crate::theme::SemanticColors;
+
+use cursive::{theme::Effect, utils::markup::StyledString};
+use serde_json::json;
 
 use super::projects::data::JiraProjects;
 
@@ -14,35 +36,406 @@ use rusty_pool::ThreadPool;
 
 /// Struct with data about company jira.
 pub struct JiraData {
+    /// Name of the company from the config.
+    pub company_name: String,
     projects: Option<HashMap<String, JiraProject>>,
     pub client: Arc<RwLock<RequestClient>>,
     pub thread_pool: ThreadPool,
     pub selected_project: String,
     pub selected_task: String,
     pub activated_views: Vec<String>,
+    pub company_state: CompanyState,
+    /// State of the history tab, `None` if task details are shown.
+    pub history_tab: Option<HistoryTab>,
+    pub keymap: Keymap,
+    pub input_mode: InputMode,
+    /// Colors of statuses, priorities and issue types.
+    pub colors: SemanticColors,
+    /// Template of git branch names from the config.
+    pub branch_template: String,
+    /// JQL filters saved for the company in the config.
+    pub saved_filters: Vec<JqlFilter>,
+    /// Minutes between checks for updated issues from the config.
+    pub auto_refresh_minutes: Option<u64>,
+    /// Flag of the running auto refresh, it's set to stop the refresh.
+    pub auto_refresh_stop: Option<Arc<AtomicBool>>,
+    /// Keys of the issues changed since they were shown last time.
+    changed_issues: HashSet<String>,
+    favourite_filters: Option<Vec<JqlFilter>>,
+    current_user: Option<JiraUser>,
+    fields: Option<JiraFields>,
 }
 
 impl JiraData {
-    pub fn new(jira_url: &str, request_credentials: &str) -> Self {
+    pub fn new(
+        jira_url: &str,
+        request_credentials: &str,
+        api_version: ApiVersion,
+    ) -> Self {
+        let mut client = RequestClient::new(
+            request_credentials.to_string(),
+            jira_url,
+            api_version,
+        );
+        client.detect_deployment_type();
         Self {
+            company_name: String::default(),
             projects: None,
-            client: Arc::new(RwLock::new(RequestClient::new(
-                request_credentials.to_string(),
-                jira_url,
-            ))),
+            client: Arc::new(RwLock::new(client)),
             thread_pool: ThreadPool::default(),
             selected_project: String::default(),
             selected_task: String::default(),
             activated_views: Vec::default(),
+            company_state: CompanyState::default(),
+            history_tab: None,
+            keymap: Keymap::default(),
+            input_mode: InputMode::default(),
+            colors: SemanticColors::default(),
+            branch_template: DEFAULT_BRANCH_TEMPLATE.into(),
+            saved_filters: Vec::new(),
+            auto_refresh_minutes: None,
+            auto_refresh_stop: None,
+            changed_issues: HashSet::new(),
+            favourite_filters: None,
+            current_user: None,
+            fields: None,
+        }
+    }
+
+    /// Loads projects, fields and the current user on the thread pool.
+    ///
+    /// Fields and the current user are loaded together with projects,
+    /// so views showing epics and sprints or searching `assignee:me`
+    /// never wait for them.
+    pub fn load_projects_and_fields(&mut self) {
+        let projects_client = self.client.clone();
+        let fields_client = self.client.clone();
+        let user_client = self.client.clone();
+        let jira_projects = self
+            .thread_pool
+            .evaluate(move || JiraProjects::new(projects_client));
+        let jira_fields = self
+            .thread_pool
+            .evaluate(move || JiraFields::new(fields_client));
+        let current_user = self
+            .thread_pool
+            .evaluate(move || JiraUser::current(user_client));
+
+        self.update_projects(jira_projects.await_complete());
+        self.update_fields(jira_fields.await_complete());
+        self.update_current_user(current_user.await_complete());
+    }
+
+    /// Updates the current user, `None` is kept if it can't be loaded.
+    pub fn update_current_user(
+        &mut self,
+        current_user: RusjiResult<JiraUser>,
+    ) {
+        match current_user {
+            Ok(current_user) => self.current_user = Some(current_user),
+            Err(err) => {
+                logger::error(
+                    "load_error",
+                    json!({
+                        "data": "current user",
+                        "message": err.to_string(),
+                    }),
+                );
+            }
+        }
+    }
+
+    /// Updates fields of the Jira, `None` is kept if they can't be loaded.
+    pub fn update_fields(&mut self, jira_fields: RusjiResult<JiraFields>) {
+        match jira_fields {
+            Ok(fields) => self.fields = Some(fields),
+            Err(err) => {
+                logger::error(
+                    "load_error",
+                    json!({ "data": "fields", "message": err.to_string() }),
+                );
+                self.fields = None;
+            }
         }
     }
 
-    pub fn update_selected_issue(&mut self) {
+    /// Returns id of the `Epic Link` custom field.
+    pub fn get_epic_link_field(&self) -> Option<&str> {
+        self.fields.as_ref()?.get_field_id("Epic Link")
+    }
+
+    /// Returns id of the `Sprint` custom field.
+    pub fn get_sprint_field(&self) -> Option<&str> {
+        self.fields.as_ref()?.get_field_id("Sprint")
+    }
+
+    /// Returns order of the tasks list of the selected project.
+    pub fn get_task_list_order(&self) -> TaskListOrder {
+        self.company_state
+            .task_list_order(&self.get_selected_project_key())
+    }
+
+    /// Changes order of the tasks list of the selected project
+    /// and saves company state.
+    pub fn change_task_list_order<F>(&mut self, change_fn: F)
+    where
+        F: FnOnce(&mut TaskListOrder),
+    {
+        let project_key = self.get_selected_project_key();
+        let mut order = self.company_state.task_list_order(&project_key);
+        change_fn(&mut order);
+        self.company_state.set_task_list_order(&project_key, order);
+        // Order is only a view setting, so failed save isn't critical.
+        let _ = self.company_state.save();
+    }
+
+    /// Returns sorted and grouped tasks of the selected project.
+    ///
+    /// Returns `None` if tasks of the project aren't loaded.
+    pub fn get_tasks_list(&self) -> Option<Vec<TaskListItem>> {
+        let fields = TaskListFields {
+            epic_link_field: self.get_epic_link_field(),
+            sprint_field: self.get_sprint_field(),
+        };
+        self.get_selected_project()?
+            .tasks_list(self.get_task_list_order(), &fields)
+    }
+
+    /// Selects the issue, it can be from another project.
+    ///
+    /// Selects project of the issue and loads its tasks if needed,
+    /// the issue is loaded if it isn't in the tasks of the project.
+    /// Returns true if selected project was changed.
+    pub fn open_issue(&mut self, issue_key: &str) -> RusjiResult<bool> {
+        let project = issue_key
+            .rsplit_once('-')
+            .and_then(|(project_key, _)| {
+                self.projects
+                    .as_ref()?
+                    .values()
+                    .find(|project| project.key == project_key)
+            })
+            .ok_or_else(|| RusjiError::UnknownProject(issue_key.into()))?;
+        let (project_name, project_key) =
+            (project.name.clone(), project.key.clone());
+        let is_issue_loaded = project
+            .tasks
+            .as_ref()
+            .is_some_and(|tasks| tasks.contains_key(issue_key));
+        let new_issue = if is_issue_loaded {
+            None
+        } else {
+            Some(JiraIssue::new(self.client.clone(), issue_key)?)
+        };
+
+        let is_project_changed = self.selected_project != project_name;
+        self.set_selected_project(&project_name);
+        if self.get_mut_selected_project().tasks.is_none() {
+            let tasks = JiraIssues::new(self.client.clone(), &project_key);
+            self.update_tasks(tasks);
+        }
+        if let Some(issue) = new_issue {
+            self.get_mut_selected_project()
+                .tasks
+                .get_or_insert_with(HashMap::default)
+                .insert(issue.key.clone(), issue);
+        }
+        self.selected_task = issue_key.into();
+        self.history_tab = None;
+        self.update_selected_task_transitions();
+
+        Ok(is_project_changed)
+    }
+
+    /// Loads transitions of the selected task.
+    ///
+    /// Error is only logged, the task keeps transitions loaded before.
+    pub fn update_selected_task_transitions(&mut self) {
+        let client = self.client.clone();
+        if let Err(err) = self.get_mut_selected_task().add_transitions(client)
+        {
+            logger::error(
+                "load_error",
+                json!({ "data": "transitions", "message": err.to_string() }),
+            );
+        }
+    }
+
+    /// Returns true if the current user is already loaded.
+    pub fn has_current_user(&self) -> bool {
+        self.current_user.is_some()
+    }
+
+    /// Returns keys of the projects whose tasks are loaded.
+    pub fn get_loaded_project_keys(&self) -> Vec<String> {
+        let mut project_keys: Vec<String> = self
+            .projects
+            .iter()
+            .flat_map(|projects| projects.values())
+            .filter(|project| project.tasks.is_some())
+            .map(|project| project.key.clone())
+            .collect();
+        project_keys.sort();
+        project_keys
+    }
+
+    /// Returns currently logged in user.
+    ///
+    /// The user is loaded with projects, no request is made here.
+    /// If it isn't loaded, auto refresh tries again in the background.
+    pub fn get_current_user(&self) -> Option<&JiraUser> {
+        self.current_user.as_ref()
+    }
+
+    /// Returns favourite filters of the current user from Jira.
+    ///
+    /// Filters are requested only once per session,
+    /// empty list is returned if they can't be loaded.
+    pub fn get_favourite_filters(&mut self) -> &[JqlFilter] {
+        if self.favourite_filters.is_none() {
+            self.favourite_filters =
+                JqlFilter::favourite(self.client.clone()).ok();
+        }
+        self.favourite_filters.as_deref().unwrap_or_default()
+    }
+
+    /// Remembers assignee as recently used and saves company state.
+    pub fn add_recent_assignee(&mut self, assignee: RecentAssignee) {
+        self.company_state.add_recent_assignee(assignee);
+        // Recent assignees are only a hint, so failed save isn't critical.
+        let _ = self.company_state.save();
+    }
+
+    /// Remembers the selected issue as recently viewed.
+    pub fn add_recent_issue(&mut self) {
+        let task = self.get_selected_task();
+        let recent_issue = RecentIssue::new(&task.key, &task.summary);
+        if self.company_state.add_recent_issue(recent_issue) {
+            // History is only a shortcut, so failed save isn't critical.
+            let _ = self.company_state.save();
+        }
+    }
+
+    /// Bookmarks the selected issue or removes its bookmark.
+    ///
+    /// Returns `None` if no issue is selected,
+    /// otherwise true if the issue is bookmarked now.
+    pub fn toggle_bookmark(&mut self) -> Option<bool> {
+        let task = self
+            .get_selected_project()?
+            .tasks
+            .as_ref()?
+            .get(&self.selected_task)?;
+        let recent_issue = RecentIssue::new(&task.key, &task.summary);
+        let is_bookmarked = self.company_state.toggle_bookmark(recent_issue);
+        // Bookmarks are only a shortcut, so failed save isn't critical.
+        let _ = self.company_state.save();
+        Some(is_bookmarked)
+    }
+
+    /// Puts updated issues into loaded tasks of the projects.
+    ///
+    /// Issues with the same update time as the cached ones are skipped,
+    /// changed and new issues are marked in the tasks list.
+    /// Issues of projects whose tasks aren't loaded are ignored.
+    ///
+    /// No requests are made, transitions and changelog of the selected
+    /// issue which weren't loaded are taken from the cached issue.
+    pub fn apply_updated_issues(
+        &mut self,
+        updated_issues: UpdatedIssues,
+    ) -> RefreshSummary {
+        if self.current_user.is_none() {
+            self.current_user = updated_issues.current_user;
+        }
+        let current_user = self.current_user.clone();
+        let is_mine = |issue: &JiraIssue| {
+            current_user
+                .as_ref()
+                .is_some_and(|user| issue.is_assigned_to(user))
+        };
+        let mut summary = RefreshSummary::default();
+        let Some(projects) = self.projects.as_mut() else {
+            return summary;
+        };
+        for mut issue in updated_issues.issues {
+            let Some(project) =
+                issue.key.rsplit_once('-').and_then(|(project_key, _)| {
+                    projects
+                        .values_mut()
+                        .find(|project| project.key == project_key)
+                })
+            else {
+                continue;
+            };
+            let is_selected_project = project.name == self.selected_project;
+            let Some(tasks) = project.tasks.as_mut() else {
+                continue;
+            };
+            let cached = tasks.get(&issue.key);
+            let was_mine = match cached {
+                Some(cached) if cached.updated == issue.updated => continue,
+                Some(cached) => is_mine(cached),
+                None => false,
+            };
+            let is_selected =
+                is_selected_project && issue.key == self.selected_task;
+            if let (true, Some(cached)) = (is_selected, cached) {
+                if issue.transitions.is_none() {
+                    issue.transitions = cached.transitions.clone();
+                }
+                if let Some(history_tab) = self.history_tab.as_mut() {
+                    match issue.changelog {
+                        Some(_) => history_tab.page = 0,
+                        None => issue.changelog = cached.changelog.clone(),
+                    }
+                }
+            }
+            if is_mine(&issue) && !was_mine {
+                summary.assigned.push(issue.key.clone());
+            } else {
+                summary.updated.push(issue.key.clone());
+            }
+            summary.is_selected_changed |= is_selected;
+            summary.is_list_changed |= is_selected_project;
+            self.changed_issues.insert(issue.key.clone());
+            tasks.insert(issue.key.clone(), issue);
+        }
+        summary
+    }
+
+    /// Removes the change mark of the issue.
+    ///
+    /// Returns true if the issue was marked.
+    pub fn mark_issue_seen(&mut self, issue_key: &str) -> bool {
+        self.changed_issues.remove(issue_key)
+    }
+
+    /// Reloads the selected issue.
+    ///
+    /// Changelog is reloaded too if the history tab is shown,
+    /// the tab shows its first page then.
+    /// The old changelog is kept if it can't be reloaded.
+    /// The cached issue is kept if the issue can't be reloaded.
+    pub fn update_selected_issue(&mut self) -> RusjiResult<()> {
         let old_task_key = self.get_selected_task().key.clone();
         let mut updated_issue =
-            JiraIssue::new(self.client.clone(), old_task_key.as_str())
-                .unwrap();
-        updated_issue.add_transitions(self.client.clone());
+            JiraIssue::new(self.client.clone(), &old_task_key)?;
+        // Kept if the new transitions can't be loaded.
+        updated_issue.transitions =
+            self.get_selected_task().transitions.clone();
+        if let Some(history_tab) = self.history_tab.as_mut() {
+            updated_issue.changelog = match IssueChangelog::new(
+                self.client.clone(),
+                &old_task_key,
+            ) {
+                Ok(changelog) => {
+                    history_tab.page = 0;
+                    Some(changelog)
+                }
+                Err(_) => self.get_selected_task().changelog.clone(),
+            };
+        }
 
         let selected_project = self.get_mut_selected_project();
         selected_project
@@ -50,6 +443,108 @@ impl JiraData {
             .as_mut()
             .unwrap()
             .insert(old_task_key, updated_issue);
+        self.update_selected_task_transitions();
+        Ok(())
+    }
+
+    /// Shows history tab for the selected issue
+    /// or hides it if it's already shown.
+    ///
+    /// Changelog of the issue is loaded when the tab is shown first time.
+    pub fn toggle_history_tab(&mut self) -> RusjiResult<()> {
+        if self.history_tab.take().is_some() {
+            return Ok(());
+        }
+        if self.get_selected_task().changelog.is_none() {
+            let issue_key = self.get_selected_task().key.clone();
+            let changelog =
+                IssueChangelog::new(self.client.clone(), &issue_key)?;
+            self.get_mut_selected_task().changelog = Some(changelog);
+        }
+        self.history_tab = Some(HistoryTab::default());
+        Ok(())
+    }
+
+    /// Changes state of the shown history tab,
+    /// the page is kept among pages of the filtered changelog.
+    pub fn change_history_tab<F>(&mut self, change_fn: F)
+    where
+        F: FnOnce(&mut HistoryTab),
+    {
+        let Some(mut history_tab) = self.history_tab else {
+            return;
+        };
+        change_fn(&mut history_tab);
+        if let Some(changelog) = self.get_selected_task().changelog.as_ref() {
+            history_tab.page = history_tab
+                .page
+                .min(changelog.pages_count(history_tab.filter) - 1);
+        }
+        self.history_tab = Some(history_tab);
+    }
+
+    /// Downloads attachments of the selected issue into `directory`.
+    ///
+    /// Directory is created if it doesn't exist.
+    /// Returns paths of the saved files.
+    pub fn download_attachments(
+        &self,
+        attachments: &[IssueAttachment],
+        directory: &Path,
+    ) -> RusjiResult<Vec<PathBuf>> {
+        fs::create_dir_all(directory)?;
+        let mut file_paths: Vec<PathBuf> = Vec::new();
+        for attachment in attachments {
+            let content = self
+                .client
+                .read()
+                .unwrap()
+                .download_attachment(&attachment.content)?;
+            // Only the name is used, so the file can't be written
+            // outside of the directory.
+            let file_name = Path::new(&attachment.filename)
+                .file_name()
+                .unwrap_or_else(|| OsStr::new(&attachment.id));
+            let file_path = free_file_path(directory, Path::new(file_name));
+            fs::write(&file_path, content)?;
+            file_paths.push(file_path);
+        }
+        Ok(file_paths)
+    }
+
+    /// Uploads local file as an attachment of the selected issue.
+    pub fn upload_attachment(&self, file_path: &Path) -> RusjiResult<()> {
+        let issue_key = &self.get_selected_task().key;
+        self.client
+            .read()
+            .unwrap()
+            .upload_attachment(issue_key, file_path)?;
+        Ok(())
+    }
+
+    /// Returns styled row of the task in the tasks list.
+    ///
+    /// Chars of the name from `highlights` are highlighted,
+    /// tasks changed since they were shown last time start with `*`.
+    /// Only the name is shown if the task isn't loaded.
+    pub fn get_task_label(
+        &self,
+        task_name: &str,
+        highlights: &[usize],
+    ) -> StyledString {
+        let task = self.get_selected_project().and_then(|project| {
+            project.tasks.as_ref()?.get(search::task_key(task_name))
+        });
+        let Some(task) = task else {
+            return highlight_chars(task_name, highlights);
+        };
+        let label = task_label(task, highlights, &self.colors);
+        if !self.changed_issues.contains(&task.key) {
+            return label;
+        }
+        let mut marked_label = StyledString::styled("* ", Effect::Bold);
+        marked_label.append(label);
+        marked_label
     }
 
     /// Sets new selected project.
@@ -80,6 +575,7 @@ impl JiraData {
             }
         };
         self.selected_task = selected_task;
+        self.history_tab = None;
         Some(&self.selected_task)
     }
 
@@ -156,7 +652,11 @@ impl JiraData {
                 let projects_field = self.make_projects_field(projects);
                 self.projects = Some(projects_field);
             }
-            Err(_) => {
+            Err(err) => {
+                logger::error(
+                    "load_error",
+                    json!({ "data": "projects", "message": err.to_string() }),
+                );
                 self.projects = None;
             }
         }
@@ -166,10 +666,10 @@ impl JiraData {
     pub fn get_projects_names(&self) -> Vec<&str> {
         match self.projects.as_ref() {
             Some(project) => {
-                return project
-                    .values()
-                    .map(|prj| prj.name.as_str())
-                    .collect();
+                let mut projects_names: Vec<&str> =
+                    project.values().map(|prj| prj.name.as_str()).collect();
+                projects_names.sort_by_key(|name| name.to_lowercase());
+                projects_names
             }
             None => Vec::default(),
         }
@@ -187,11 +687,19 @@ impl JiraData {
         match jira_tasks {
             Ok(tasks) => {
                 let tasks_field = self.make_tasks_field(tasks);
-                let mut project = self.get_mut_selected_project();
+                let project = self.get_mut_selected_project();
                 project.tasks = Some(tasks_field);
             }
-            Err(_) => {
-                let mut project = self.get_mut_selected_project();
+            Err(err) => {
+                let project = self.get_mut_selected_project();
+                logger::error(
+                    "load_error",
+                    json!({
+                        "data": "tasks",
+                        "project": project.key,
+                        "message": err.to_string(),
+                    }),
+                );
                 project.tasks = None;
             }
         }
@@ -206,47 +714,65 @@ impl JiraData {
             .insert(task.key.clone(), task);
     }
 
-    /// Tries to find project by subname.
-    pub fn find_project_by_subname(&self, project_subname: &str) -> Vec<&str> {
-        let mut fit_projects: Vec<&str> = Vec::new();
-        for project in self.projects.as_ref().unwrap().values() {
-            let project_name_copy = project.name.clone();
-            let available_condition = project.name.contains(project_subname)
-                || project
-                    .name
-                    .contains(project_subname.to_uppercase().as_str())
-                || project
-                    .name
-                    .contains(project_subname.to_lowercase().as_str())
-                || project_name_copy.to_lowercase().contains(project_subname)
-                || project_name_copy.to_uppercase().contains(project_subname);
-            if available_condition {
-                fit_projects.push(&project.name);
-            }
-        }
+    /// Finds projects by name or key, the best matches go first.
+    ///
+    /// Name and key are matched fuzzily, matched chars of the name
+    /// are highlighted.
+    pub fn find_project_by_subname(
+        &self,
+        project_subname: &str,
+    ) -> Vec<SearchMatch> {
+        let Some(projects) = self.projects.as_ref() else {
+            return Vec::new();
+        };
+        let mut fit_projects: Vec<SearchMatch> = projects
+            .values()
+            .filter_map(|project| {
+                let name_match =
+                    fuzzy_match_indices(project_subname, &project.name);
+                let key_match = fuzzy_match(project_subname, &project.key)
+                    .map(|score| (score, Vec::new()));
+                let (score, highlights) = match (name_match, key_match) {
+                    (Some(name_match), Some(key_match)) => {
+                        if key_match.0 > name_match.0 {
+                            key_match
+                        } else {
+                            name_match
+                        }
+                    }
+                    (name_match, key_match) => name_match.or(key_match)?,
+                };
+                Some(SearchMatch {
+                    text: project.name.clone(),
+                    highlights,
+                    score,
+                })
+            })
+            .collect();
+        SearchMatch::sort(&mut fit_projects, str::to_lowercase);
         fit_projects
     }
 
-    /// Tries to find task by subname
+    /// Finds tasks of the selected project by the search query.
+    ///
+    /// See `TaskQuery` for the query syntax.
+    /// `assignee:me` filter finds nothing if the current user
+    /// isn't loaded.
     pub fn find_task_by_subname(
         &self,
-        task_subname: &str,
-        selected_project: &str,
-    ) -> Option<Vec<&JiraIssue>> {
-        let mut fit_tasks: Vec<&JiraIssue> = Vec::new();
-        let project = self.get_project(selected_project)?;
-
-        for (task_name, task) in project.tasks.as_ref()?.iter() {
-            let task_name_copy = task_name.clone();
-            let available_condition = task_name.contains(task_subname)
-                || task_name.contains(task_subname.to_uppercase().as_str())
-                || task_name.contains(task_subname.to_lowercase().as_str())
-                || task_name_copy.to_lowercase().contains(task_subname)
-                || task_name_copy.to_uppercase().contains(task_subname);
-            if available_condition {
-                fit_tasks.push(task);
-            }
-        }
+        raw_query: &str,
+    ) -> Option<Vec<SearchMatch>> {
+        let query = TaskQuery::parse(raw_query);
+        let current_user = self.get_current_user();
+        let project = self.get_selected_project()?;
+
+        let mut fit_tasks: Vec<SearchMatch> = project
+            .tasks
+            .as_ref()?
+            .values()
+            .filter_map(|task| query.match_task(task, current_user))
+            .collect();
+        SearchMatch::sort(&mut fit_tasks, task_name_order);
         Some(fit_tasks)
     }
 
@@ -275,3 +801,22 @@ impl JiraData {
         tasks_hashmap
     }
 }
+
+/// Returns path to the file in the directory which doesn't exist yet.
+///
+/// If the name is taken, a number is added to it, e.g. `image (1).png`,
+/// so files with the same name don't overwrite each other.
+fn free_file_path(directory: &Path, file_name: &Path) -> PathBuf {
+    let mut file_path = directory.join(file_name);
+    let stem = file_name.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = file_name
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+    let mut number = 1;
+    while file_path.exists() {
+        file_path = directory.join(format!("{stem} ({number}){extension}"));
+        number += 1;
+    }
+    file_path
+}
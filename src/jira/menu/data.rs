@@ -1,11 +1,17 @@
 use std::str::FromStr;
 
-enum MenuVariant {
+use crate::jira::filters::data::JqlFilter;
+
+pub(crate) enum MenuVariant {
     IamAssignee,
     IamCreator,
+    MyWork,
+    SaveFilter,
+    ChangeTheme,
+    SwitchCompany,
 }
 
-struct MenuVariantErr;
+pub(crate) struct MenuVariantErr;
 
 impl FromStr for MenuVariant {
     type Err = MenuVariantErr;
@@ -14,6 +20,10 @@ impl FromStr for MenuVariant {
         match str_menu_variant {
             "I'm assignee tasks" => Ok(Self::IamAssignee),
             "I'm creator tasks" => Ok(Self::IamCreator),
+            "My work in all companies" => Ok(Self::MyWork),
+            "Save JQL filter" => Ok(Self::SaveFilter),
+            "Change theme" => Ok(Self::ChangeTheme),
+            "Switch company" => Ok(Self::SwitchCompany),
             _ => Err(MenuVariantErr {}),
         }
     }
@@ -24,12 +34,32 @@ impl From<MenuVariant> for &str {
         match menu_variant {
             MenuVariant::IamAssignee => "I'm assignee tasks",
             MenuVariant::IamCreator => "I'm creator tasks",
+            MenuVariant::MyWork => "My work in all companies",
+            MenuVariant::SaveFilter => "Save JQL filter",
+            MenuVariant::ChangeTheme => "Change theme",
+            MenuVariant::SwitchCompany => "Switch company",
         }
     }
 }
 
 impl MenuVariant {
     pub fn get_menu_variants() -> Vec<&'static str> {
-        vec![Self::IamAssignee.into(), Self::IamCreator.into()]
+        vec![
+            Self::IamAssignee.into(),
+            Self::IamCreator.into(),
+            Self::MyWork.into(),
+            Self::SaveFilter.into(),
+            Self::ChangeTheme.into(),
+            Self::SwitchCompany.into(),
+        ]
     }
 }
+
+/// Item of the menu.
+///
+/// Saved and favourite filters are listed after the menu variants.
+#[derive(Clone)]
+pub(crate) enum MenuItem {
+    Variant(&'static str),
+    Filter(JqlFilter),
+}
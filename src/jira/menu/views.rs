@@ -1,14 +1,31 @@
+use std::sync::{Arc, RwLock};
+
 use cursive::{
     view::{Finder, Nameable, ViewWrapper},
     views::{Dialog, NamedView, ScrollView, SelectView},
-    View,
+    Cursive, View,
 };
 use rusji_derive::ViewWrapper;
 
-use crate::jira::common::views::{
-    JiraViewWithName, JiraWithDialogView, ToggleableView,
+use crate::{
+    jira::{
+        common::views::{
+            ChangeJiraView, JiraViewWithName, JiraWithDialogView,
+            ToggleableView,
+        },
+        filters::views::{FilterView, SaveFilterView},
+        my_work::views::MyWorkView,
+        tasks::views::{InfoView, TasksView},
+        utils::views::FailedAttemptView,
+    },
+    jira_data::JiraData,
+    switch_company,
+    theme::AppTheme,
+    Config,
 };
 
+use super::data::{MenuItem, MenuVariant};
+
 #[derive(ViewWrapper)]
 pub(crate) struct MenuView {
     inner_view: NamedView<Dialog>,
@@ -41,7 +58,34 @@ impl ToggleableView for MenuView {}
 impl MenuView {
     pub fn new(cursive: &mut cursive::Cursive) -> Self {
         Self::toggle_on_view(cursive);
-        let inner_select_view = SelectView::<String>::new();
+        let (saved_filters, favourite_filters) = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let mut jira_data_guard = jira_data.write().unwrap();
+            (
+                jira_data_guard.saved_filters.clone(),
+                jira_data_guard.get_favourite_filters().to_vec(),
+            )
+        };
+
+        let mut inner_select_view =
+            SelectView::<MenuItem>::new().on_submit(Self::on_submit_menu_item);
+        for menu_variant in MenuVariant::get_menu_variants() {
+            inner_select_view
+                .add_item(menu_variant, MenuItem::Variant(menu_variant));
+        }
+        for filter in saved_filters {
+            inner_select_view.add_item(
+                format!("Filter: {}", filter.name),
+                MenuItem::Filter(filter),
+            );
+        }
+        for filter in favourite_filters {
+            inner_select_view.add_item(
+                format!("Jira filter: {}", filter.name),
+                MenuItem::Filter(filter),
+            );
+        }
 
         Self {
             inner_view: Dialog::new()
@@ -50,4 +94,131 @@ impl MenuView {
                 .with_name(Self::main_dialog_name()),
         }
     }
+
+    fn on_submit_menu_item(cursive: &mut Cursive, menu_item: &MenuItem) {
+        match menu_item {
+            MenuItem::Variant(menu_variant) => {
+                Self::on_submit_menu_variant(cursive, menu_variant)
+            }
+            MenuItem::Filter(filter) => {
+                Self::toggle_off_view(cursive);
+                cursive.pop_layer();
+                FilterView::show(cursive, filter);
+            }
+        }
+    }
+
+    fn on_submit_menu_variant(cursive: &mut Cursive, menu_variant: &str) {
+        match menu_variant.parse() {
+            Ok(MenuVariant::MyWork) => {
+                Self::toggle_off_view(cursive);
+                cursive.pop_layer();
+                MyWorkView::show(cursive);
+            }
+            Ok(MenuVariant::SaveFilter) => {
+                Self::toggle_off_view(cursive);
+                cursive.pop_layer();
+                let save_filter_view = SaveFilterView::new(cursive);
+                cursive.add_layer(save_filter_view);
+            }
+            Ok(MenuVariant::ChangeTheme) => {
+                Self::toggle_off_view(cursive);
+                cursive.pop_layer();
+                let themes_view = ThemesView::new(cursive);
+                cursive.add_layer(themes_view);
+            }
+            Ok(MenuVariant::SwitchCompany) => {
+                Self::toggle_off_view(cursive);
+                cursive.pop_layer();
+                switch_company(cursive);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// View to choose one of the built-in or user themes.
+#[derive(ViewWrapper)]
+pub(crate) struct ThemesView {
+    inner_view: NamedView<Dialog>,
+}
+
+impl JiraViewWithName for ThemesView {
+    fn view_name() -> String {
+        "ThemesView".into()
+    }
+
+    fn get_view(cursive: &mut Cursive) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl JiraWithDialogView for ThemesView {
+    fn main_dialog_name() -> String {
+        "ThemesViewDialogName".into()
+    }
+
+    fn get_main_dialog(&mut self) -> cursive::views::ViewRef<Dialog> {
+        self.find_name(&Self::main_dialog_name()).unwrap()
+    }
+}
+
+impl ToggleableView for ThemesView {}
+
+impl ThemesView {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let inner_select_view = SelectView::<String>::new()
+            .with_all_str(AppTheme::available_names())
+            .on_submit(Self::on_submit_theme);
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Choose theme")
+                .content(ScrollView::new(inner_select_view))
+                .with_name(Self::main_dialog_name()),
+        }
+    }
+
+    /// Applies the theme and remembers it in the config.
+    fn on_submit_theme(cursive: &mut Cursive, theme_name: &str) {
+        let app_theme = match AppTheme::load(theme_name) {
+            Ok(app_theme) => app_theme,
+            Err(err) => {
+                cursive.add_layer(FailedAttemptView::new(&err.to_string()));
+                return;
+            }
+        };
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        cursive.set_theme(app_theme.theme);
+
+        let (is_tasks_loaded, is_task_selected) = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let mut jira_data_guard = jira_data.write().unwrap();
+            jira_data_guard.colors = app_theme.colors;
+            let tasks = jira_data_guard
+                .get_selected_project()
+                .and_then(|project| project.tasks.as_ref());
+            (
+                tasks.is_some(),
+                tasks.is_some_and(|tasks| {
+                    tasks.contains_key(&jira_data_guard.selected_task)
+                }),
+            )
+        };
+        // Indicators are styled while rendering, so views are rebuilt.
+        if is_tasks_loaded {
+            TasksView::get_view(cursive).update_view_content(cursive);
+        }
+        if is_task_selected {
+            InfoView::get_view(cursive).update_view_content(cursive);
+        }
+
+        // The theme is already applied, so failed save isn't critical.
+        if let Ok(mut config) = Config::new() {
+            let _ = config.set_theme(theme_name);
+        }
+    }
 }
@@ -1,13 +1,24 @@
+mod adf;
+mod auto_refresh;
 mod bottom_menu;
+mod command_line;
+mod command_palette;
 mod common;
 mod constance;
+pub(crate) mod filters;
 mod global_callbacks;
+mod help;
+mod input_mode;
 pub mod jira_data;
 mod layouts;
 mod menu;
+mod my_work;
 mod projects;
+mod recent_issues;
 pub mod request_client;
 pub mod screen;
-mod tasks;
-mod tasks_actions;
-mod utils;
+pub(crate) mod tasks;
+pub(crate) mod tasks_actions;
+#[cfg(test)]
+mod tests;
+pub(crate) mod utils;
@@ -0,0 +1,102 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{theme::Effect, utils::markup::StyledString};
+use rusty_pool::ThreadPool;
+
+use crate::{
+    jira::tasks::{
+        data::{JiraIssue, JiraIssues},
+        indicators::task_label,
+    },
+    request_client::client::RequestClient,
+    theme::SemanticColors,
+    Config,
+};
+
+/// JQL of the unresolved issues assigned to the current user.
+pub const MY_WORK_JQL: &str =
+    "assignee = currentUser() AND resolution = Unresolved ORDER BY updated DESC";
+
+/// Issue of the "my work" list tagged with its company.
+pub struct MyWorkIssue {
+    pub company_name: String,
+    pub issue: JiraIssue,
+}
+
+impl MyWorkIssue {
+    /// Returns row of the issue, e.g. `[acme] ■ ↑ TST-1 -- Fix login │ ...`.
+    pub fn label(&self, colors: &SemanticColors) -> StyledString {
+        let mut label = StyledString::styled(
+            format!("[{}] ", self.company_name),
+            Effect::Bold,
+        );
+        label.append(task_label(&self.issue, &[], colors));
+        label
+    }
+}
+
+/// Unresolved issues of the current user in every company.
+#[derive(Default)]
+pub struct MyWork {
+    /// Issues of all companies, recently updated go first.
+    pub issues: Vec<MyWorkIssue>,
+    /// Companies where issues can't be found, e.g. Jira is unavailable.
+    pub failed_companies: Vec<String>,
+}
+
+impl MyWork {
+    /// Searches issues of every company in parallel.
+    ///
+    /// `companies` are pairs of the company name and its client.
+    pub fn load(
+        thread_pool: &ThreadPool,
+        companies: Vec<(String, Arc<RwLock<RequestClient>>)>,
+    ) -> Self {
+        let searches: Vec<_> = companies
+            .into_iter()
+            .map(|(company_name, client)| {
+                let search = thread_pool
+                    .evaluate(move || JiraIssues::search(client, MY_WORK_JQL));
+                (company_name, search)
+            })
+            .collect();
+
+        let mut my_work = Self::default();
+        for (company_name, search) in searches {
+            match search.await_complete() {
+                Ok(issues) => {
+                    my_work.issues.extend(issues.into_iter().map(|issue| {
+                        MyWorkIssue {
+                            company_name: company_name.clone(),
+                            issue,
+                        }
+                    }))
+                }
+                Err(_) => my_work.failed_companies.push(company_name),
+            }
+        }
+        my_work.issues.sort_by(|first, second| {
+            second.issue.updated.cmp(&first.issue.updated)
+        });
+        my_work
+    }
+}
+
+/// Returns names and clients of every company from the config.
+pub fn companies_clients(
+    config: &Config,
+) -> Vec<(String, Arc<RwLock<RequestClient>>)> {
+    config
+        .companies_names()
+        .into_iter()
+        .filter_map(|company_name| {
+            let jira = config.get_jira_by_company(&company_name).ok()?;
+            let client = RequestClient::new(
+                jira.get_encoded_creds().into(),
+                jira.get_url(),
+                jira.get_api_version(),
+            );
+            Some((company_name, Arc::new(RwLock::new(client))))
+        })
+        .collect()
+}
@@ -0,0 +1,138 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Finder, Nameable, ViewWrapper},
+    views::{
+        Dialog, DummyView, LinearLayout, NamedView, ScrollView, SelectView,
+        TextView,
+    },
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::views::{
+            JiraViewWithName, JiraWithDialogView, ToggleableView,
+        },
+        tasks::views::InfoView,
+        utils::views::FailedAttemptView,
+    },
+    jira_data::JiraData,
+    open_company, Config,
+};
+
+use super::data::{companies_clients, MyWork};
+
+/// View with unresolved issues of the current user in every company.
+#[derive(ViewWrapper)]
+pub(crate) struct MyWorkView {
+    inner_view: NamedView<Dialog>,
+}
+
+impl JiraViewWithName for MyWorkView {
+    fn view_name() -> String {
+        "MyWorkView".into()
+    }
+
+    fn get_view(cursive: &mut Cursive) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl JiraWithDialogView for MyWorkView {
+    fn main_dialog_name() -> String {
+        "MyWorkViewDialogName".into()
+    }
+
+    fn get_main_dialog(&mut self) -> cursive::views::ViewRef<Dialog> {
+        self.find_name(&Self::main_dialog_name()).unwrap()
+    }
+}
+
+impl ToggleableView for MyWorkView {}
+
+impl MyWorkView {
+    /// Searches issues of every company from the config and shows them.
+    pub fn show(cursive: &mut Cursive) {
+        let config = match Config::new() {
+            Ok(config) => config,
+            Err(err) => {
+                cursive.add_layer(FailedAttemptView::new(&err.to_string()));
+                return;
+            }
+        };
+        let my_work = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            MyWork::load(
+                &jira_data_guard.thread_pool,
+                companies_clients(&config),
+            )
+        };
+        let my_work_view = Self::new(cursive, my_work);
+        cursive.add_layer(my_work_view);
+    }
+
+    pub fn new(cursive: &mut Cursive, my_work: MyWork) -> Self {
+        Self::toggle_on_view(cursive);
+        let colors = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.colors
+        };
+
+        let mut inner_layout = LinearLayout::vertical();
+        if my_work.issues.is_empty() {
+            inner_layout.add_child(TextView::new("No unresolved issues"));
+        } else {
+            let issues_select_view = SelectView::<(String, String)>::new()
+                .with_all(my_work.issues.iter().map(|my_work_issue| {
+                    (
+                        my_work_issue.label(&colors),
+                        (
+                            my_work_issue.company_name.clone(),
+                            my_work_issue.issue.key.clone(),
+                        ),
+                    )
+                }))
+                .on_submit(|cursive, (company_name, issue_key)| {
+                    Self::open_issue(cursive, company_name, issue_key)
+                });
+            inner_layout.add_child(ScrollView::new(issues_select_view));
+        }
+        if !my_work.failed_companies.is_empty() {
+            inner_layout.add_child(DummyView);
+            inner_layout.add_child(TextView::new(format!(
+                "Can't load: {}",
+                my_work.failed_companies.join(", ")
+            )));
+        }
+
+        Self {
+            inner_view: Dialog::new()
+                .title("My work in all companies")
+                .padding_lrtb(1, 1, 1, 1)
+                .content(inner_layout)
+                .with_name(Self::main_dialog_name()),
+        }
+    }
+
+    /// Opens the issue, the company of the issue is opened if needed.
+    fn open_issue(cursive: &mut Cursive, company_name: &str, issue_key: &str) {
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        let current_company = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.company_name.clone()
+        };
+        if current_company != company_name {
+            open_company(cursive, company_name);
+        }
+        InfoView::open_issue(cursive, issue_key);
+    }
+}
@@ -6,8 +6,13 @@ use std::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::RusjiResult, jira::tasks::data::JiraIssue,
-    request_client::request_client::RequestClient,
+    errors::RusjiResult,
+    jira::tasks::{
+        data::JiraIssue,
+        ordering::{TaskListFields, TaskListItem, TaskListOrder},
+        search::{task_name, task_name_order},
+    },
+    request_client::client::RequestClient,
 };
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -29,10 +34,7 @@ impl JiraProjects {
         request_client: Arc<RwLock<RequestClient>>,
     ) -> RusjiResult<Self> {
         let response = request_client.read().unwrap().get_jira_projects()?;
-        let resp_text = response.get_body();
-
-        let projects = serde_json::from_str::<JiraProjects>(resp_text)?;
-        Ok(projects)
+        response.parse::<JiraProjects>()
     }
 }
 
@@ -58,14 +60,18 @@ impl JiraProject {
     }
 
     pub fn tasks_names(&self) -> Option<Vec<String>> {
-        let mut tasks_names: Vec<String> = Vec::default();
-        if let Some(tasks) = self.tasks.as_ref() {
-            for task in tasks.values() {
-                tasks_names
-                    .push(format!("{} -- {}", &task.key, &task.summary));
-            }
-            return Some(tasks_names);
-        }
-        None
+        let mut tasks_names: Vec<String> =
+            self.tasks.as_ref()?.values().map(task_name).collect();
+        tasks_names.sort_by_key(|task_name| task_name_order(task_name));
+        Some(tasks_names)
+    }
+
+    /// Returns tasks sorted and grouped by `order`.
+    pub fn tasks_list(
+        &self,
+        order: TaskListOrder,
+        fields: &TaskListFields<'_>,
+    ) -> Option<Vec<TaskListItem>> {
+        Some(order.arrange(self.tasks.as_ref()?.values(), fields))
     }
 }
@@ -16,7 +16,9 @@ use crate::errors::RusjiError;
 use crate::jira::common::views::{
     ChangeJiraView, JiraViewWithName, JiraWithDialogView,
 };
+use crate::jira::input_mode::SearchInput;
 use crate::jira::tasks::data::JiraIssues;
+use crate::jira::utils::helpers::highlight_chars;
 use crate::jira::{
     constance::INNER_CENTER_TOP_VIEW_ALIGN, tasks::views::TasksView,
 };
@@ -40,46 +42,18 @@ impl Default for ProjectsView {
     fn default() -> Self {
         let projects_select_view = SelectView::<String>::new()
             .align(INNER_CENTER_TOP_VIEW_ALIGN)
-            .on_submit(|cursive: &mut Cursive, selected_project: &str| {
-                let jira_data: Arc<RwLock<JiraData>> = cursive
-                    .user_data()
-                    .map(|jira_data: &mut Arc<RwLock<JiraData>>| {
-                        jira_data.clone()
-                    })
-                    .unwrap();
-
-                {
-                    let mut jira_guard = jira_data.write().unwrap();
-                    jira_guard.set_selected_project(selected_project);
-
-                    let client_clone = jira_guard.client.clone();
-                    let project_key = jira_guard.get_selected_project_key();
-
-                    let jira_tasks = {
-                        let project_key_clone = project_key;
-                        jira_guard.thread_pool.evaluate(
-                            move || -> Result<JiraIssues, RusjiError> {
-                                JiraIssues::new(
-                                    client_clone,
-                                    project_key_clone.as_str(),
-                                )
-                            },
-                        )
-                    };
-                    let jira_tasks_result = jira_tasks.await_complete();
-
-                    jira_guard.update_tasks(jira_tasks_result);
-                }
-                TasksView::get_view(cursive).update_view_content(cursive);
-            })
+            .on_submit(Self::open_project)
             .with_name(Self::select_view_name());
         let projects_scroll_view = ScrollView::new(projects_select_view);
 
         let search_project_dialog = Dialog::new()
             .title("Search project by name")
-            .content(EditView::new().on_edit(|cursive, text, _cursor| {
-                ProjectsView::on_enter_search_project(cursive, text)
-            }))
+            .content(SearchInput::new(
+                EditView::new().on_edit(|cursive, text, _cursor| {
+                    ProjectsView::on_enter_search_project(cursive, text)
+                }),
+                Self::select_view_name(),
+            ))
             .with_name(Self::search_view_name());
 
         let dialog = Dialog::new()
@@ -153,12 +127,6 @@ impl ChangeJiraView for ProjectsView {
         }
         cursive.set_user_data(jira_data);
     }
-
-    /// Extends view content with passed `content`.
-    fn add_content_to_view(&mut self, content: Vec<&str>) {
-        let mut select_view = self.get_select_view();
-        select_view.add_all_str(content);
-    }
 }
 
 impl ProjectsView {
@@ -179,11 +147,43 @@ impl ProjectsView {
             .unwrap()
     }
 
+    /// Selects the project and shows its tasks.
+    pub(crate) fn open_project(cursive: &mut Cursive, selected_project: &str) {
+        let jira_data: Arc<RwLock<JiraData>> = cursive
+            .user_data()
+            .map(|jira_data: &mut Arc<RwLock<JiraData>>| jira_data.clone())
+            .unwrap();
+
+        {
+            let mut jira_guard = jira_data.write().unwrap();
+            jira_guard.set_selected_project(selected_project);
+
+            let client_clone = jira_guard.client.clone();
+            let project_key = jira_guard.get_selected_project_key();
+
+            let jira_tasks = {
+                let project_key_clone = project_key;
+                jira_guard.thread_pool.evaluate(
+                    move || -> Result<JiraIssues, RusjiError> {
+                        JiraIssues::new(
+                            client_clone,
+                            project_key_clone.as_str(),
+                        )
+                    },
+                )
+            };
+            let jira_tasks_result = jira_tasks.await_complete();
+
+            jira_guard.update_tasks(jira_tasks_result);
+        }
+        TasksView::get_view(cursive).update_view_content(cursive);
+    }
+
     /// Gets input string from EditView as `project_subname`
     /// and tries to find suitable projects.
     ///
-    /// If search result is empty just clear view with projects
-    /// else show names of suitable projects.
+    /// Suitable projects are shown with highlighted matched chars,
+    /// the best matches go first.
     fn on_enter_search_project(cursive: &mut Cursive, project_subname: &str) {
         let mut select_project_view: ViewRef<SelectView> =
             ProjectsView::get_view(cursive).get_select_view();
@@ -196,12 +196,13 @@ impl ProjectsView {
         let fit_projects =
             guard_jira_data.find_project_by_subname(project_subname);
 
-        if fit_projects.is_empty() {
-            select_project_view.clear();
-        } else {
-            select_project_view.clear();
-            select_project_view.add_all_str(fit_projects);
-        };
+        select_project_view.clear();
+        for fit_project in fit_projects {
+            select_project_view.add_item(
+                highlight_chars(&fit_project.text, &fit_project.highlights),
+                fit_project.text,
+            );
+        }
         cursive.set_user_data(jira_data);
     }
 }
@@ -0,0 +1,196 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    event::Event,
+    view::{Finder, Nameable, ViewWrapper},
+    views::{
+        Dialog, NamedView, OnEventView, ScrollView, SelectView, TextView,
+    },
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::{
+            button::{CallbackSection, ShowOnlyCallback, StaticCallback},
+            views::{JiraViewWithName, JiraWithDialogView, ToggleableView},
+        },
+        tasks::views::InfoView,
+    },
+    jira_data::JiraData,
+    keymap::{KeyChord, KeyContext},
+    state::RecentIssue,
+};
+
+/// View with bookmarked and recently viewed issues of the company.
+///
+/// Issues are numbered, so they can be opened with number keys.
+/// Name and action of a number key callback.
+type NumberedIssueCallback = (&'static str, fn(&mut Cursive));
+
+/// Callbacks of the number keys, the first one opens the first issue.
+const NUMBERED_ISSUE_CALLBACKS: [NumberedIssueCallback; 9] = [
+    ("open issue 1", RecentIssuesView::open_numbered_issue::<1>),
+    ("open issue 2", RecentIssuesView::open_numbered_issue::<2>),
+    ("open issue 3", RecentIssuesView::open_numbered_issue::<3>),
+    ("open issue 4", RecentIssuesView::open_numbered_issue::<4>),
+    ("open issue 5", RecentIssuesView::open_numbered_issue::<5>),
+    ("open issue 6", RecentIssuesView::open_numbered_issue::<6>),
+    ("open issue 7", RecentIssuesView::open_numbered_issue::<7>),
+    ("open issue 8", RecentIssuesView::open_numbered_issue::<8>),
+    ("open issue 9", RecentIssuesView::open_numbered_issue::<9>),
+];
+
+#[derive(ViewWrapper)]
+pub(crate) struct RecentIssuesView {
+    inner_view: NamedView<Dialog>,
+}
+
+impl JiraViewWithName for RecentIssuesView {
+    fn view_name() -> String {
+        "RecentIssuesView".into()
+    }
+
+    fn get_view(cursive: &mut Cursive) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl JiraWithDialogView for RecentIssuesView {
+    fn main_dialog_name() -> String {
+        "RecentIssuesViewDialogName".into()
+    }
+
+    fn get_main_dialog(&mut self) -> cursive::views::ViewRef<Dialog> {
+        self.find_name(&Self::main_dialog_name()).unwrap()
+    }
+}
+
+impl ToggleableView for RecentIssuesView {}
+
+impl RecentIssuesView {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let (bookmarks, recent_issues) = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            let company_state = &jira_data_guard.company_state;
+            let bookmarks = company_state.bookmarks().to_vec();
+            // Bookmarked issues are already shown above.
+            let recent_issues: Vec<RecentIssue> = company_state
+                .recent_issues()
+                .iter()
+                .filter(|issue| !company_state.is_bookmarked(&issue.key))
+                .cloned()
+                .collect();
+            (bookmarks, recent_issues)
+        };
+
+        let dialog = Dialog::new()
+            .title("Recent issues and bookmarks")
+            .padding_lrtb(1, 1, 1, 1);
+        if bookmarks.is_empty() && recent_issues.is_empty() {
+            return Self {
+                inner_view: dialog
+                    .content(TextView::new("No viewed issues"))
+                    .with_name(Self::main_dialog_name()),
+            };
+        }
+
+        let mut select_view = SelectView::<String>::new().on_submit(
+            |cursive, issue_key: &String| Self::open_issue(cursive, issue_key),
+        );
+        // Headers have empty key, so they can't be opened.
+        let mut number = 0;
+        for (header, issues) in
+            [("Bookmarks", &bookmarks), ("Recent", &recent_issues)]
+        {
+            if issues.is_empty() {
+                continue;
+            }
+            select_view.add_item(header, String::default());
+            for issue in issues {
+                number += 1;
+                select_view.add_item(
+                    Self::issue_label(number, issue),
+                    issue.key.clone(),
+                );
+            }
+        }
+        // The first row is a header.
+        let select_view =
+            select_view.selected(1).with_name(Self::select_view_name());
+        let mut on_event_view = OnEventView::new(ScrollView::new(select_view));
+        for callback in Self::callbacks() {
+            on_event_view = on_event_view
+                .on_event(Event::from(callback.event), callback.action_fn);
+        }
+
+        Self {
+            inner_view: dialog
+                .content(on_event_view)
+                .with_name(Self::main_dialog_name()),
+        }
+    }
+
+    fn select_view_name() -> String {
+        "RecentIssuesSelectView".into()
+    }
+
+    /// Returns callbacks of the number keys, they're listed in the help.
+    pub fn callbacks() -> Vec<StaticCallback<'static, KeyChord>> {
+        KeyContext::RecentIssues
+            .fixed_keys()
+            .into_iter()
+            .zip(NUMBERED_ISSUE_CALLBACKS)
+            .map(|(key, (name, action_fn))| {
+                StaticCallback::new(key, name, action_fn)
+                    .in_section(CallbackSection::RecentIssues)
+            })
+            .collect()
+    }
+
+    /// Opens the issue with the `NUMBER` in the list, if there is one.
+    fn open_numbered_issue<const NUMBER: usize>(cursive: &mut Cursive) {
+        let issue_key = cursive
+            .call_on_name(
+                &Self::select_view_name(),
+                |select_view: &mut SelectView<String>| {
+                    // Headers have empty key.
+                    select_view
+                        .iter()
+                        .map(|(_, issue_key)| issue_key)
+                        .filter(|issue_key| !issue_key.is_empty())
+                        .nth(NUMBER - 1)
+                        .cloned()
+                },
+            )
+            .flatten();
+        if let Some(issue_key) = issue_key {
+            Self::open_issue(cursive, &issue_key);
+        }
+    }
+
+    /// Returns row of the issue, e.g. `1. TST-1 -- Fix login page`.
+    ///
+    /// Only issues which can be opened with a number key are numbered.
+    fn issue_label(number: usize, issue: &RecentIssue) -> String {
+        if number <= KeyContext::RecentIssues.fixed_keys().len() {
+            format!("{}. {} -- {}", number, issue.key, issue.summary)
+        } else {
+            format!("   {} -- {}", issue.key, issue.summary)
+        }
+    }
+
+    /// Opens the issue, it can be from another project.
+    fn open_issue(cursive: &mut Cursive, issue_key: &str) {
+        if issue_key.is_empty() {
+            return;
+        }
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        InfoView::open_issue(cursive, issue_key);
+    }
+}
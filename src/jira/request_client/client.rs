@@ -0,0 +1,667 @@
+use std::any::type_name;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::{
+    config::ApiVersion,
+    errors::{RusjiError, RusjiResult},
+    jira::{tasks::data::IssueMetaData, tasks_actions::data::UserIdentity},
+    logger::{self, json_snippet, LogLevel},
+};
+use reqwest::{
+    blocking::{multipart::Form, Client, RequestBuilder, Response},
+    header::HeaderMap,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Map, Value};
+use url::Url;
+
+use super::request_models::{
+    IssueCommentReqData, IssueLinkReqData, IssuePropertiesReqData,
+    IssueTransitionsReqData,
+};
+
+/// Timeout of the requests, except uploads and downloads of files.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Struct for request response.
+///
+/// Now contain only body.
+pub struct RequestResponse {
+    body: String,
+}
+
+impl RequestResponse {
+    /// Returns body from response.
+    pub fn get_body(&self) -> &str {
+        &self.body
+    }
+
+    /// Parses the body as JSON.
+    ///
+    /// Failed parsing is logged with the part of the body around the error.
+    pub fn parse<T: DeserializeOwned>(&self) -> RusjiResult<T> {
+        serde_json::from_str::<T>(&self.body).map_err(|err| {
+            logger::error(
+                "parse_error",
+                json!({
+                    "type": type_name::<T>(),
+                    "message": err.to_string(),
+                    "snippet": json_snippet(&self.body, &err),
+                }),
+            );
+            RusjiError::from(err)
+        })
+    }
+}
+
+/// Headers whose values aren't written to the log.
+const REDACTED_HEADERS: [&str; 3] =
+    ["authorization", "cookie", "proxy-authorization"];
+
+/// Returns headers as JSON object for the log,
+/// values of the credentials are replaced with `<redacted>`.
+pub(crate) fn redact_headers(headers: &HeaderMap) -> Value {
+    let headers: Map<String, Value> = headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if REDACTED_HEADERS.contains(&name.as_str()) {
+                "<redacted>"
+            } else {
+                value.to_str().unwrap_or("<binary>")
+            };
+            (name.to_string(), value.into())
+        })
+        .collect();
+    Value::Object(headers)
+}
+
+/// Type of Jira installation.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeploymentType {
+    /// Jira Cloud, users are identified by `accountId`.
+    Cloud,
+    /// Jira Server or Data Center, users are identified by `name`.
+    #[default]
+    #[serde(other)]
+    Server,
+}
+
+/// Part of `serverInfo` response with installation type.
+#[derive(Deserialize)]
+struct ServerInfo {
+    #[serde(alias = "deploymentType", default)]
+    deployment_type: DeploymentType,
+}
+
+/// Struct for request client
+///
+/// Can make request and return a response or an RusjiError.
+pub struct RequestClient {
+    client: Client,
+    jira_url: Url,
+    request_credentials: String,
+    api_version: ApiVersion,
+    deployment_type: DeploymentType,
+}
+
+impl RequestClient {
+    /// Create new instance of `RequestClient`
+    ///
+    /// `api_version` is a version of Jira REST API.
+    pub fn new(
+        request_credentials: String,
+        jira_url: &str,
+        api_version: ApiVersion,
+    ) -> Self {
+        Self {
+            // Files can take long to transfer, so only connecting
+            // is limited for them, see `builder_add_default_fields`.
+            client: Client::builder()
+                .connect_timeout(REQUEST_TIMEOUT)
+                .timeout(None)
+                .build()
+                .unwrap(),
+            jira_url: Url::parse(jira_url).unwrap(),
+            request_credentials,
+            api_version,
+            deployment_type: DeploymentType::default(),
+        }
+    }
+
+    /// Detects type of Jira installation from `serverInfo`.
+    ///
+    /// `serverInfo` of API version 2 is used, it's available on every
+    /// Jira whatever version is configured.
+    /// If detection failed client keeps working as with Jira Server.
+    pub fn detect_deployment_type(&mut self) {
+        let url = self.jira_url.join("/rest/api/2/serverInfo").unwrap();
+        let server_info = self
+            .make_basic_request(url)
+            .and_then(|response| response.parse::<ServerInfo>());
+        self.deployment_type = match server_info {
+            Ok(server_info) => server_info.deployment_type,
+            Err(err) => {
+                logger::error(
+                    "load_error",
+                    json!({
+                        "data": "server info",
+                        "message": err.to_string(),
+                    }),
+                );
+                DeploymentType::default()
+            }
+        };
+    }
+
+    /// Returns true if users are identified by `accountId`.
+    pub fn is_cloud(&self) -> bool {
+        self.deployment_type == DeploymentType::Cloud
+    }
+
+    /// Returns true if Jira API works with Atlassian Document Format.
+    ///
+    /// Version 3 of the API accepts and returns rich text fields
+    /// as ADF documents instead of wiki markup strings.
+    pub fn is_adf_api(&self) -> bool {
+        self.api_version == ApiVersion::V3
+    }
+
+    /// Returns url of the issue page in Jira web interface.
+    pub fn browse_url(&self, issue_key: &str) -> Url {
+        self.jira_url
+            .join(&format!("/browse/{}", issue_key))
+            .unwrap()
+    }
+
+    /// Return all Jira projects.
+    pub fn get_jira_projects(&self) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(self.api_url("project"))
+    }
+
+    /// Return one page of tasks from project.
+    ///
+    /// `start_at` is the index of the first task in the page.
+    pub fn get_tasks_from_project(
+        &self,
+        project_name: &str,
+        start_at: usize,
+    ) -> Result<RequestResponse, RusjiError> {
+        let project_tasks_endpoint = format!(
+            "search?jql=project={project_name}\
+            &expand=renderedFields&startAt={start_at}",
+        );
+        self.make_basic_request(self.api_url(&project_tasks_endpoint))
+    }
+
+    /// Returns favourite filters of the current user.
+    pub fn get_favourite_filters(
+        &self,
+    ) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(self.api_url("filter/favourite"))
+    }
+
+    /// Returns one page of issues found by JQL.
+    ///
+    /// `start_at` is the index of the first issue in the page.
+    pub fn search_issues(
+        &self,
+        jql: &str,
+        start_at: usize,
+    ) -> Result<RequestResponse, RusjiError> {
+        let mut search_url = self.api_url("search");
+        search_url
+            .query_pairs_mut()
+            .append_pair("jql", jql)
+            .append_pair("expand", "renderedFields")
+            .append_pair("startAt", &start_at.to_string());
+        self.make_basic_request(search_url)
+    }
+
+    /// Return new task.
+    pub fn get_task(
+        &self,
+        task_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(
+            self.api_url(&format!("issue/{}?expand=renderedFields", task_key)),
+        )
+    }
+
+    /// Returns one page of the issue changelog.
+    ///
+    /// `start_at` is the index of the first history,
+    /// Jira returns histories from the oldest one.
+    pub fn get_issue_changelog(
+        &self,
+        issue_key: &str,
+        start_at: usize,
+        max_results: usize,
+    ) -> Result<RequestResponse, RusjiError> {
+        let mut changelog_url =
+            self.api_url(&format!("issue/{}/changelog", issue_key));
+        changelog_url
+            .query_pairs_mut()
+            .append_pair("startAt", &start_at.to_string())
+            .append_pair("maxResults", &max_results.to_string());
+        self.make_basic_request(changelog_url)
+    }
+
+    /// Returns the issue with its whole changelog.
+    ///
+    /// Used on old Jira Server which has no changelog endpoint,
+    /// other fields aren't needed then.
+    pub fn get_issue_with_changelog(
+        &self,
+        issue_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        let mut issue_url = self.api_url(&format!("issue/{}", issue_key));
+        issue_url
+            .query_pairs_mut()
+            .append_pair("expand", "changelog")
+            .append_pair("fields", "summary");
+        self.make_basic_request(issue_url)
+    }
+
+    /// Return all available task statuses for project.
+    pub fn get_task_statuses(
+        &self,
+        project_name: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(
+            self.api_url(&format!("project/{}/statuses", project_name)),
+        )
+    }
+
+    /// Return all available issue transitions for the task.
+    pub fn get_issue_transitions(
+        &self,
+        issue_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(
+            self.api_url(&format!("issue/{}/transitions", issue_key)),
+        )
+    }
+
+    pub fn get_issue_metadata(
+        &self,
+        issue_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(
+            self.api_url(&format!("issue/{}/editmeta", issue_key)),
+        )
+    }
+
+    /// Return all users with passed username.
+    ///
+    /// Jira Cloud doesn't support `username` parameter,
+    /// so `query` is used for it.
+    pub fn get_jira_users(
+        &self,
+        username: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        let search_param = if self.is_cloud() { "query" } else { "username" };
+        let mut users_url = self.api_url("user/search");
+        users_url
+            .query_pairs_mut()
+            .append_pair(search_param, username);
+        self.make_basic_request(users_url)
+    }
+
+    /// Returns users which can be assigned to issues of the project.
+    pub fn get_assignable_users(
+        &self,
+        project_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(self.api_url(&format!(
+            "user/assignable/search?project={}",
+            project_key
+        )))
+    }
+
+    /// Returns currently logged in user.
+    pub fn get_myself(&self) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(self.api_url("myself"))
+    }
+
+    /// Update task transition.
+    pub fn update_task_transition(
+        &self,
+        issue_key: &str,
+        transition_id: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        let mut request_data = IssueTransitionsReqData::new();
+        request_data = request_data.add_transition_data(transition_id);
+
+        let req_builder = self
+            .post(self.api_url(&format!("issue/{}/transitions", issue_key)));
+
+        Self::process_response(
+            self.send(
+                req_builder.body(serde_json::to_string(&request_data)?),
+            )?,
+        )
+    }
+
+    /// Set new assignee to the issue.
+    ///
+    /// `None` assignee unassigns the issue.
+    pub fn update_issue_assignee(
+        &self,
+        assignee: Option<&UserIdentity>,
+        issue_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        let mut request_data = IssuePropertiesReqData::new();
+        request_data.set_assignee(assignee, self.is_cloud());
+        let req_builder =
+            self.put(self.api_url(&format!("issue/{}", issue_key)));
+
+        Self::process_response(
+            self.send(
+                req_builder.body(serde_json::to_string(&request_data)?),
+            )?,
+        )
+    }
+
+    pub fn update_issue_story_points(
+        &self,
+        new_story_points: usize,
+        issue_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        let issue_metadata_response = self.get_issue_metadata(issue_key)?;
+        let issue_metadata =
+            issue_metadata_response.parse::<IssueMetaData>()?;
+        let mut story_point_field_id =
+            issue_metadata.get_story_points_field_id();
+        if story_point_field_id.is_none() {
+            story_point_field_id = Some("Story Points".into())
+        }
+
+        let request_data = IssuePropertiesReqData::new();
+
+        let final_request_body = request_data
+            .add_story_points_and_return_as_string(
+                new_story_points,
+                story_point_field_id.unwrap(),
+            );
+
+        let req_builder =
+            self.put(self.api_url(&format!("issue/{}", issue_key)));
+
+        Self::process_response(
+            self.send(req_builder.body(final_request_body))?,
+        )
+    }
+
+    /// Set new description to the issue.
+    ///
+    /// `description` must be a plain string for API version 2
+    /// and ADF document for version 3.
+    pub fn update_issue_description(
+        &self,
+        description: serde_json::Value,
+        issue_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        let mut request_data = IssuePropertiesReqData::new();
+        request_data.set_description(description);
+        let req_builder =
+            self.put(self.api_url(&format!("issue/{}", issue_key)));
+
+        Self::process_response(
+            self.send(
+                req_builder.body(serde_json::to_string(&request_data)?),
+            )?,
+        )
+    }
+
+    /// Add new comment to the issue.
+    ///
+    /// `comment_body` must be a plain string for API version 2
+    /// and ADF document for version 3.
+    pub fn add_issue_comment(
+        &self,
+        comment_body: serde_json::Value,
+        issue_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        let request_data = IssueCommentReqData::new(comment_body);
+        let req_builder =
+            self.post(self.api_url(&format!("issue/{}/comment", issue_key)));
+
+        Self::process_response(
+            self.send(
+                req_builder.body(serde_json::to_string(&request_data)?),
+            )?,
+        )
+    }
+
+    /// Returns all fields, system and custom ones.
+    pub fn get_fields(&self) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(self.api_url("field"))
+    }
+
+    /// Returns all configured issue link types.
+    pub fn get_issue_link_types(&self) -> Result<RequestResponse, RusjiError> {
+        self.make_basic_request(self.api_url("issueLinkType"))
+    }
+
+    /// Links two issues with the link type.
+    ///
+    /// Link is described as `inward_issue_key` <outward description>
+    /// `outward_issue_key`, e.g. `PRO-1 blocks PRO-2`.
+    pub fn create_issue_link(
+        &self,
+        link_type_name: &str,
+        inward_issue_key: &str,
+        outward_issue_key: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        let request_data = IssueLinkReqData::new(
+            link_type_name,
+            inward_issue_key,
+            outward_issue_key,
+        );
+        let req_builder = self.post(self.api_url("issueLink"));
+
+        Self::process_response(
+            self.send(
+                req_builder.body(serde_json::to_string(&request_data)?),
+            )?,
+        )
+    }
+
+    /// Removes issue link by its id.
+    pub fn delete_issue_link(
+        &self,
+        link_id: &str,
+    ) -> Result<RequestResponse, RusjiError> {
+        Self::process_response(self.send(
+            self.delete(self.api_url(&format!("issueLink/{}", link_id))),
+        )?)
+    }
+
+    /// Downloads content of the attachment.
+    ///
+    /// `content_url` is the `content` link of the attachment.
+    /// The request has no timeout, because files can be large.
+    pub fn download_attachment(
+        &self,
+        content_url: &str,
+    ) -> RusjiResult<Vec<u8>> {
+        let response = self.send(self.builder_add_auth(
+            self.client.get(self.attachment_content_url(content_url)),
+        ))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(RusjiError::BadResponseStatus(status.as_u16()));
+        }
+        Ok(response.bytes()?.to_vec())
+    }
+
+    /// Uploads local file as an attachment of the issue.
+    ///
+    /// Jira rejects multipart requests without
+    /// `X-Atlassian-Token: no-check` header as XSRF.
+    /// The request has no timeout, because files can be large.
+    pub fn upload_attachment(
+        &self,
+        issue_key: &str,
+        file_path: &Path,
+    ) -> Result<RequestResponse, RusjiError> {
+        let form = Form::new().file("file", file_path)?;
+        let req_builder =
+            self.builder_add_auth(self.client.post(
+                self.api_url(&format!("issue/{}/attachments", issue_key)),
+            ));
+
+        Self::process_response(
+            self.send(
+                req_builder
+                    .header("X-Atlassian-Token", "no-check")
+                    .multipart(form),
+            )?,
+        )
+    }
+
+    /// Builds url to the attachment content on the configured Jira url.
+    ///
+    /// Jira puts its own base url into the content links,
+    /// it can differ from the configured one, e.g. behind a proxy.
+    fn attachment_content_url(&self, content_url: &str) -> Url {
+        let mut url = self.jira_url.clone();
+        match Url::parse(content_url) {
+            Ok(content_url) => {
+                url.set_path(content_url.path());
+                url.set_query(content_url.query());
+            }
+            Err(_) => url.set_path(content_url),
+        }
+        url
+    }
+
+    /// Builds full url to the Jira REST API endpoint.
+    ///
+    /// `endpoint` is a path after `/rest/api/{version}/`.
+    fn api_url(&self, endpoint: &str) -> Url {
+        self.jira_url
+            .join(&format!(
+                "/rest/api/{}/{}",
+                self.api_version.as_str(),
+                endpoint
+            ))
+            .unwrap()
+    }
+
+    /// Makes a request.
+    ///
+    /// Returns `RequestResponse` or `RusjiError`.
+    fn make_basic_request(
+        &self,
+        url: Url,
+    ) -> Result<RequestResponse, RusjiError> {
+        Self::process_response(self.send(self.get(url))?)
+    }
+
+    /// Sends the request, it's logged with the status and latency.
+    fn send(&self, builder: RequestBuilder) -> RusjiResult<Response> {
+        let request = builder.build()?;
+        if !logger::is_enabled(LogLevel::Error) {
+            return Ok(self.client.execute(request)?);
+        }
+        let mut record = json!({
+            "method": request.method().as_str(),
+            "path": request.url().path(),
+            "headers": redact_headers(request.headers()),
+        });
+        if logger::is_enabled(LogLevel::Debug) {
+            record["query"] = request.url().query().unwrap_or_default().into();
+        }
+        let started_at = Instant::now();
+        let result = self.client.execute(request);
+        record["latency_ms"] =
+            (started_at.elapsed().as_millis() as u64).into();
+
+        let level = match &result {
+            Ok(response) => {
+                record["status"] = response.status().as_u16().into();
+                if logger::is_enabled(LogLevel::Debug) {
+                    record["content_length"] =
+                        response.content_length().into();
+                }
+                if response.status().is_success() {
+                    LogLevel::Info
+                } else {
+                    LogLevel::Error
+                }
+            }
+            Err(err) => {
+                record["error"] = err.to_string().into();
+                LogLevel::Error
+            }
+        };
+        logger::log(level, "request", record);
+        Ok(result?)
+    }
+
+    /// Reads the body of the response.
+    ///
+    /// Returns `RusjiError::BadResponseStatus` if Jira
+    /// responded with non-success status.
+    fn process_response(
+        response: Response,
+    ) -> Result<RequestResponse, RusjiError> {
+        let status = response.status();
+        let body = response.text()?;
+        if !status.is_success() {
+            return Err(RusjiError::BadResponseStatus(status.as_u16()));
+        }
+        Ok(RequestResponse { body })
+    }
+
+    /// Adds basic fields to a request builder.
+    ///
+    /// It's necessary because in some cases we have additional
+    /// parameters that should be added to the builder
+    ///
+    /// It is used only in methods like `get`, `post`, etc.
+    fn builder_add_default_fields(
+        &self,
+        builder: RequestBuilder,
+    ) -> RequestBuilder {
+        self.builder_add_auth(builder)
+            .timeout(REQUEST_TIMEOUT)
+            .header("Content-Type", "application/json")
+    }
+
+    /// Adds credentials to a request builder.
+    ///
+    /// Requests with non json body (e.g. multipart)
+    /// set content type by themselves.
+    fn builder_add_auth(&self, builder: RequestBuilder) -> RequestBuilder {
+        builder.header(
+            "Authorization",
+            format!("Basic {}", self.request_credentials),
+        )
+    }
+
+    /// Makes request builder for `get` request.
+    fn get(&self, url: Url) -> RequestBuilder {
+        let builder = self.client.get(url);
+        self.builder_add_default_fields(builder)
+    }
+
+    /// Makes request builder for `post` request.
+    fn post(&self, url: Url) -> RequestBuilder {
+        let builder = self.client.post(url);
+        self.builder_add_default_fields(builder)
+    }
+
+    fn put(&self, url: Url) -> RequestBuilder {
+        let builder = self.client.put(url);
+        self.builder_add_default_fields(builder)
+    }
+
+    /// Makes request builder for `delete` request.
+    fn delete(&self, url: Url) -> RequestBuilder {
+        let builder = self.client.delete(url);
+        self.builder_add_default_fields(builder)
+    }
+}
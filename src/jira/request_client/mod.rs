@@ -1,2 +1,2 @@
-pub mod request_client;
+pub mod client;
 pub mod request_models;
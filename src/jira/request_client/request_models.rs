@@ -1,3 +1,4 @@
+use crate::jira::tasks_actions::data::UserIdentity;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize)]
@@ -24,15 +25,32 @@ impl<'a> IssueTransitionsReqData<'a> {
     }
 }
 
-#[derive(Default, Serialize, Deserialize)]
-struct AssigneeData<'a> {
-    name: &'a str,
+/// Assignee identity, Jira Cloud accepts only `accountId`.
+///
+/// Empty identity removes assignee from the issue.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum AssigneeData<'a> {
+    Name {
+        name: Option<&'a str>,
+    },
+    AccountId {
+        #[serde(rename = "accountId")]
+        account_id: Option<&'a str>,
+    },
 }
 
 impl<'a> AssigneeData<'a> {
-    fn new(assignee_username: &'a str) -> Self {
-        Self {
-            name: assignee_username,
+    /// Uses the field of the identity,
+    /// `is_cloud` chooses the field only to remove assignee.
+    fn new(assignee: Option<&'a UserIdentity>, is_cloud: bool) -> Self {
+        match assignee {
+            Some(UserIdentity::AccountId(account_id)) => Self::AccountId {
+                account_id: Some(account_id),
+            },
+            Some(UserIdentity::Name(name)) => Self::Name { name: Some(name) },
+            None if is_cloud => Self::AccountId { account_id: None },
+            None => Self::Name { name: None },
         }
     }
 }
@@ -42,6 +60,8 @@ struct IssueFieldsReqData<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     assignee: Option<AssigneeData<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(alias = "Story Points")]
     story_points: Option<usize>,
 }
@@ -58,11 +78,19 @@ impl<'a> IssuePropertiesReqData<'a> {
         }
     }
 
-    pub fn set_assignee(&mut self, assignee_username: &'a str) {
-        let assignee_data = Some(AssigneeData::new(assignee_username));
+    pub fn set_assignee(
+        &mut self,
+        assignee: Option<&'a UserIdentity>,
+        is_cloud: bool,
+    ) {
+        let assignee_data = Some(AssigneeData::new(assignee, is_cloud));
         self.fields.assignee = assignee_data;
     }
 
+    pub fn set_description(&mut self, description: serde_json::Value) {
+        self.fields.description = Some(description);
+    }
+
     pub fn add_story_points_and_return_as_string(
         self,
         new_story_points: usize,
@@ -90,3 +118,63 @@ impl<'a> IssuePropertiesReqData<'a> {
         serde_json::from_str(str_data.as_str()).unwrap()
     }
 }
+
+/// Request data for a new comment.
+///
+/// `body` is a plain string for API version 2
+/// and ADF document for version 3.
+#[derive(Serialize)]
+pub(crate) struct IssueCommentReqData {
+    body: serde_json::Value,
+}
+
+impl IssueCommentReqData {
+    pub fn new(body: serde_json::Value) -> Self {
+        Self { body }
+    }
+}
+
+#[derive(Serialize)]
+struct IssueKeyData<'a> {
+    key: &'a str,
+}
+
+#[derive(Serialize)]
+struct IssueLinkTypeData<'a> {
+    name: &'a str,
+}
+
+/// Request data to link two issues.
+///
+/// Jira describes the link from `inward_issue`
+/// with the outward description of the type,
+/// e.g. `inward_issue` blocks `outward_issue`.
+#[derive(Serialize)]
+pub(crate) struct IssueLinkReqData<'a> {
+    #[serde(rename = "type")]
+    link_type: IssueLinkTypeData<'a>,
+    #[serde(rename = "inwardIssue")]
+    inward_issue: IssueKeyData<'a>,
+    #[serde(rename = "outwardIssue")]
+    outward_issue: IssueKeyData<'a>,
+}
+
+impl<'a> IssueLinkReqData<'a> {
+    pub fn new(
+        link_type_name: &'a str,
+        inward_issue_key: &'a str,
+        outward_issue_key: &'a str,
+    ) -> Self {
+        Self {
+            link_type: IssueLinkTypeData {
+                name: link_type_name,
+            },
+            inward_issue: IssueKeyData {
+                key: inward_issue_key,
+            },
+            outward_issue: IssueKeyData {
+                key: outward_issue_key,
+            },
+        }
+    }
+}
@@ -1,25 +1,60 @@
-use std::sync::{Arc, RwLock};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, RwLock};
 
 use super::{
+    auto_refresh::views::{
+        start_auto_refresh, stop_auto_refresh, NotificationView,
+    },
     bottom_menu::views::BottomMenuView,
     common::views::{ChangeJiraView, JiraViewWithName},
-    global_callbacks::add_global_callbacks,
+    global_callbacks::{add_global_callbacks, remove_global_callbacks},
+    input_mode::InputMode,
     jira_data::JiraData,
     layouts::{InfoLayout, TasksProjectsLayout},
-    projects::data::JiraProjects,
 };
 use crate::jira::projects::views::ProjectsView;
+use crate::jira::tasks::views::{InfoView, TasksSelectView, TasksView};
 
-use crate::Config;
+use crate::{
+    state::{CompanyState, SessionState},
+    theme::AppTheme,
+    Config,
+};
 use cursive::{
     view::{Nameable, Resizable},
     views::LinearLayout,
     Cursive,
 };
 
+/// `JiraData` of the companies opened in this run of the app.
+///
+/// Data of the company is kept when another company is opened,
+/// so switching back doesn't load projects and tasks again.
+static OPENED_COMPANIES: Mutex<BTreeMap<String, Arc<RwLock<JiraData>>>> =
+    Mutex::new(BTreeMap::new());
+
 pub fn make_jira_screen(cursive: &mut Cursive, company_name: &str) {
-    add_global_callbacks(cursive);
-    let jira_data = init_data(company_name);
+    let jira_data = OPENED_COMPANIES
+        .lock()
+        .unwrap()
+        .entry(company_name.into())
+        .or_insert_with(|| init_data(company_name))
+        .clone();
+    // Config could be changed while the company was closed.
+    if let Ok(config) = Config::new() {
+        apply_config(&mut jira_data.write().unwrap(), &config);
+    }
+    make_jira_screen_with_data(cursive, jira_data);
+    start_auto_refresh(cursive);
+}
+
+/// Builds Jira screen around already initialized `JiraData`.
+pub(crate) fn make_jira_screen_with_data(
+    cursive: &mut Cursive,
+    jira_data: Arc<RwLock<JiraData>>,
+) {
+    let keymap = jira_data.read().unwrap().keymap.clone();
+    add_global_callbacks(cursive, &keymap);
     cursive.set_user_data(jira_data);
 
     let screen_size = cursive.screen_size();
@@ -28,7 +63,7 @@ pub fn make_jira_screen(cursive: &mut Cursive, company_name: &str) {
 
     let mut all_layout = LinearLayout::vertical();
 
-    let bottom_menu = BottomMenuView::default();
+    let bottom_menu = BottomMenuView::new(&keymap);
 
     let mut main_layout = LinearLayout::horizontal();
 
@@ -46,24 +81,176 @@ pub fn make_jira_screen(cursive: &mut Cursive, company_name: &str) {
     );
 
     all_layout.add_child(main_layout);
-    all_layout.add_child(bottom_menu);
+    all_layout.add_child(
+        LinearLayout::horizontal()
+            .child(bottom_menu.full_width())
+            .child(NotificationView::default()),
+    );
 
     cursive.add_layer(all_layout);
 
     ProjectsView::get_view(cursive).update_view_content(cursive);
+    restore_session(cursive);
+}
+
+/// Opens project, tasks search, selected row of the tasks list
+/// and issue from the last session with the company.
+fn restore_session(cursive: &mut Cursive) {
+    let jira_data: Arc<RwLock<JiraData>> = cursive
+        .user_data::<Arc<RwLock<JiraData>>>()
+        .unwrap()
+        .clone();
+    let (session, project_name) = {
+        let jira_data_guard = jira_data.read().unwrap();
+        let session = jira_data_guard.company_state.session().clone();
+        // The project could be removed since the last session.
+        let project_name = session.project.clone().filter(|project_name| {
+            jira_data_guard
+                .get_projects_names()
+                .contains(&project_name.as_str())
+        });
+        (session, project_name)
+    };
+    let Some(project_name) = project_name else {
+        return;
+    };
+
+    // Tasks of the company opened before are shown without reloading.
+    let is_tasks_cached = jira_data
+        .read()
+        .unwrap()
+        .get_project(&project_name)
+        .is_some_and(|project| project.tasks.is_some());
+    if is_tasks_cached {
+        jira_data
+            .write()
+            .unwrap()
+            .set_selected_project(&project_name);
+        TasksView::get_view(cursive).update_view_content(cursive);
+    } else {
+        ProjectsView::open_project(cursive, &project_name);
+    }
+    let is_tasks_loaded = jira_data
+        .read()
+        .unwrap()
+        .get_selected_project()
+        .is_some_and(|project| project.tasks.is_some());
+    if !is_tasks_loaded {
+        return;
+    }
+    if !session.search_text.is_empty() {
+        TasksView::set_search_text(cursive, &session.search_text);
+    }
+    let _ = TasksSelectView::get_view(cursive)
+        .inner_view
+        .set_selection(session.selected_row);
+    // List can't be focused if it's empty, search stays focused then.
+    let _ = cursive.focus_name(&TasksSelectView::view_name());
+
+    let Some(issue_key) = session.issue else {
+        return;
+    };
+    let open_result = jira_data.write().unwrap().open_issue(&issue_key);
+    // The issue could be removed since the last session.
+    if let Ok(is_project_changed) = open_result {
+        if is_project_changed {
+            TasksView::get_view(cursive).update_view_content(cursive);
+        }
+        InfoView::get_view(cursive).update_view_content(cursive);
+    }
+}
+
+/// Remembers the selected project, tasks search, selected row
+/// of the tasks list and issue in the company state.
+///
+/// Does nothing if the Jira screen isn't opened.
+pub(crate) fn save_session(cursive: &mut Cursive) {
+    let Some(jira_data) =
+        cursive.user_data::<Arc<RwLock<JiraData>>>().cloned()
+    else {
+        return;
+    };
+    let search_text = TasksView::get_search_text(cursive);
+    let selected_row = cursive
+        .call_on_name(
+            &TasksSelectView::view_name(),
+            |tasks_select_view: &mut TasksSelectView| {
+                tasks_select_view.inner_view.selected_id()
+            },
+        )
+        .flatten()
+        .unwrap_or_default();
+
+    let mut jira_data_guard = jira_data.write().unwrap();
+    let session = if jira_data_guard.selected_project.is_empty() {
+        SessionState::default()
+    } else {
+        let project = jira_data_guard.get_selected_project();
+        // Selected task stays the same when another project is opened.
+        let issue = project
+            .and_then(|project| {
+                project.tasks.as_ref()?.get(&jira_data_guard.selected_task)
+            })
+            .map(|task| task.key.clone());
+        SessionState {
+            project: project.map(|project| project.name.clone()),
+            issue,
+            search_text,
+            selected_row,
+        }
+    };
+    jira_data_guard.company_state.set_session(session);
+    // Session is only a convenience, so failed save isn't critical.
+    let _ = jira_data_guard.company_state.save();
+}
+
+/// Applies keymap, theme colors, branch template, saved filters
+/// and auto refresh interval from the config.
+fn apply_config(jira_data: &mut JiraData, config: &Config) {
+    jira_data.keymap = config.get_keymap().clone();
+    jira_data.branch_template = config.get_branch_template().into();
+    jira_data.saved_filters =
+        config.get_saved_filters(&jira_data.company_name).to_vec();
+    jira_data.auto_refresh_minutes = config.get_auto_refresh_minutes();
+    if let Ok(app_theme) = AppTheme::load(config.get_theme()) {
+        jira_data.colors = app_theme.colors;
+    }
+}
+
+/// Removes the Jira screen with its global callbacks.
+///
+/// Session is saved, so it's restored when the company is opened again.
+/// Does nothing if the Jira screen isn't opened.
+pub(crate) fn close_jira_screen(cursive: &mut Cursive) {
+    save_session(cursive);
+    let Some(jira_data) = cursive.take_user_data::<Arc<RwLock<JiraData>>>()
+    else {
+        return;
+    };
+    {
+        let mut jira_data_guard = jira_data.write().unwrap();
+        remove_global_callbacks(cursive, &jira_data_guard.keymap);
+        jira_data_guard.activated_views.clear();
+        jira_data_guard.input_mode = InputMode::Normal;
+        jira_data_guard.history_tab = None;
+        stop_auto_refresh(&mut jira_data_guard);
+    }
+    while cursive.pop_layer().is_some() {}
 }
 
 fn init_data(company_name: &str) -> Arc<RwLock<JiraData>> {
     let config = Config::new().unwrap();
     let jira = config.get_jira_by_company(company_name).unwrap();
-    let jira_data = Arc::new(RwLock::new(JiraData::new(
+    let mut jira_data = JiraData::new(
         jira.get_url(),
         jira.get_encoded_creds(),
-    )));
+        jira.get_api_version(),
+    );
+    jira_data.company_name = company_name.into();
+    jira_data.company_state = CompanyState::load(company_name);
+    let jira_data = Arc::new(RwLock::new(jira_data));
 
-    let jira_projects =
-        JiraProjects::new(jira_data.read().unwrap().client.clone());
-    jira_data.write().unwrap().update_projects(jira_projects);
+    jira_data.write().unwrap().load_projects_and_fields();
 
     jira_data
 }
@@ -0,0 +1,325 @@
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{RusjiError, RusjiResult},
+    jira::{
+        constance::{CHANGELOG_REQUEST_SIZE, HISTORY_PAGE_SIZE},
+        tasks_actions::data::JiraUser,
+    },
+    request_client::client::RequestClient,
+};
+
+/// Whole changelog of the issue.
+///
+/// Changes are filtered before they are split into pages,
+/// the page `0` has the latest changes.
+#[derive(Debug, Clone, Default)]
+pub struct IssueChangelog {
+    /// Histories from the oldest one, as Jira returns them.
+    histories: Vec<ChangelogHistory>,
+}
+
+/// One page of the changelog as Jira returns it.
+///
+/// The changelog endpoint returns `values`,
+/// the changelog of the issue with `expand=changelog` has `histories`.
+#[derive(Serialize, Deserialize, Debug)]
+struct ChangelogPage {
+    total: usize,
+    #[serde(default, alias = "histories")]
+    values: Vec<ChangelogHistory>,
+}
+
+/// Issue loaded with `expand=changelog`.
+#[derive(Serialize, Deserialize, Debug)]
+struct IssueWithChangelog {
+    changelog: ChangelogPage,
+}
+
+/// Group of changes made by one user at once.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ChangelogHistory {
+    author: Option<JiraUser>,
+    created: String,
+    #[serde(default)]
+    items: Vec<ChangelogItem>,
+}
+
+/// Change of the single field.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ChangelogItem {
+    field: String,
+    #[serde(alias = "fromString")]
+    from_string: Option<String>,
+    #[serde(alias = "toString")]
+    to_string: Option<String>,
+}
+
+/// Filter for the changelog entries shown in the history tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChangelogFilter {
+    #[default]
+    All,
+    Status,
+    Assignee,
+}
+
+impl From<ChangelogFilter> for &str {
+    fn from(filter: ChangelogFilter) -> Self {
+        match filter {
+            ChangelogFilter::All => "all",
+            ChangelogFilter::Status => "status",
+            ChangelogFilter::Assignee => "assignee",
+        }
+    }
+}
+
+impl ChangelogFilter {
+    /// Returns the next filter, the last one is followed by the first.
+    pub fn next(self) -> Self {
+        match self {
+            ChangelogFilter::All => ChangelogFilter::Status,
+            ChangelogFilter::Status => ChangelogFilter::Assignee,
+            ChangelogFilter::Assignee => ChangelogFilter::All,
+        }
+    }
+
+    fn is_suitable(self, field: &str) -> bool {
+        match self {
+            ChangelogFilter::All => true,
+            ChangelogFilter::Status => field == "status",
+            ChangelogFilter::Assignee => field == "assignee",
+        }
+    }
+}
+
+impl IssueChangelog {
+    /// Loads the whole changelog of the issue.
+    ///
+    /// Histories are requested page by page from the changelog endpoint.
+    /// Old Jira Server doesn't have it, so the changelog is loaded
+    /// with the issue by `expand=changelog` if the endpoint isn't found.
+    pub fn new(
+        request_client: Arc<RwLock<RequestClient>>,
+        issue_key: &str,
+    ) -> RusjiResult<Self> {
+        let mut changelog = Self::default();
+        loop {
+            let start_at = changelog.histories.len();
+            let page = match ChangelogPage::load(
+                &request_client,
+                issue_key,
+                start_at,
+            ) {
+                Err(RusjiError::BadResponseStatus(404)) if start_at == 0 => {
+                    return Self::load_with_issue(&request_client, issue_key)
+                }
+                page => page?,
+            };
+            let is_empty = page.values.is_empty();
+            changelog.histories.extend(page.values);
+            if is_empty || changelog.histories.len() >= page.total {
+                return Ok(changelog);
+            }
+        }
+    }
+
+    fn load_with_issue(
+        request_client: &Arc<RwLock<RequestClient>>,
+        issue_key: &str,
+    ) -> RusjiResult<Self> {
+        let response = request_client
+            .read()
+            .unwrap()
+            .get_issue_with_changelog(issue_key)?;
+        Ok(Self {
+            histories: response
+                .parse::<IssueWithChangelog>()?
+                .changelog
+                .values,
+        })
+    }
+
+    /// Returns amount of pages with the changes which fit the filter,
+    /// there is at least one page.
+    pub fn pages_count(&self, filter: ChangelogFilter) -> usize {
+        self.changes(filter)
+            .count()
+            .div_ceil(HISTORY_PAGE_SIZE)
+            .max(1)
+    }
+
+    /// Returns text of every change of the page among changes
+    /// which fit the filter.
+    ///
+    /// The latest change is the first one.
+    pub fn entries(
+        &self,
+        filter: ChangelogFilter,
+        page: usize,
+    ) -> Vec<String> {
+        self.changes(filter)
+            .skip(page * HISTORY_PAGE_SIZE)
+            .take(HISTORY_PAGE_SIZE)
+            .map(|(history, item)| {
+                let author = history
+                    .author
+                    .as_ref()
+                    .map(|author| author.display_name.as_str())
+                    .unwrap_or("Anonymous");
+                format!(
+                    "{} | {} | {}: {} → {}",
+                    format_timestamp(&history.created),
+                    author,
+                    item.field,
+                    item.from_string.as_deref().unwrap_or("none"),
+                    item.to_string.as_deref().unwrap_or("none"),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns changes which fit the filter from the latest one.
+    fn changes(
+        &self,
+        filter: ChangelogFilter,
+    ) -> impl Iterator<Item = (&ChangelogHistory, &ChangelogItem)> {
+        self.histories
+            .iter()
+            .rev()
+            .flat_map(|history| {
+                history.items.iter().map(move |item| (history, item))
+            })
+            .filter(move |(_, item)| filter.is_suitable(&item.field))
+    }
+}
+
+impl ChangelogPage {
+    fn load(
+        request_client: &Arc<RwLock<RequestClient>>,
+        issue_key: &str,
+        start_at: usize,
+    ) -> RusjiResult<Self> {
+        let response = request_client.read().unwrap().get_issue_changelog(
+            issue_key,
+            start_at,
+            CHANGELOG_REQUEST_SIZE,
+        )?;
+        response.parse::<Self>()
+    }
+}
+
+/// State of the history tab in `InfoView`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HistoryTab {
+    pub filter: ChangelogFilter,
+    pub page: usize,
+}
+
+/// Converts Jira timestamp like `2023-01-10T10:15:30.000+0000`
+/// into `2023-01-10 10:15`.
+pub fn format_timestamp(timestamp: &str) -> String {
+    timestamp
+        .chars()
+        .take(16)
+        .collect::<String>()
+        .replace('T', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changelog_entries() {
+        let changelog_str = r#"
+        {
+            "startAt": 0,
+            "maxResults": 2,
+            "total": 2,
+            "isLast": true,
+            "values": [
+                {
+                    "id": "1",
+                    "author": {
+                        "self": "https://link.com",
+                        "name": "alice",
+                        "displayName": "Alice Doe"
+                    },
+                    "created": "2023-01-10T10:15:30.000+0000",
+                    "items": [
+                        {
+                            "field": "status",
+                            "fieldtype": "jira",
+                            "fromString": "To Do",
+                            "toString": "In Progress"
+                        },
+                        {
+                            "field": "assignee",
+                            "fieldtype": "jira",
+                            "fromString": null,
+                            "toString": "Alice Doe"
+                        }
+                    ]
+                },
+                {
+                    "id": "2",
+                    "created": "2023-01-11T08:00:00.000+0000",
+                    "items": [
+                        {
+                            "field": "status",
+                            "fieldtype": "jira",
+                            "fromString": "In Progress",
+                            "toString": "Done"
+                        }
+                    ]
+                }
+            ]
+        }
+        "#;
+
+        let changelog = IssueChangelog {
+            histories: serde_json::from_str::<ChangelogPage>(changelog_str)
+                .unwrap()
+                .values,
+        };
+
+        assert_eq!(
+            changelog.entries(ChangelogFilter::All, 0),
+            vec![
+                "2023-01-11 08:00 | Anonymous | status: In Progress → Done",
+                "2023-01-10 10:15 | Alice Doe | status: To Do → In Progress",
+                "2023-01-10 10:15 | Alice Doe | assignee: none → Alice Doe",
+            ]
+        );
+        assert_eq!(changelog.entries(ChangelogFilter::Assignee, 0).len(), 1);
+        assert_eq!(changelog.entries(ChangelogFilter::Status, 0).len(), 2);
+    }
+
+    #[test]
+    fn test_filtered_pages() {
+        let history = |field: &str| ChangelogHistory {
+            author: None,
+            created: "2023-01-10T10:15:30.000+0000".into(),
+            items: vec![ChangelogItem {
+                field: field.into(),
+                from_string: None,
+                to_string: None,
+            }],
+        };
+        let mut changelog = IssueChangelog::default();
+        assert_eq!(changelog.pages_count(ChangelogFilter::All), 1);
+
+        for _ in 0..15 {
+            changelog.histories.push(history("status"));
+            changelog.histories.push(history("assignee"));
+        }
+        assert_eq!(changelog.pages_count(ChangelogFilter::All), 3);
+        assert_eq!(changelog.pages_count(ChangelogFilter::Status), 2);
+        assert_eq!(changelog.entries(ChangelogFilter::Status, 0).len(), 10);
+        assert_eq!(changelog.entries(ChangelogFilter::Status, 1).len(), 5);
+        assert!(changelog.entries(ChangelogFilter::Status, 2).is_empty());
+    }
+}
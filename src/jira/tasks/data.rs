@@ -4,16 +4,28 @@ use std::sync::{Arc, RwLock};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
+use super::changelog::{format_timestamp, IssueChangelog};
 use crate::{
-    errors::RusjiResult, jira::tasks_actions::data::JiraUser,
-    request_client::request_client::RequestClient,
+    errors::RusjiResult,
+    jira::{
+        adf::{
+            convert::{adf_to_text, is_text_convertible, text_to_adf},
+            html::html_to_adf,
+            wiki::wiki_to_adf,
+        },
+        constance::ISSUES_LOAD_LIMIT,
+        tasks_actions::data::JiraUser,
+    },
+    request_client::client::{RequestClient, RequestResponse},
 };
 
 /// JiraIssues holds all necessary information
 /// about task to interact with it.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct JiraIssues {
     issues: Vec<JiraIssue>,
+    #[serde(default)]
+    total: usize,
 }
 
 impl IntoIterator for JiraIssues {
@@ -26,21 +38,58 @@ impl IntoIterator for JiraIssues {
 }
 
 impl JiraIssues {
+    /// Returns mutable iterator over the issues.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, JiraIssue> {
+        self.issues.iter_mut()
+    }
+
     /// Creates new instance of JiraIssues.
     ///
-    /// Makes request to get tasks for project and parses the response.
+    /// Makes requests to get tasks for project page by page
+    /// and parses the responses.
+    /// Stops when all tasks are loaded or `ISSUES_LOAD_LIMIT` is reached.
     /// If request failed return error.
     pub fn new(
         request_client: Arc<RwLock<RequestClient>>,
         project_name: &str,
     ) -> RusjiResult<Self> {
-        let response = request_client
-            .read()
-            .unwrap()
-            .get_tasks_from_project(project_name)?;
+        Self::load_pages(|start_at| {
+            request_client
+                .read()
+                .unwrap()
+                .get_tasks_from_project(project_name, start_at)
+        })
+    }
+
+    /// Finds issues by JQL, pages are loaded like in `new`.
+    pub fn search(
+        request_client: Arc<RwLock<RequestClient>>,
+        jql: &str,
+    ) -> RusjiResult<Self> {
+        Self::load_pages(|start_at| {
+            request_client.read().unwrap().search_issues(jql, start_at)
+        })
+    }
 
-        let resp_text = response.get_body();
-        let tasks = serde_json::from_str::<Self>(resp_text)?;
+    /// Loads pages with `load_page` until all issues are loaded
+    /// or `ISSUES_LOAD_LIMIT` is reached.
+    fn load_pages<F>(load_page: F) -> RusjiResult<Self>
+    where
+        F: Fn(usize) -> RusjiResult<RequestResponse>,
+    {
+        let parse_page = |start_at: usize| -> RusjiResult<Self> {
+            let response = load_page(start_at)?;
+            response.parse::<Self>()
+        };
+        let mut tasks = parse_page(0)?;
+
+        while tasks.issues.len() < tasks.total.min(ISSUES_LOAD_LIMIT) {
+            let next_page = parse_page(tasks.issues.len())?;
+            if next_page.issues.is_empty() {
+                break;
+            }
+            tasks.issues.extend(next_page.issues);
+        }
         Ok(tasks)
     }
 }
@@ -52,11 +101,33 @@ pub struct JiraIssue {
     #[serde(alias = "self")]
     pub link: String,
     pub key: String,
+    /// Description rendered by Jira into HTML,
+    /// empty if Jira didn't render it.
     pub description: String,
+    /// Description from `fields`, wiki markup string
+    /// for API version 2 or ADF document for version 3.
+    pub raw_description: Option<Value>,
     pub summary: String,
     pub status: JiraIssueStatus,
     pub transitions: Option<IssueTransitions>,
     pub assignee: Option<JiraUser>,
+    pub parent: Option<LinkedIssue>,
+    pub subtasks: Vec<LinkedIssue>,
+    pub issue_links: Vec<IssueLink>,
+    /// Custom fields with string values, like `Epic Link`.
+    pub custom_fields: Map<String, String>,
+    /// Custom fields with list values, like `Sprint`,
+    /// only names of the items are kept.
+    pub custom_lists: Map<String, Vec<String>>,
+    pub attachments: Vec<IssueAttachment>,
+    pub labels: Vec<String>,
+    pub priority: Option<IssuePriority>,
+    pub issue_type: Option<IssueType>,
+    /// Time of the last update, as Jira returns it.
+    pub updated: String,
+    /// Changelog is loaded separately, only for the history tab.
+    #[serde(skip)]
+    pub changelog: Option<IssueChangelog>,
 }
 
 /// Creates custom Deserialize for JiraTask.
@@ -86,37 +157,128 @@ impl<'de> Deserialize<'de> for JiraIssue {
         #[derive(Serialize, Deserialize, Debug)]
         struct Fields {
             summary: String,
+            #[serde(default)]
+            description: Option<Value>,
             status: JiraIssueStatus,
             assignee: Option<JiraUser>,
+            #[serde(default)]
+            parent: Option<LinkedIssue>,
+            #[serde(default)]
+            subtasks: Vec<LinkedIssue>,
+            #[serde(default)]
+            issuelinks: Vec<IssueLink>,
+            #[serde(default)]
+            attachment: Vec<IssueAttachment>,
+            #[serde(default)]
+            labels: Vec<String>,
+            #[serde(default)]
+            priority: Option<IssuePriority>,
+            #[serde(default)]
+            issuetype: Option<IssueType>,
+            #[serde(default)]
+            updated: String,
+            #[serde(flatten)]
+            other: Map<String, Value>,
         }
 
         #[derive(Serialize, Deserialize, Debug)]
         struct RenderedFields {
-            description: String,
+            // Jira sends `null` for issues without description.
+            #[serde(default)]
+            description: Option<String>,
         }
 
         fn default_rendered_fields() -> RenderedFields {
-            RenderedFields {
-                description: "No description".to_string(),
-            }
+            RenderedFields { description: None }
         }
 
         let task = Task::deserialize(deserializer)?;
+        let mut custom_fields: Map<String, String> = Map::new();
+        let mut custom_lists: Map<String, Vec<String>> = Map::new();
+        for (field_id, value) in task.fields.other {
+            if !field_id.starts_with("customfield_") {
+                continue;
+            }
+            match value {
+                Value::String(value) => {
+                    custom_fields.insert(field_id, value);
+                }
+                Value::Array(items) => {
+                    custom_lists.insert(
+                        field_id,
+                        items.iter().filter_map(list_item_name).collect(),
+                    );
+                }
+                _ => {}
+            }
+        }
 
         Ok(JiraIssue {
             id: task.id,
             link: task.link,
             key: task.key,
-            description: task.rendered_fields.description,
+            description: task.rendered_fields.description.unwrap_or_default(),
+            raw_description: task.fields.description,
             summary: task.fields.summary,
             status: task.fields.status,
             transitions: Default::default(),
             assignee: task.fields.assignee,
+            parent: task.fields.parent,
+            subtasks: task.fields.subtasks,
+            issue_links: task.fields.issuelinks,
+            custom_fields,
+            custom_lists,
+            attachments: task.fields.attachment,
+            labels: task.fields.labels,
+            priority: task.fields.priority,
+            issue_type: task.fields.issuetype,
+            updated: task.fields.updated,
+            changelog: None,
         })
     }
 }
 
+/// Returns name of the item from the custom field with list value.
+///
+/// Jira Server returns sprints as strings like
+/// `com.atlassian.greenhopper.service.sprint.Sprint@1f[id=1,name=S1,...]`,
+/// Jira Cloud returns them as objects with `name`.
+fn list_item_name(item: &Value) -> Option<String> {
+    match item {
+        Value::String(item) => {
+            let name = item
+                .split_once("name=")
+                .map(|(_, rest)| rest.split(',').next().unwrap_or_default())
+                .unwrap_or(item);
+            Some(name.to_string())
+        }
+        Value::Object(item) => item
+            .get("name")
+            .or_else(|| item.get("value"))
+            .and_then(Value::as_str)
+            .map(String::from),
+        _ => None,
+    }
+}
+
 impl JiraIssue {
+    /// Returns true if the user is assignee of the issue.
+    ///
+    /// Users are compared by `accountId` if both have it,
+    /// otherwise by username or display name.
+    pub fn is_assigned_to(&self, user: &JiraUser) -> bool {
+        let Some(assignee) = &self.assignee else {
+            return false;
+        };
+        match (&assignee.account_id, &user.account_id) {
+            (Some(assignee_id), Some(user_id)) => assignee_id == user_id,
+            _ => {
+                (assignee.name.is_some() && assignee.name == user.name)
+                    || assignee.display_name == user.display_name
+            }
+        }
+    }
+
     /// Create new instance of JiraIssue.
     ///
     /// Makes request to Jira API.
@@ -126,32 +288,305 @@ impl JiraIssue {
         issue_key: &str,
     ) -> RusjiResult<Self> {
         let response = request_client.read().unwrap().get_task(issue_key)?;
-        let resp_text = response.get_body();
-        let task = serde_json::from_str::<Self>(resp_text)?;
-        Ok(task)
+        response.parse::<Self>()
+    }
+
+    /// Returns description as ADF document.
+    ///
+    /// Returns `None` if Jira didn't send the description in ADF.
+    pub fn description_adf(&self) -> Option<&Value> {
+        self.raw_description
+            .as_ref()
+            .filter(|description| description.is_object())
+    }
+
+    /// Returns description as ADF document to show it.
+    ///
+    /// ADF from API version 3 is used as is, otherwise HTML rendered
+    /// by Jira is converted or raw wiki markup if Jira didn't render it.
+    pub fn description_document(&self) -> Value {
+        if let Some(description_adf) = self.description_adf() {
+            return description_adf.clone();
+        }
+        if !self.description.trim().is_empty() {
+            return html_to_adf(&self.description);
+        }
+        match &self.raw_description {
+            Some(Value::String(description))
+                if !description.trim().is_empty() =>
+            {
+                wiki_to_adf(description)
+            }
+            _ => text_to_adf("No description"),
+        }
+    }
+
+    /// Returns description as a plain text to edit it.
+    pub fn description_text(&self) -> String {
+        match &self.raw_description {
+            Some(Value::String(description)) => description.clone(),
+            Some(description) if description.is_object() => {
+                adf_to_text(description)
+            }
+            _ => String::default(),
+        }
+    }
+
+    /// Returns true if the description can be edited as a plain text
+    /// and saved back without losing its content.
+    pub fn is_description_text_convertible(&self) -> bool {
+        match &self.raw_description {
+            Some(description) if description.is_object() => {
+                is_text_convertible(description)
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns key of the epic the issue belongs to.
+    ///
+    /// See `relations` about where the epic is stored.
+    pub fn epic_key(&self, epic_link_field: Option<&str>) -> Option<&str> {
+        match &self.parent {
+            Some(parent) if parent.issue_type_name() == Some("Epic") => {
+                Some(parent.key.as_str())
+            }
+            _ => epic_link_field
+                .and_then(|field| self.custom_fields.get(field))
+                .map(String::as_str),
+        }
+    }
+
+    /// Returns name of the latest sprint of the issue.
+    pub fn sprint_name(&self, sprint_field: Option<&str>) -> Option<&str> {
+        sprint_field
+            .and_then(|field| self.custom_lists.get(field))
+            .and_then(|sprints| sprints.last())
+            .map(String::as_str)
+    }
+
+    /// Returns parent, epic, subtasks and linked issues.
+    ///
+    /// Jira Server keeps epic in the `Epic Link` custom field,
+    /// Jira Cloud returns it as a parent with `Epic` type.
+    pub fn relations(
+        &self,
+        epic_link_field: Option<&str>,
+    ) -> Vec<IssueRelation> {
+        let mut relations: Vec<IssueRelation> = Vec::new();
+
+        if let Some(parent) = &self.parent {
+            let description = match parent.issue_type_name() {
+                Some("Epic") => "epic",
+                _ => "parent",
+            };
+            relations.push(IssueRelation::new(description, parent, None));
+        }
+        if let Some(epic_key) =
+            epic_link_field.and_then(|field| self.custom_fields.get(field))
+        {
+            relations.push(IssueRelation::new(
+                "epic",
+                &LinkedIssue::from_key(epic_key),
+                None,
+            ));
+        }
+        for subtask in &self.subtasks {
+            relations.push(IssueRelation::new("subtask", subtask, None));
+        }
+        for issue_link in &self.issue_links {
+            let (description, linked_issue) =
+                match (&issue_link.outward_issue, &issue_link.inward_issue) {
+                    (Some(outward_issue), _) => {
+                        (&issue_link.link_type.outward, outward_issue)
+                    }
+                    (None, Some(inward_issue)) => {
+                        (&issue_link.link_type.inward, inward_issue)
+                    }
+                    (None, None) => continue,
+                };
+            relations.push(IssueRelation::new(
+                description,
+                linked_issue,
+                Some(&issue_link.id),
+            ));
+        }
+        relations
     }
 
     /// Add transitions to the JiraIssue instance.
     ///
     /// It is necessary because issue status can be changed in time,
     /// so here transitions get in real time.
+    /// Transitions are kept unchanged if they can't be loaded.
     pub fn add_transitions(
         &mut self,
         request_client: Arc<RwLock<RequestClient>>,
-    ) {
+    ) -> RusjiResult<()> {
         let response = request_client
             .read()
             .unwrap()
-            .get_issue_transitions(&self.key)
-            .unwrap();
+            .get_issue_transitions(&self.key)?;
 
-        let available_transactions =
-            serde_json::from_str::<IssueTransitions>(response.get_body())
-                .unwrap();
+        let available_transactions = response.parse::<IssueTransitions>()?;
         self.transitions = Option::Some(available_transactions);
+        Ok(())
+    }
+}
+
+/// Short information about issue related to another one.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LinkedIssue {
+    pub key: String,
+    #[serde(default)]
+    fields: LinkedIssueFields,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+struct LinkedIssueFields {
+    #[serde(default)]
+    summary: String,
+    status: Option<NamedField>,
+    #[serde(alias = "issuetype")]
+    issue_type: Option<NamedField>,
+}
+
+/// Any Jira entity where only the name is necessary.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct NamedField {
+    name: String,
+}
+
+impl LinkedIssue {
+    /// Creates linked issue without any information except the key.
+    fn from_key(key: &str) -> Self {
+        Self {
+            key: key.into(),
+            fields: LinkedIssueFields::default(),
+        }
+    }
+
+    fn issue_type_name(&self) -> Option<&str> {
+        self.fields
+            .issue_type
+            .as_ref()
+            .map(|field| field.name.as_str())
+    }
+}
+
+/// Model for single issue link from `issuelinks` field.
+///
+/// Only one of the `inward_issue` and `outward_issue` is present,
+/// it's the issue on the other side of the link.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IssueLink {
+    pub id: String,
+    #[serde(alias = "type")]
+    pub link_type: IssueLinkType,
+    #[serde(alias = "inwardIssue")]
+    pub inward_issue: Option<LinkedIssue>,
+    #[serde(alias = "outwardIssue")]
+    pub outward_issue: Option<LinkedIssue>,
+}
+
+/// Model for issue link type, like `Blocks` or `Relates`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IssueLinkType {
+    pub name: String,
+    /// Description from the inward issue side, e.g. `is blocked by`.
+    pub inward: String,
+    /// Description from the outward issue side, e.g. `blocks`.
+    pub outward: String,
+}
+
+/// Relation of the issue to another one, shown in `InfoView`.
+pub struct IssueRelation {
+    pub description: String,
+    pub key: String,
+    summary: String,
+    status_name: Option<String>,
+    /// Id of the issue link, `None` for parent, epic and subtasks.
+    pub link_id: Option<String>,
+}
+
+impl IssueRelation {
+    fn new(
+        description: &str,
+        linked_issue: &LinkedIssue,
+        link_id: Option<&String>,
+    ) -> Self {
+        Self {
+            description: description.into(),
+            key: linked_issue.key.clone(),
+            summary: linked_issue.fields.summary.clone(),
+            status_name: linked_issue
+                .fields
+                .status
+                .as_ref()
+                .map(|status| status.name.clone()),
+            link_id: link_id.cloned(),
+        }
+    }
+
+    /// Returns text to show the relation in lists.
+    pub fn display_text(&self) -> String {
+        let mut text = format!("{}: {}", self.description, self.key);
+        if !self.summary.is_empty() {
+            text.push_str(&format!(" -- {}", self.summary));
+        }
+        if let Some(status_name) = &self.status_name {
+            text.push_str(&format!(" [{}]", status_name));
+        }
+        text
+    }
+}
+
+/// Model for single file attached to the issue.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IssueAttachment {
+    pub id: String,
+    pub filename: String,
+    /// Size of the file in bytes.
+    #[serde(default)]
+    pub size: u64,
+    pub author: Option<JiraUser>,
+    #[serde(default)]
+    pub created: String,
+    /// Link to download the file.
+    pub content: String,
+}
+
+impl IssueAttachment {
+    /// Returns text to show the attachment in lists.
+    pub fn display_text(&self) -> String {
+        format!(
+            "{} ({}) | {} | {}",
+            self.filename,
+            format_size(self.size),
+            self.author
+                .as_ref()
+                .map(|author| author.display_name.as_str())
+                .unwrap_or("Anonymous"),
+            format_timestamp(&self.created),
+        )
     }
 }
 
+/// Converts size in bytes into human readable text, like `1.5 KB`.
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if size < 1024 {
+        return format!("{} B", size);
+    }
+    let mut size = size as f64 / 1024.0;
+    let mut unit_idx = 0;
+    while size >= 1024.0 && unit_idx < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_idx += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit_idx])
+}
+
 // Model for all tasks transactions
 // that available at the moment.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -195,18 +630,41 @@ pub struct JiraIssueStatus {
     #[serde(alias = "iconUrl")]
     icon_url: String,
     pub name: String,
+    #[serde(default, alias = "statusCategory")]
+    pub status_category: Option<StatusCategory>,
+}
+
+/// Priority of the issue.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IssuePriority {
+    /// Default priorities have ids from `1` (Highest) to `5` (Lowest).
+    pub id: String,
+    pub name: String,
 }
 
 /// Struct for single task category.
-#[derive(Deserialize, Serialize, Debug)]
-struct StatusCategory {
-    #[serde(alias = "self")]
+///
+/// Jira has only three categories with keys
+/// `new`, `indeterminate` and `done`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StatusCategory {
+    #[serde(default, alias = "self")]
     link: String,
+    #[serde(default)]
     id: u8,
-    key: String,
+    pub key: String,
+    #[serde(default)]
     name: String,
 }
 
+/// Type of the issue, like `Bug` or `Story`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IssueType {
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct IssueMetaData {
     #[serde(flatten)]
@@ -217,44 +675,58 @@ impl IssueMetaData {
     pub fn get_story_points_field_id(&self) -> Option<String> {
         let fields_data = self.other.get("fields").unwrap();
 
-        match fields_data {
-            Value::Object(fields_data) => {
-                for (data_key, data_value) in fields_data {
-                    if !data_key.contains("customfield") {
-                        continue;
-                    }
-                    match data_value {
-                        Value::Object(data) => {
-                            let customfield_name = data.get("name");
-                            if let Some(name) = customfield_name {
-                                if let Value::String(name) = name {
-                                    if name.contains("Story Points") {
-                                        let field_id = data.get("fieldId");
-                                        if let Some(field_id) = field_id {
-                                            match field_id {
-                                                Value::String(field_id) => {
-                                                    return Some(
-                                                        field_id.clone(),
-                                                    );
-                                                }
-                                                _ => {}
-                                            }
-                                        }
-                                    }
-                                }
+        if let Value::Object(fields_data) = fields_data {
+            for (data_key, data_value) in fields_data {
+                if !data_key.contains("customfield") {
+                    continue;
+                }
+                if let Value::Object(data) = data_value {
+                    if let Some(Value::String(name)) = data.get("name") {
+                        if name.contains("Story Points") {
+                            if let Some(Value::String(field_id)) =
+                                data.get("fieldId")
+                            {
+                                return Some(field_id.clone());
                             }
                         }
-                        _ => {}
                     }
                 }
             }
-            _ => {}
         }
 
         None
     }
 }
 
+/// All fields available in Jira.
+#[derive(Serialize, Deserialize)]
+pub struct JiraFields(Vec<JiraField>);
+
+#[derive(Serialize, Deserialize)]
+struct JiraField {
+    id: String,
+    name: String,
+}
+
+impl JiraFields {
+    /// Requests all fields of the Jira.
+    pub fn new(
+        request_client: Arc<RwLock<RequestClient>>,
+    ) -> RusjiResult<Self> {
+        let response = request_client.read().unwrap().get_fields()?;
+        response.parse::<JiraFields>()
+    }
+
+    /// Returns id of the field with passed name.
+    pub fn get_field_id(&self, field_name: &str) -> Option<&str> {
+        let Self(fields) = self;
+        fields
+            .iter()
+            .find(|field| field.name == field_name)
+            .map(|field| field.id.as_str())
+    }
+}
+
 #[derive(Default)]
 pub struct IssueBaseInfoField<'a> {
     pub display_name: &'a str,
@@ -273,33 +745,71 @@ impl<'a> IssueBaseInfoField<'a> {
 #[derive(Default)]
 pub struct IssueBaseInfo<'a> {
     pub summary: IssueBaseInfoField<'a>,
-    pub description: IssueBaseInfoField<'a>,
+    /// Description as ADF document, see `JiraIssue::description_document`.
+    pub description: Value,
     pub task_key: IssueBaseInfoField<'a>,
     pub task_status_name: IssueBaseInfoField<'a>,
     pub issue_assignee: IssueBaseInfoField<'a>,
+    pub status: Option<&'a JiraIssueStatus>,
+    pub priority: Option<&'a IssuePriority>,
+    pub issue_type: Option<&'a IssueType>,
+    pub relations: Vec<IssueRelation>,
+    pub attachments: &'a [IssueAttachment],
 }
 
 impl<'a> IssueBaseInfo<'a> {
     pub fn new(
         summary: IssueBaseInfoField<'a>,
-        description: IssueBaseInfoField<'a>,
         task_key: IssueBaseInfoField<'a>,
         task_status_name: IssueBaseInfoField<'a>,
         issue_assignee: IssueBaseInfoField<'a>,
     ) -> Self {
         Self {
             summary,
-            description,
+            description: Value::Null,
             task_key,
             task_status_name,
             issue_assignee,
+            status: None,
+            priority: None,
+            issue_type: None,
+            relations: Vec::default(),
+            attachments: &[],
+        }
+    }
+
+    /// Creates base info with all fields from the issue.
+    ///
+    /// `epic_link_field` is id of the `Epic Link` custom field.
+    pub fn from_issue(
+        task: &'a JiraIssue,
+        epic_link_field: Option<&str>,
+    ) -> Self {
+        let mut assignee_name: &str = "Unassigned";
+        if let Some(assignee) = &task.assignee {
+            assignee_name =
+                assignee.name.as_deref().unwrap_or(&assignee.display_name);
         }
+        let mut issue_base_info = Self::new(
+            IssueBaseInfoField::new("Summary", &task.summary),
+            IssueBaseInfoField::new("Issue", &task.key),
+            IssueBaseInfoField::new("Status", &task.status.name),
+            IssueBaseInfoField::new("Assignee", assignee_name),
+        );
+        issue_base_info.description = task.description_document();
+        issue_base_info.status = Some(&task.status);
+        issue_base_info.priority = task.priority.as_ref();
+        issue_base_info.issue_type = task.issue_type.as_ref();
+        issue_base_info.relations = task.relations(epic_link_field);
+        issue_base_info.attachments = &task.attachments;
+        issue_base_info
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::jira::adf::render::adf_to_plain_text;
 
     #[test]
     fn test_deserialize_task() {
@@ -344,6 +854,53 @@ mod tests {
         serde_json::from_str::<JiraIssue>(json_task_str).unwrap();
     }
 
+    #[test]
+    fn test_description_document() {
+        let make_issue = |description: Value, rendered_description: Value| {
+            serde_json::from_value::<JiraIssue>(serde_json::json!({
+                "id": "1",
+                "self": "https://link.com",
+                "key": "TST-1",
+                "fields": {
+                    "summary": "Fix login page",
+                    "description": description,
+                    "status": {
+                        "id": "10000",
+                        "self": "https://link.com",
+                        "description": "",
+                        "iconUrl": "https://link.com",
+                        "name": "To Do"
+                    }
+                },
+                "renderedFields": {"description": rendered_description}
+            }))
+            .unwrap()
+        };
+        let description_text = |issue: JiraIssue| {
+            adf_to_plain_text(&issue.description_document())
+        };
+
+        assert_eq!(
+            description_text(make_issue(
+                "*Fails* on submit".into(),
+                "<p><b>Fails</b> on submit</p>".into()
+            )),
+            "Fails on submit"
+        );
+        // Raw wiki markup is used if Jira didn't render it.
+        assert_eq!(
+            description_text(make_issue(
+                "h2. Steps\n* submit".into(),
+                Value::Null
+            )),
+            "Steps\n\n• submit"
+        );
+        assert_eq!(
+            description_text(make_issue(Value::Null, Value::Null)),
+            "No description"
+        );
+    }
+
     #[test]
     fn test_deserialize_issue_metadata() {
         let json_issue_metadata_str = r#"
@@ -0,0 +1,162 @@
+use cursive::{
+    theme::{Effect, Style},
+    utils::markup::StyledString,
+};
+
+use crate::{jira::utils::helpers::highlight_chars, theme::SemanticColors};
+
+use super::{
+    data::{IssuePriority, IssueType, JiraIssue, JiraIssueStatus},
+    search::task_name,
+};
+
+/// Level of the issue priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PriorityLevel {
+    Highest,
+    High,
+    Medium,
+    Low,
+    Lowest,
+}
+
+impl PriorityLevel {
+    /// Returns level of the priority.
+    ///
+    /// Priorities are recognized by the name first,
+    /// so renamed priorities of the default scheme work too.
+    /// Otherwise ids of the default scheme are used.
+    fn from_priority(priority: &IssuePriority) -> Option<Self> {
+        let level = match priority.name.to_lowercase().as_str() {
+            "highest" | "blocker" | "critical" => Self::Highest,
+            "high" | "major" => Self::High,
+            "medium" | "normal" => Self::Medium,
+            "low" | "minor" => Self::Low,
+            "lowest" | "trivial" => Self::Lowest,
+            _ => match priority.id.as_str() {
+                "1" => Self::Highest,
+                "2" => Self::High,
+                "3" => Self::Medium,
+                "4" => Self::Low,
+                "5" => Self::Lowest,
+                _ => return None,
+            },
+        };
+        Some(level)
+    }
+
+    fn glyph(self) -> &'static str {
+        match self {
+            Self::Highest => "⇈",
+            Self::High => "↑",
+            Self::Medium => "=",
+            Self::Low => "↓",
+            Self::Lowest => "⇊",
+        }
+    }
+}
+
+/// Returns style of the status name by its category.
+pub fn status_style(
+    status: &JiraIssueStatus,
+    colors: &SemanticColors,
+) -> Style {
+    let category_key = status
+        .status_category
+        .as_ref()
+        .map(|category| category.key.as_str());
+    let style = Style::from(Effect::Bold);
+    match category_key {
+        Some("new") => style.combine(colors.status_to_do),
+        Some("indeterminate") => style.combine(colors.status_in_progress),
+        Some("done") => style.combine(colors.status_done),
+        _ => style,
+    }
+}
+
+/// Returns glyph of the priority, e.g. `↑` for `High`.
+///
+/// Unknown priority has a space glyph, so rows stay aligned.
+pub fn priority_glyph(
+    priority: Option<&IssuePriority>,
+    colors: &SemanticColors,
+) -> StyledString {
+    let Some(level) = priority.and_then(PriorityLevel::from_priority) else {
+        return StyledString::plain(" ");
+    };
+    let color = match level {
+        PriorityLevel::Highest | PriorityLevel::High => colors.priority_high,
+        PriorityLevel::Medium => colors.priority_medium,
+        PriorityLevel::Low | PriorityLevel::Lowest => colors.priority_low,
+    };
+    StyledString::styled(level.glyph(), color)
+}
+
+/// Returns symbol of the issue type, e.g. `●` for `Bug`.
+pub fn issue_type_symbol(
+    issue_type: Option<&IssueType>,
+    colors: &SemanticColors,
+) -> StyledString {
+    let Some(issue_type) = issue_type else {
+        return StyledString::plain(" ");
+    };
+    match issue_type.name.to_lowercase().as_str() {
+        "bug" => StyledString::styled("●", colors.issue_bug),
+        "epic" => StyledString::plain("★"),
+        "story" => StyledString::plain("◆"),
+        _ if issue_type.subtask => StyledString::plain("▪"),
+        _ => StyledString::plain("■"),
+    }
+}
+
+/// Returns row of the task in the tasks list.
+///
+/// Row has issue type, priority, name with highlighted chars,
+/// status and assignee, e.g. `■ ↑ TST-1 -- Fix login │ To Do │ Alice`.
+pub fn task_label(
+    task: &JiraIssue,
+    highlights: &[usize],
+    colors: &SemanticColors,
+) -> StyledString {
+    let mut label = issue_type_symbol(task.issue_type.as_ref(), colors);
+    label.append_plain(" ");
+    label.append(priority_glyph(task.priority.as_ref(), colors));
+    label.append_plain(" ");
+    label.append(highlight_chars(&task_name(task), highlights));
+    label.append_plain(" │ ");
+    label.append_styled(&task.status.name, status_style(&task.status, colors));
+    label.append_plain(" │ ");
+    match &task.assignee {
+        Some(assignee) => {
+            label.append_styled(&assignee.display_name, colors.assignee)
+        }
+        None => label.append_styled("Unassigned", Effect::Italic),
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_level() {
+        let priority = |id: &str, name: &str| IssuePriority {
+            id: id.into(),
+            name: name.into(),
+        };
+
+        assert_eq!(
+            PriorityLevel::from_priority(&priority("10", "Blocker")),
+            Some(PriorityLevel::Highest)
+        );
+        assert_eq!(
+            PriorityLevel::from_priority(&priority("4", "Later")),
+            Some(PriorityLevel::Low)
+        );
+        assert_eq!(
+            PriorityLevel::from_priority(&priority("10", "Later")),
+            None
+        );
+    }
+}
@@ -1,2 +1,6 @@
+pub mod changelog;
 pub mod data;
+pub mod indicators;
+pub mod ordering;
+pub mod search;
 pub mod views;
@@ -0,0 +1,248 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    data::JiraIssue,
+    search::{task_name, task_name_order},
+};
+
+/// Sort mode of the tasks list.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSort {
+    /// By key, numbers are compared as numbers.
+    #[default]
+    Key,
+    /// Recently updated tasks go first.
+    Updated,
+    /// The highest priority goes first.
+    Priority,
+    Status,
+    Assignee,
+}
+
+impl From<TaskSort> for &str {
+    fn from(sort: TaskSort) -> Self {
+        match sort {
+            TaskSort::Key => "key",
+            TaskSort::Updated => "updated",
+            TaskSort::Priority => "priority",
+            TaskSort::Status => "status",
+            TaskSort::Assignee => "assignee",
+        }
+    }
+}
+
+impl TaskSort {
+    /// Returns the next sort mode, the last one is followed by the first.
+    pub fn next(self) -> Self {
+        match self {
+            TaskSort::Key => TaskSort::Updated,
+            TaskSort::Updated => TaskSort::Priority,
+            TaskSort::Priority => TaskSort::Status,
+            TaskSort::Status => TaskSort::Assignee,
+            TaskSort::Assignee => TaskSort::Key,
+        }
+    }
+
+    /// Compares tasks, tasks with equal fields are ordered by key.
+    fn compare(self, first: &JiraIssue, second: &JiraIssue) -> Ordering {
+        let ordering = match self {
+            TaskSort::Key => Ordering::Equal,
+            TaskSort::Updated => second.updated.cmp(&first.updated),
+            TaskSort::Priority => {
+                priority_order(first).cmp(&priority_order(second))
+            }
+            TaskSort::Status => first
+                .status
+                .name
+                .to_lowercase()
+                .cmp(&second.status.name.to_lowercase()),
+            TaskSort::Assignee => {
+                assignee_order(first).cmp(&assignee_order(second))
+            }
+        };
+        ordering.then_with(|| {
+            task_name_order(&first.key).cmp(&task_name_order(&second.key))
+        })
+    }
+}
+
+/// Returns sort key of the priority, tasks without priority go last.
+fn priority_order(task: &JiraIssue) -> u64 {
+    task.priority
+        .as_ref()
+        .and_then(|priority| priority.id.parse::<u64>().ok())
+        .unwrap_or(u64::MAX)
+}
+
+/// Returns sort key of the assignee, unassigned tasks go last.
+fn assignee_order(task: &JiraIssue) -> (bool, String) {
+    match &task.assignee {
+        Some(assignee) => (false, assignee.display_name.to_lowercase()),
+        None => (true, String::default()),
+    }
+}
+
+/// Grouping of the tasks list.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskGroup {
+    #[default]
+    None,
+    Status,
+    Assignee,
+    Epic,
+    Sprint,
+}
+
+impl From<TaskGroup> for &str {
+    fn from(group: TaskGroup) -> Self {
+        match group {
+            TaskGroup::None => "none",
+            TaskGroup::Status => "status",
+            TaskGroup::Assignee => "assignee",
+            TaskGroup::Epic => "epic",
+            TaskGroup::Sprint => "sprint",
+        }
+    }
+}
+
+impl TaskGroup {
+    /// Returns the next grouping, the last one is followed by the first.
+    pub fn next(self) -> Self {
+        match self {
+            TaskGroup::None => TaskGroup::Status,
+            TaskGroup::Status => TaskGroup::Assignee,
+            TaskGroup::Assignee => TaskGroup::Epic,
+            TaskGroup::Epic => TaskGroup::Sprint,
+            TaskGroup::Sprint => TaskGroup::None,
+        }
+    }
+
+    /// Returns name of the group the task belongs to,
+    /// `None` means that the field is empty.
+    fn group_name<'a>(
+        self,
+        task: &'a JiraIssue,
+        fields: &TaskListFields<'_>,
+    ) -> Option<&'a str> {
+        match self {
+            TaskGroup::None => None,
+            TaskGroup::Status => Some(task.status.name.as_str()),
+            TaskGroup::Assignee => task
+                .assignee
+                .as_ref()
+                .map(|assignee| assignee.display_name.as_str()),
+            TaskGroup::Epic => task.epic_key(fields.epic_link_field),
+            TaskGroup::Sprint => task.sprint_name(fields.sprint_field),
+        }
+    }
+
+    /// Returns name of the group for tasks with the empty field.
+    fn empty_group_name(self) -> &'static str {
+        match self {
+            TaskGroup::None => "",
+            TaskGroup::Status => "No status",
+            TaskGroup::Assignee => "Unassigned",
+            TaskGroup::Epic => "No epic",
+            TaskGroup::Sprint => "No sprint",
+        }
+    }
+}
+
+/// Ids of the custom fields used for grouping.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TaskListFields<'a> {
+    pub epic_link_field: Option<&'a str>,
+    pub sprint_field: Option<&'a str>,
+}
+
+/// Item of the tasks list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskListItem {
+    /// Header of the group, e.g. `In Progress (2)`.
+    Group(String),
+    /// Task name, see `task_name`.
+    Task(String),
+}
+
+/// Sort mode and grouping of the tasks list.
+///
+/// It's remembered per project in the company state.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default,
+)]
+pub struct TaskListOrder {
+    #[serde(default)]
+    pub sort: TaskSort,
+    #[serde(default)]
+    pub group: TaskGroup,
+}
+
+impl TaskListOrder {
+    /// Returns text to show the order in the title of the list,
+    /// e.g. `priority, by status`.
+    pub fn display_text(&self) -> String {
+        let sort: &str = self.sort.into();
+        match self.group {
+            TaskGroup::None => sort.to_string(),
+            group => format!("{}, by {}", sort, <&str>::from(group)),
+        }
+    }
+
+    /// Sorts and groups tasks.
+    ///
+    /// Groups are ordered by name, the group with the empty field
+    /// goes last. Tasks in the group are sorted by the sort mode.
+    pub fn arrange<'a, I>(
+        &self,
+        tasks: I,
+        fields: &TaskListFields<'_>,
+    ) -> Vec<TaskListItem>
+    where
+        I: IntoIterator<Item = &'a JiraIssue>,
+    {
+        let mut tasks: Vec<&JiraIssue> = tasks.into_iter().collect();
+        tasks.sort_by(|first, second| self.sort.compare(first, second));
+
+        if self.group == TaskGroup::None {
+            return tasks
+                .into_iter()
+                .map(|task| TaskListItem::Task(task_name(task)))
+                .collect();
+        }
+
+        let mut groups: Vec<(Option<&str>, Vec<&JiraIssue>)> = Vec::new();
+        for task in tasks {
+            let group_name = self.group.group_name(task, fields);
+            match groups.iter_mut().find(|(name, _)| *name == group_name) {
+                Some((_, group_tasks)) => group_tasks.push(task),
+                None => groups.push((group_name, vec![task])),
+            }
+        }
+        groups.sort_by_key(|(group_name, _)| {
+            (group_name.is_none(), group_name.map(str::to_lowercase))
+        });
+
+        let mut items: Vec<TaskListItem> = Vec::new();
+        for (group_name, group_tasks) in groups {
+            items.push(TaskListItem::Group(format!(
+                "{} ({})",
+                group_name.unwrap_or(self.group.empty_group_name()),
+                group_tasks.len()
+            )));
+            items.extend(
+                group_tasks
+                    .into_iter()
+                    .map(|task| TaskListItem::Task(task_name(task))),
+            );
+        }
+        items
+    }
+}
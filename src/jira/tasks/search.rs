@@ -0,0 +1,254 @@
+use std::cmp::Ordering;
+
+use crate::jira::{
+    tasks_actions::data::JiraUser, utils::helpers::fuzzy_match_indices,
+};
+
+use super::data::JiraIssue;
+
+/// Separates key and summary in the tasks list.
+const TASK_NAME_SEPARATOR: &str = " -- ";
+
+/// Field of the task which can be used in the search query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskField {
+    Key,
+    Summary,
+    Assignee,
+    Status,
+    Label,
+}
+
+impl TaskField {
+    const ALL: [Self; 5] = [
+        Self::Key,
+        Self::Summary,
+        Self::Assignee,
+        Self::Status,
+        Self::Label,
+    ];
+
+    /// Returns field by its prefix in the query, e.g. `status`.
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix.to_lowercase().as_str() {
+            "key" => Some(Self::Key),
+            "summary" => Some(Self::Summary),
+            "assignee" => Some(Self::Assignee),
+            "status" => Some(Self::Status),
+            "label" | "labels" => Some(Self::Label),
+            _ => None,
+        }
+    }
+
+    /// Returns values of the field in the task.
+    fn values(self, task: &JiraIssue) -> Vec<&str> {
+        match self {
+            Self::Key => vec![task.key.as_str()],
+            Self::Summary => vec![task.summary.as_str()],
+            Self::Assignee => match &task.assignee {
+                Some(assignee) => {
+                    let mut values = vec![assignee.display_name.as_str()];
+                    values.extend(assignee.name.as_deref());
+                    values
+                }
+                None => vec!["unassigned"],
+            },
+            Self::Status => vec![task.status.name.as_str()],
+            Self::Label => task.labels.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// Task or project found by the search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Text shown in the list.
+    pub text: String,
+    /// Indices of the chars in `text` matched by the search.
+    pub highlights: Vec<usize>,
+    pub score: i64,
+}
+
+impl SearchMatch {
+    /// Sorts matches, the best ones go first.
+    ///
+    /// Matches with the same score are ordered by `order_fn`,
+    /// so the list doesn't jump while the user types.
+    pub fn sort<F, K>(matches: &mut [Self], order_fn: F)
+    where
+        F: Fn(&str) -> K,
+        K: Ord,
+    {
+        matches.sort_by(|first, second| {
+            match second.score.cmp(&first.score) {
+                Ordering::Equal => {
+                    order_fn(&first.text).cmp(&order_fn(&second.text))
+                }
+                ordering => ordering,
+            }
+        });
+    }
+}
+
+/// Returns text of the task in the tasks list.
+pub fn task_name(task: &JiraIssue) -> String {
+    format!("{}{}{}", task.key, TASK_NAME_SEPARATOR, task.summary)
+}
+
+/// Returns issue key from the task name, e.g. `TST-10`.
+pub fn task_key(task_name: &str) -> &str {
+    task_name
+        .split(TASK_NAME_SEPARATOR)
+        .next()
+        .unwrap_or_default()
+}
+
+/// Returns sort key of the task name, e.g. `TST` and `10` for `TST-10`.
+///
+/// Numbers are compared as numbers, so `TST-2` goes before `TST-10`.
+pub fn task_name_order(task_name: &str) -> (String, u64, String) {
+    let issue_key = task_key(task_name);
+    match issue_key.rsplit_once('-') {
+        Some((project_key, number)) => match number.parse::<u64>() {
+            Ok(number) => (project_key.into(), number, task_name.into()),
+            Err(_) => (issue_key.into(), 0, task_name.into()),
+        },
+        None => (issue_key.into(), 0, task_name.into()),
+    }
+}
+
+/// Search query for tasks, e.g. `status:progress assignee:me login`.
+///
+/// Words with field prefix must match the field,
+/// other words must match any field.
+/// Every word is matched fuzzily, case is ignored.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TaskQuery {
+    filters: Vec<(TaskField, String)>,
+    words: Vec<String>,
+}
+
+impl TaskQuery {
+    pub fn parse(raw_query: &str) -> Self {
+        let mut query = Self::default();
+        for word in raw_query.split_whitespace() {
+            let field_filter =
+                word.split_once(':').and_then(|(prefix, value)| {
+                    TaskField::from_prefix(prefix).map(|field| (field, value))
+                });
+            match field_filter {
+                Some((_, "")) => {}
+                Some((field, value)) => {
+                    query.filters.push((field, value.to_string()))
+                }
+                None => query.words.push(word.to_string()),
+            }
+        }
+        query
+    }
+
+    fn is_assignee_me(field: TaskField, value: &str) -> bool {
+        field == TaskField::Assignee && value.eq_ignore_ascii_case("me")
+    }
+
+    /// Matches the task, `None` means the task doesn't fit the query.
+    ///
+    /// Matched chars of the key and summary are highlighted.
+    pub fn match_task(
+        &self,
+        task: &JiraIssue,
+        current_user: Option<&JiraUser>,
+    ) -> Option<SearchMatch> {
+        let mut score: i64 = 0;
+        let mut highlights: Vec<usize> = Vec::new();
+
+        for (field, value) in self.filters.iter() {
+            if Self::is_assignee_me(*field, value) {
+                if !task.is_assigned_to(current_user?) {
+                    return None;
+                }
+                continue;
+            }
+            let (field_score, field_highlights) =
+                Self::match_field(task, *field, value)?;
+            score += field_score;
+            highlights.extend(field_highlights);
+        }
+
+        for word in self.words.iter() {
+            let (word_score, word_highlights) = TaskField::ALL
+                .into_iter()
+                .filter_map(|field| Self::match_field(task, field, word))
+                .max_by_key(|(word_score, _)| *word_score)?;
+            score += word_score;
+            highlights.extend(word_highlights);
+        }
+
+        highlights.sort_unstable();
+        highlights.dedup();
+        Some(SearchMatch {
+            text: task_name(task),
+            highlights,
+            score,
+        })
+    }
+
+    /// Returns the best score of the field values
+    /// and highlighted chars of the task name.
+    fn match_field(
+        task: &JiraIssue,
+        field: TaskField,
+        pattern: &str,
+    ) -> Option<(i64, Vec<usize>)> {
+        let (score, indices) = field
+            .values(task)
+            .into_iter()
+            .filter_map(|value| fuzzy_match_indices(pattern, value))
+            .max_by_key(|(score, _)| *score)?;
+        let highlights = match field {
+            TaskField::Key => indices,
+            TaskField::Summary => {
+                let summary_offset = task.key.chars().count()
+                    + TASK_NAME_SEPARATOR.chars().count();
+                indices
+                    .into_iter()
+                    .map(|idx| idx + summary_offset)
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        Some((score, highlights))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_task_query() {
+        let query = TaskQuery::parse("status:progress  Assignee:me login x:y");
+        assert_eq!(
+            query,
+            TaskQuery {
+                filters: vec![
+                    (TaskField::Status, "progress".into()),
+                    (TaskField::Assignee, "me".into()),
+                ],
+                words: vec!["login".into(), "x:y".into()],
+            }
+        );
+        assert_eq!(TaskQuery::parse("status: "), TaskQuery::default());
+    }
+
+    #[test]
+    fn test_task_name_order() {
+        let mut task_names =
+            vec!["TST-10 -- Later", "TST-2 -- Second", "EMP-1 -- Empty"];
+        task_names.sort_by_key(|task_name| task_name_order(task_name));
+        assert_eq!(
+            task_names,
+            vec!["EMP-1 -- Empty", "TST-2 -- Second", "TST-10 -- Later"]
+        );
+    }
+}
@@ -1,27 +1,39 @@
 use std::sync::{Arc, RwLock};
 
+use cursive::theme::Effect;
+use cursive::utils::markup::StyledString;
 use cursive::view::Resizable;
 
 use cursive::View;
 use cursive::{
     view::{Finder, Nameable, Scrollable, ViewWrapper},
     views::{
-        Dialog, DummyView, EditView, LinearLayout, NamedView, ScrollView,
-        SelectView, TextView, ViewRef,
+        Dialog, DialogFocus, DummyView, EditView, LinearLayout, NamedView,
+        ScrollView, SelectView, TextView, ViewRef,
     },
     Cursive,
 };
 
+use cursive_markup::MarkupView;
 use rusji_derive::ViewWrapper;
 
+use crate::jira::adf::render::AdfRenderer;
 use crate::jira::common::views::{
     ChangeJiraView, JiraViewWithName, JiraWithDialogView,
 };
 use crate::jira::constance::INNER_LEFT_TOP_VIEW_ALIGN;
+use crate::jira::input_mode::SearchInput;
 use crate::jira::utils::views::FailedAttemptView;
 use crate::jira_data::JiraData;
-
-use super::data::{IssueBaseInfo, IssueBaseInfoField, JiraIssue};
+use crate::theme::SemanticColors;
+
+use super::{
+    changelog::HistoryTab,
+    data::{IssueBaseInfo, JiraIssue},
+    indicators::{issue_type_symbol, priority_glyph, status_style},
+    ordering::TaskListItem,
+    search::task_key,
+};
 
 #[derive(ViewWrapper)]
 pub(crate) struct TasksView {
@@ -40,6 +52,63 @@ impl TasksView {
             .find_name(TasksSelectView::view_name().as_str())
             .unwrap()
     }
+
+    /// Returns text of the tasks search field.
+    pub(crate) fn get_search_text(cursive: &mut Cursive) -> String {
+        cursive
+            .call_on_name(
+                &Self::search_view_name(),
+                |search_input: &mut SearchInput| {
+                    search_input.with_view(|edit_view: &EditView| {
+                        edit_view.get_content().to_string()
+                    })
+                },
+            )
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Puts text into the tasks search field and shows matching tasks.
+    pub(crate) fn set_search_text(cursive: &mut Cursive, search_text: &str) {
+        cursive.call_on_name(
+            &Self::search_view_name(),
+            |search_input: &mut SearchInput| {
+                search_input.with_view_mut(|edit_view: &mut EditView| {
+                    edit_view.set_content(search_text)
+                })
+            },
+        );
+        TasksSearchView::on_edit_task_search(cursive, search_text);
+    }
+
+    /// Shows the tasks list again with the same search,
+    /// the selected task stays selected.
+    pub(crate) fn refresh_tasks_list(cursive: &mut Cursive) {
+        let selected_task = cursive
+            .call_on_name(
+                &TasksSelectView::view_name(),
+                |tasks_select_view: &mut TasksSelectView| {
+                    tasks_select_view.inner_view.selection()
+                },
+            )
+            .flatten();
+        let search_text = Self::get_search_text(cursive);
+        TasksSearchView::on_edit_task_search(cursive, &search_text);
+
+        let Some(selected_task) = selected_task else {
+            return;
+        };
+        // Name of the task has its summary, which could be changed.
+        let issue_key = task_key(&selected_task);
+        let mut tasks_select_view = TasksSelectView::get_view(cursive);
+        let row = tasks_select_view
+            .inner_view
+            .iter()
+            .position(|(_, task_name)| task_key(task_name) == issue_key);
+        if let Some(row) = row {
+            let _ = tasks_select_view.inner_view.set_selection(row);
+        }
+    }
 }
 
 impl Default for TasksView {
@@ -102,10 +171,13 @@ impl ChangeJiraView for TasksView {
 
         let jira_guard = jira_data_clone.read().unwrap();
 
-        match jira_guard.get_selected_project().unwrap().tasks_names() {
-            Some(tasks_names) => {
-                tasks_select_view.inner_view.clear();
-                tasks_select_view.inner_view.add_all_str(tasks_names);
+        match jira_guard.get_tasks_list() {
+            Some(tasks_list) => {
+                tasks_select_view.set_tasks_list(tasks_list, &jira_guard);
+                self.get_main_dialog().set_title(format!(
+                    "Choose issue ({})",
+                    jira_guard.get_task_list_order().display_text()
+                ));
                 cursive.focus_name(&TasksView::view_name()).unwrap();
             }
             None => cursive.add_layer(
@@ -119,11 +191,6 @@ impl ChangeJiraView for TasksView {
         }
         cursive.set_user_data(jira_data);
     }
-
-    /// Adds new content to SelectView from passed `content`.
-    fn add_content_to_view(&mut self, content: Vec<&str>) {
-        self.get_select_view().inner_view.add_all_str(content);
-    }
 }
 
 #[derive(ViewWrapper)]
@@ -162,6 +229,9 @@ impl Default for TasksSearchView {
                 Self::on_edit_task_search(cursive, task_subname);
             })
             .on_submit(Self::on_submit_task_search);
+        let task_search_view =
+            SearchInput::new(task_search_view, TasksSelectView::view_name())
+                .with_name(TasksView::search_view_name());
         Self {
             inner_view: Dialog::new()
                 .title("Search for tasks")
@@ -191,9 +261,9 @@ impl TasksSearchView {
                 format!("{}-{}", selected_project_key, issue_key).as_str(),
             );
 
-            if let Ok(mut task) = task {
-                task.add_transitions(jira_data_guard.client.clone());
+            if let Ok(task) = task {
                 jira_data_guard.add_new_task(task);
+                jira_data_guard.update_selected_task_transitions();
                 is_issue_exist = true;
             }
         };
@@ -208,35 +278,43 @@ impl TasksSearchView {
         }
     }
 
-    fn on_edit_task_search(cursive: &mut Cursive, task_subname: &str) {
-        let jira_data: Arc<RwLock<JiraData>> =
-            cursive.take_user_data().unwrap();
-        let jira_data_clone = jira_data.clone();
+    /// Shows tasks matching the search query.
+    ///
+    /// See `TaskQuery` for the query syntax.
+    /// Sorted and grouped list is shown for the empty query.
+    fn on_edit_task_search(cursive: &mut Cursive, raw_query: &str) {
+        let jira_data: Arc<RwLock<JiraData>> = cursive
+            .user_data::<Arc<RwLock<JiraData>>>()
+            .unwrap()
+            .clone();
 
-        let mut tasks_select_view: ViewRef<TasksSelectView> =
-            TasksSelectView::get_view(cursive);
+        if raw_query.trim().is_empty() {
+            let jira_data_guard = jira_data.read().unwrap();
+            if let Some(tasks_list) = jira_data_guard.get_tasks_list() {
+                TasksSelectView::get_view(cursive)
+                    .set_tasks_list(tasks_list, &jira_data_guard);
+            }
+            return;
+        }
 
-        let jira_data_guard = jira_data_clone.read().unwrap();
-        let fit_tasks = jira_data_guard.find_task_by_subname(
-            task_subname,
-            &jira_data_guard.selected_project,
-        );
+        let fit_tasks = {
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.find_task_by_subname(raw_query)
+        };
 
-        if fit_tasks.is_some() {
-            let unwrap_tasks = fit_tasks.as_ref().unwrap();
-            if unwrap_tasks.is_empty() {
-                tasks_select_view.inner_view.clear();
-            } else {
-                tasks_select_view.inner_view.clear();
-                for task in unwrap_tasks {
-                    tasks_select_view.inner_view.add_item_str(format!(
-                        "{} -- {}",
-                        task.key, task.summary
-                    ));
-                }
+        if let Some(fit_tasks) = fit_tasks {
+            let jira_data_guard = jira_data.read().unwrap();
+            let mut tasks_select_view: ViewRef<TasksSelectView> =
+                TasksSelectView::get_view(cursive);
+            tasks_select_view.inner_view.clear();
+            for fit_task in fit_tasks {
+                tasks_select_view.inner_view.add_item(
+                    jira_data_guard
+                        .get_task_label(&fit_task.text, &fit_task.highlights),
+                    fit_task.text,
+                );
             }
         };
-        cursive.set_user_data(jira_data);
     }
 }
 
@@ -269,7 +347,33 @@ impl Default for TasksSelectView {
 }
 
 impl TasksSelectView {
+    /// Replaces items with the tasks list.
+    ///
+    /// Group headers have empty values, so they can't be submitted.
+    pub fn set_tasks_list(
+        &mut self,
+        tasks_list: Vec<TaskListItem>,
+        jira_data: &JiraData,
+    ) {
+        self.inner_view.clear();
+        for item in tasks_list {
+            match item {
+                TaskListItem::Group(group_name) => self.inner_view.add_item(
+                    StyledString::styled(group_name, Effect::Bold),
+                    String::default(),
+                ),
+                TaskListItem::Task(task_name) => self.inner_view.add_item(
+                    jira_data.get_task_label(&task_name, &[]),
+                    task_name,
+                ),
+            }
+        }
+    }
+
     fn on_submit_tasks_select_view(cursive: &mut Cursive, task_name: &str) {
+        if task_name.is_empty() {
+            return;
+        }
         let jira_data: &mut Arc<RwLock<JiraData>> =
             cursive.user_data().unwrap();
 
@@ -277,12 +381,10 @@ impl TasksSelectView {
             let mut jira_data_guard = jira_data.write().unwrap();
 
             jira_data_guard.set_selected_task(task_name);
-            let client = jira_data_guard.client.clone();
-
-            let task = jira_data_guard.get_mut_selected_task();
-            task.add_transitions(client);
+            jira_data_guard.update_selected_task_transitions();
         }
         InfoView::get_view(cursive).show_info_on_select(cursive, task_name);
+        InfoView::mark_selected_issue_seen(cursive);
     }
 }
 
@@ -329,7 +431,8 @@ impl ChangeJiraView for InfoView {
     fn update_view_content(&mut self, cursive: &mut Cursive) {
         let jira_data: &mut Arc<RwLock<JiraData>> =
             cursive.user_data().unwrap();
-        let jira_data_guard = jira_data.write().unwrap();
+        let mut jira_data_guard = jira_data.write().unwrap();
+        jira_data_guard.add_recent_issue();
         let task = {
             let selected_task = &jira_data_guard.selected_task;
             jira_data_guard
@@ -337,52 +440,228 @@ impl ChangeJiraView for InfoView {
                 .unwrap()
                 .get_task(selected_task)
         };
-        let mut assignee_name: &str = "Unassigned";
-        if let Some(assignee) = &task.assignee {
-            assignee_name = assignee.name.as_str();
-        }
-        let issue_base_info = IssueBaseInfo::new(
-            IssueBaseInfoField::new("Summary", &task.summary),
-            IssueBaseInfoField::new("Description", &task.description),
-            IssueBaseInfoField::new("Issue", &task.key),
-            IssueBaseInfoField::new("Status", &task.status.name),
-            IssueBaseInfoField::new("Assignee", assignee_name),
+        let issue_base_info = IssueBaseInfo::from_issue(
+            task,
+            jira_data_guard.get_epic_link_field(),
         );
-        self.get_main_dialog()
-            .set_content(Self::make_inner_view(issue_base_info));
+        let inner_view = match &jira_data_guard.history_tab {
+            Some(history_tab) => Self::make_history_inner_view(
+                issue_base_info,
+                task,
+                history_tab,
+                &jira_data_guard.colors,
+            ),
+            None => {
+                Self::make_inner_view(issue_base_info, &jira_data_guard.colors)
+            }
+        };
+        let title = Self::title(&jira_data_guard);
+        let mut main_dialog = self.get_main_dialog();
+        main_dialog.set_title(title);
+        main_dialog.set_content(inner_view);
+        drop(jira_data_guard);
+        Self::mark_selected_issue_seen(cursive);
     }
 }
 
 impl InfoView {
     fn new(issue_base_info: IssueBaseInfo) -> Self {
         let dialog = Dialog::new()
-            .title("Task information")
-            .content(Self::make_inner_view(issue_base_info))
+            .title(Self::default_title())
+            .content(Self::make_inner_view(
+                issue_base_info,
+                &SemanticColors::default(),
+            ))
             .with_name(Self::main_dialog_name());
 
         Self { inner_view: dialog }
     }
 
-    fn make_inner_view(issue_base_info: IssueBaseInfo) -> LinearLayout {
+    fn default_title() -> String {
+        "Task information".into()
+    }
+
+    /// Returns title of the view, bookmarked task is marked with `★`.
+    fn title(jira_data: &JiraData) -> String {
+        if jira_data
+            .company_state
+            .is_bookmarked(&jira_data.selected_task)
+        {
+            format!("{} ★", Self::default_title())
+        } else {
+            Self::default_title()
+        }
+    }
+
+    fn make_inner_view(
+        issue_base_info: IssueBaseInfo,
+        colors: &SemanticColors,
+    ) -> LinearLayout {
         let top_inner_view_layout = LinearLayout::horizontal()
             .child(
                 InfoView::make_summary_dialog(&issue_base_info).full_width(),
             )
-            .child(InfoView::make_issue_main_info_dialog(&issue_base_info));
+            .child(InfoView::make_issue_main_info_dialog(
+                &issue_base_info,
+                colors,
+            ));
+
+        let mut inner_view_layout = LinearLayout::vertical()
+            .child(top_inner_view_layout)
+            .child(DummyView);
+        if !issue_base_info.relations.is_empty() {
+            inner_view_layout
+                .add_child(InfoView::make_relations_dialog(&issue_base_info));
+            inner_view_layout.add_child(DummyView);
+        }
+        if !issue_base_info.attachments.is_empty() {
+            inner_view_layout.add_child(InfoView::make_attachments_dialog(
+                &issue_base_info,
+            ));
+            inner_view_layout.add_child(DummyView);
+        }
+        inner_view_layout
+            .child(InfoView::make_description_dialog(issue_base_info))
+    }
+
+    /// Makes view with the history tab instead of the task details.
+    fn make_history_inner_view(
+        issue_base_info: IssueBaseInfo,
+        task: &JiraIssue,
+        history_tab: &HistoryTab,
+        colors: &SemanticColors,
+    ) -> LinearLayout {
+        let top_inner_view_layout = LinearLayout::horizontal()
+            .child(
+                InfoView::make_summary_dialog(&issue_base_info).full_width(),
+            )
+            .child(InfoView::make_issue_main_info_dialog(
+                &issue_base_info,
+                colors,
+            ));
 
         LinearLayout::vertical()
             .child(top_inner_view_layout)
             .child(DummyView)
-            .child(InfoView::make_description_dialog(issue_base_info))
+            .child(
+                InfoView::make_history_dialog(task, history_tab)
+                    .with_name(Self::history_dialog_name()),
+            )
     }
 
-    fn make_summary_dialog(issue_base_info: &IssueBaseInfo) -> Dialog {
-        let title: String;
-        if issue_base_info.task_key.inner_value.is_empty() {
-            title = "No task selected".into();
+    /// Makes dialog with one page of the filtered issue changelog.
+    fn make_history_dialog(
+        task: &JiraIssue,
+        history_tab: &HistoryTab,
+    ) -> Dialog {
+        let pages_count = task
+            .changelog
+            .as_ref()
+            .map_or(1, |changelog| changelog.pages_count(history_tab.filter));
+        let page = history_tab.page.min(pages_count - 1);
+        let page_entries = task
+            .changelog
+            .as_ref()
+            .map(|changelog| changelog.entries(history_tab.filter, page))
+            .unwrap_or_default();
+        let history_text = if page_entries.is_empty() {
+            "No changes".to_string()
         } else {
-            title = "Issue title".into()
+            page_entries.join("\n")
+        };
+        let filter_name: &str = history_tab.filter.into();
+
+        Dialog::new()
+            .title(format!("History, page {} of {}", page + 1, pages_count))
+            .padding_lrtb(1, 1, 1, 1)
+            .content(ScrollView::new(TextView::new(history_text)))
+            .button(format!("Filter: {}", filter_name), |cursive| {
+                Self::change_history_tab(cursive, 0, |history_tab| {
+                    history_tab.filter = history_tab.filter.next();
+                    history_tab.page = 0;
+                })
+            })
+            .button("Previous page", move |cursive| {
+                Self::change_history_tab(cursive, 1, |history_tab| {
+                    history_tab.page = page.saturating_sub(1);
+                })
+            })
+            .button("Next page", move |cursive| {
+                Self::change_history_tab(cursive, 2, |history_tab| {
+                    history_tab.page = (page + 1).min(pages_count - 1);
+                })
+            })
+    }
+
+    /// Returns name of the dialog with the issue history.
+    fn history_dialog_name() -> String {
+        "history_task_view".into()
+    }
+
+    /// Changes state of the history tab and shows it.
+    ///
+    /// Focus is returned to the pressed button of the history dialog.
+    fn change_history_tab<F>(
+        cursive: &mut Cursive,
+        button_idx: usize,
+        change_fn: F,
+    ) where
+        F: FnOnce(&mut HistoryTab),
+    {
+        {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let mut jira_data_guard = jira_data.write().unwrap();
+            jira_data_guard.change_history_tab(change_fn);
         }
+        Self::get_view(cursive).update_view_content(cursive);
+        // The dialog has just been added, so it can be focused.
+        let _ = cursive.focus_name(&Self::history_dialog_name());
+        cursive.call_on_name(
+            &Self::history_dialog_name(),
+            |history_dialog: &mut Dialog| {
+                history_dialog.set_focus(DialogFocus::Button(button_idx))
+            },
+        );
+    }
+
+    /// Makes dialog with parent, epic, subtasks and linked issues.
+    ///
+    /// Submit on the relation opens the related issue.
+    fn make_relations_dialog(issue_base_info: &IssueBaseInfo) -> Dialog {
+        let relations_select_view = SelectView::<String>::new()
+            .align(INNER_LEFT_TOP_VIEW_ALIGN)
+            .with_all(issue_base_info.relations.iter().map(|relation| {
+                (relation.display_text(), relation.key.clone())
+            }))
+            .on_submit(|cursive: &mut Cursive, issue_key: &str| {
+                Self::open_issue(cursive, issue_key)
+            });
+        Dialog::new()
+            .title("Links")
+            .content(relations_select_view.with_name("relations_task_view"))
+    }
+
+    /// Makes dialog with files attached to the issue.
+    fn make_attachments_dialog(issue_base_info: &IssueBaseInfo) -> Dialog {
+        let attachments_text = issue_base_info
+            .attachments
+            .iter()
+            .map(|attachment| attachment.display_text())
+            .collect::<Vec<String>>()
+            .join("\n");
+        Dialog::new().title("Attachments").content(
+            TextView::new(attachments_text).with_name("attachments_task_view"),
+        )
+    }
+
+    fn make_summary_dialog(issue_base_info: &IssueBaseInfo) -> Dialog {
+        let title: String = if issue_base_info.task_key.inner_value.is_empty()
+        {
+            "No task selected".into()
+        } else {
+            "Issue title".into()
+        };
         Dialog::new().title(title).content(
             cursive_markup::MarkupView::html(
                 issue_base_info.summary.inner_value,
@@ -391,42 +670,116 @@ impl InfoView {
         )
     }
 
-    fn make_issue_main_info_dialog(issue_base_info: &IssueBaseInfo) -> Dialog {
+    /// Makes dialog with key, type, priority, status and assignee.
+    ///
+    /// Status is colored by its category, priority has its glyph.
+    fn make_issue_main_info_dialog(
+        issue_base_info: &IssueBaseInfo,
+        colors: &SemanticColors,
+    ) -> Dialog {
         let main_info_dialog = Dialog::new().title("Main issue information");
         if issue_base_info.task_key.inner_value.is_empty() {
-            main_info_dialog
-        } else {
-            let main_info_inner_layout = LinearLayout::vertical()
-                .child(TextView::new(format!(
-                    "{} - {}",
-                    issue_base_info.task_key.display_name,
-                    issue_base_info.task_key.inner_value
-                )))
-                .child(TextView::new(format!(
-                    "{} - {}",
-                    issue_base_info.task_status_name.display_name,
-                    issue_base_info.task_status_name.inner_value
-                )))
-                .child(TextView::new(format!(
-                    "{} - {}",
-                    issue_base_info.issue_assignee.display_name,
-                    issue_base_info.issue_assignee.inner_value,
-                )));
-
-            main_info_dialog.content(main_info_inner_layout)
+            return main_info_dialog;
+        }
+
+        let mut main_info_inner_layout =
+            LinearLayout::vertical().child(TextView::new(format!(
+                "{} - {}",
+                issue_base_info.task_key.display_name,
+                issue_base_info.task_key.inner_value
+            )));
+        if let Some(issue_type) = issue_base_info.issue_type {
+            let mut issue_type_text = StyledString::plain("Type - ");
+            issue_type_text
+                .append(issue_type_symbol(Some(issue_type), colors));
+            issue_type_text.append_plain(format!(" {}", issue_type.name));
+            main_info_inner_layout.add_child(TextView::new(issue_type_text));
         }
+        if let Some(priority) = issue_base_info.priority {
+            let mut priority_text = StyledString::plain("Priority - ");
+            priority_text.append(priority_glyph(Some(priority), colors));
+            priority_text.append_plain(format!(" {}", priority.name));
+            main_info_inner_layout.add_child(TextView::new(priority_text));
+        }
+        let mut status_text = StyledString::plain(format!(
+            "{} - ",
+            issue_base_info.task_status_name.display_name
+        ));
+        match issue_base_info.status {
+            Some(status) => status_text.append_styled(
+                issue_base_info.task_status_name.inner_value,
+                status_style(status, colors),
+            ),
+            None => status_text
+                .append_plain(issue_base_info.task_status_name.inner_value),
+        }
+        main_info_inner_layout.add_child(TextView::new(status_text));
+        main_info_inner_layout.add_child(TextView::new(format!(
+            "{} - {}",
+            issue_base_info.issue_assignee.display_name,
+            issue_base_info.issue_assignee.inner_value,
+        )));
+
+        main_info_dialog.content(main_info_inner_layout)
     }
 
+    /// Makes dialog with issue description.
+    ///
+    /// Description is rendered from ADF, see
+    /// `JiraIssue::description_document`.
+    /// Links to issues can be selected to open the issue.
     fn make_description_dialog(issue_base_info: IssueBaseInfo) -> Dialog {
-        Dialog::new()
-            .title("Description")
-            .padding_lrtb(1, 1, 1, 1)
-            .content(ScrollView::new(
-                cursive_markup::MarkupView::html(
-                    issue_base_info.description.inner_value,
-                )
-                .with_name("description_task_view"),
-            ))
+        let dialog =
+            Dialog::new().title("Description").padding_lrtb(1, 1, 1, 1);
+        let mut description_view = MarkupView::with_renderer(
+            AdfRenderer::new(issue_base_info.description),
+        );
+        description_view.on_link_select(Self::open_issue);
+        dialog.content(
+            ScrollView::new(
+                description_view.with_name("description_task_view"),
+            )
+            .with_name(Self::description_scroll_view_name()),
+        )
+    }
+
+    /// Returns name of the ScrollView with issue description.
+    fn description_scroll_view_name() -> String {
+        "description_scroll_view".into()
+    }
+
+    /// Scrolls description by half of its height.
+    pub(crate) fn scroll_description(cursive: &mut Cursive, is_down: bool) {
+        cursive.call_on_name(
+            &Self::description_scroll_view_name(),
+            |scroll_view: &mut ScrollView<
+                NamedView<MarkupView<AdfRenderer>>,
+            >| {
+                let viewport = scroll_view.content_viewport();
+                let step = (viewport.height() / 2).max(1);
+                let offset = viewport.top_left();
+                let offset_y = if is_down {
+                    offset.y + step
+                } else {
+                    offset.y.saturating_sub(step)
+                };
+                scroll_view.set_offset((offset.x, offset_y));
+            },
+        );
+    }
+
+    /// Removes the change mark of the shown issue from the tasks list.
+    fn mark_selected_issue_seen(cursive: &mut Cursive) {
+        let is_marked = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let mut jira_data_guard = jira_data.write().unwrap();
+            let issue_key = jira_data_guard.selected_task.clone();
+            jira_data_guard.mark_issue_seen(&issue_key)
+        };
+        if is_marked {
+            TasksView::refresh_tasks_list(cursive);
+        }
     }
 
     /// Shows task information in InfoView.
@@ -435,24 +788,62 @@ impl InfoView {
             cursive.user_data().unwrap();
         let task_key: Vec<&str> = task_name.split(" -- ").collect();
 
-        let jira_data_guard = jira_data.read().unwrap();
+        let mut jira_data_guard = jira_data.write().unwrap();
+        jira_data_guard.add_recent_issue();
         let task = jira_data_guard
             .get_project(jira_data_guard.selected_project.clone().as_str())
             .unwrap()
             .get_task(task_key[0]);
 
-        let mut assignee_name: &str = "Unassigned";
-        if let Some(assignee) = &task.assignee {
-            assignee_name = assignee.name.as_str();
-        }
-        let issue_base_info = IssueBaseInfo::new(
-            IssueBaseInfoField::new("Summary", &task.summary),
-            IssueBaseInfoField::new("Description", &task.description),
-            IssueBaseInfoField::new("Issue", &task.key),
-            IssueBaseInfoField::new("Status", &task.status.name),
-            IssueBaseInfoField::new("Assignee", assignee_name),
+        let issue_base_info = IssueBaseInfo::from_issue(
+            task,
+            jira_data_guard.get_epic_link_field(),
         );
-        self.get_main_dialog()
-            .set_content(Self::make_inner_view(issue_base_info));
+        let inner_view =
+            Self::make_inner_view(issue_base_info, &jira_data_guard.colors);
+        let title = Self::title(&jira_data_guard);
+        let mut main_dialog = self.get_main_dialog();
+        main_dialog.set_title(title);
+        main_dialog.set_content(inner_view);
+    }
+
+    /// Shows issue by its key, it can be from another project.
+    pub(crate) fn open_issue(cursive: &mut Cursive, issue_key: &str) {
+        let open_result = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let mut jira_data_guard = jira_data.write().unwrap();
+            jira_data_guard.open_issue(issue_key)
+        };
+
+        match open_result {
+            Ok(is_project_changed) => {
+                if is_project_changed {
+                    TasksView::get_view(cursive).update_view_content(cursive);
+                }
+                Self::get_view(cursive).update_view_content(cursive);
+            }
+            Err(_) => cursive.add_layer(FailedAttemptView::new(
+                format!("Can't open issue: {}", issue_key).as_str(),
+            )),
+        }
+    }
+
+    /// Reloads the selected issue after it's changed and shows it.
+    ///
+    /// The issue loaded before is shown if it can't be reloaded.
+    pub(crate) fn reload_selected_issue(cursive: &mut Cursive) {
+        let reload_result = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let mut jira_data_guard = jira_data.write().unwrap();
+            jira_data_guard.update_selected_issue()
+        };
+        Self::get_view(cursive).update_view_content(cursive);
+        if reload_result.is_err() {
+            cursive.add_layer(FailedAttemptView::new(
+                "The task is changed, but can't be reloaded. Try again",
+            ));
+        }
     }
 }
@@ -0,0 +1,114 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, ViewWrapper},
+    views::{Dialog, NamedView, ResizedView, TextArea},
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        adf::convert::text_to_request_body,
+        common::views::{ButtonView, JiraViewWithName, ToggleableView},
+        utils::{helpers::calculate_view_size, views::FailedAttemptView},
+    },
+    jira_data::JiraData,
+};
+
+/// Main view for adding new comment to the issue.
+#[derive(ViewWrapper)]
+pub struct AddCommentView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for AddCommentView {}
+
+impl ButtonView for AddCommentView {
+    fn inner_view(self) -> NamedView<ResizedView<Dialog>> {
+        self.inner_view
+    }
+}
+
+impl JiraViewWithName for AddCommentView {
+    /// Returns name of the `AddCommentView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "AddCommentView".into()
+    }
+
+    /// Returns instance of `AddCommentView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl AddCommentView {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let add_comment_view = Dialog::new()
+            .title("New comment")
+            .content(
+                TextArea::new()
+                    .with_name(Self::text_area_name())
+                    .full_screen(),
+            )
+            .button("Send", Self::on_send_comment)
+            .fixed_size(calculate_view_size(cursive, 4, 7))
+            .with_name(Self::view_name());
+        Self {
+            inner_view: add_comment_view,
+        }
+    }
+
+    /// Returns name of the TextArea with comment.
+    fn text_area_name() -> String {
+        "AddCommentTextArea".into()
+    }
+
+    fn on_send_comment(cursive: &mut Cursive) {
+        let comment = cursive
+            .call_on_name(&Self::text_area_name(), |view: &mut TextArea| {
+                view.get_content().to_string()
+            })
+            .unwrap();
+        if comment.trim().is_empty() {
+            return;
+        }
+
+        let (request_client, issue_key) = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            let request_client = jira_data_guard.client.clone();
+            let selected_issue_key =
+                jira_data_guard.get_selected_task().key.clone();
+
+            (request_client, selected_issue_key)
+        };
+
+        let request_result = {
+            let request_client_guard = request_client.read().unwrap();
+            request_client_guard.add_issue_comment(
+                text_to_request_body(
+                    &comment,
+                    request_client_guard.is_adf_api(),
+                ),
+                issue_key.as_str(),
+            )
+        };
+
+        if request_result.is_err() {
+            cursive.add_layer(FailedAttemptView::new(
+                "Can't add comment for some reason. Try again",
+            ));
+            return;
+        }
+
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+    }
+}
@@ -0,0 +1,193 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, Scrollable, ViewWrapper},
+    views::{
+        Dialog, EditView, LinearLayout, NamedView, ResizedView, SelectView,
+    },
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::views::{ButtonView, JiraViewWithName, ToggleableView},
+        constance::INNER_LEFT_TOP_VIEW_ALIGN,
+        tasks::views::InfoView,
+        utils::{helpers::calculate_view_size, views::FailedAttemptView},
+    },
+    jira_data::JiraData,
+};
+
+use super::data::IssueLinkTypes;
+
+/// One side of the issue link type.
+///
+/// `Blocks` type has two directions: `blocks` and `is blocked by`.
+#[derive(Clone)]
+struct LinkDirection {
+    link_type_name: String,
+    /// True if the selected issue is described
+    /// with outward description of the type.
+    is_outward: bool,
+}
+
+/// Main view for linking the selected issue with another one.
+#[derive(ViewWrapper)]
+pub struct AddLinkView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for AddLinkView {}
+
+impl ButtonView for AddLinkView {
+    fn inner_view(self) -> NamedView<ResizedView<Dialog>> {
+        self.inner_view
+    }
+}
+
+impl JiraViewWithName for AddLinkView {
+    /// Returns name of the `AddLinkView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "AddLinkView".into()
+    }
+
+    /// Returns instance of `AddLinkView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl AddLinkView {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let issue_key_view = EditView::new()
+            .on_submit(|cursive: &mut Cursive, _: &str| {
+                // Focus can't be lost, the select view is in the same layout.
+                let _ = cursive.focus_name(&Self::select_view_name());
+            })
+            .with_name(Self::edit_view_name());
+        let link_directions_view = SelectView::<LinkDirection>::new()
+            .align(INNER_LEFT_TOP_VIEW_ALIGN)
+            .with_all(Self::get_link_directions(cursive))
+            .on_submit(Self::on_submit_link_direction)
+            .with_name(Self::select_view_name());
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Enter issue key and choose link type")
+                .content(
+                    LinearLayout::vertical()
+                        .child(
+                            Dialog::new()
+                                .title("Issue")
+                                .content(issue_key_view),
+                        )
+                        .child(link_directions_view.scrollable()),
+                )
+                .fixed_size(calculate_view_size(cursive, 3, 7))
+                .with_name(Self::view_name()),
+        }
+    }
+
+    /// Returns name of the EditView with issue key.
+    fn edit_view_name() -> String {
+        "AddLinkEditView".into()
+    }
+
+    /// Returns name of the SelectView with link types.
+    fn select_view_name() -> String {
+        "AddLinkSelectView".into()
+    }
+
+    /// Returns both directions of all link types configured in Jira.
+    fn get_link_directions(
+        cursive: &mut Cursive,
+    ) -> Vec<(String, LinkDirection)> {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let jira_data_guard = jira_data.read().unwrap();
+        let response = jira_data_guard
+            .client
+            .read()
+            .unwrap()
+            .get_issue_link_types();
+        let link_types = response
+            .ok()
+            .and_then(|response| response.parse::<IssueLinkTypes>().ok());
+
+        let mut link_directions: Vec<(String, LinkDirection)> = Vec::new();
+        for link_type in link_types.into_iter().flatten() {
+            link_directions.push((
+                link_type.outward.clone(),
+                LinkDirection {
+                    link_type_name: link_type.name.clone(),
+                    is_outward: true,
+                },
+            ));
+            if link_type.inward != link_type.outward {
+                link_directions.push((
+                    link_type.inward,
+                    LinkDirection {
+                        link_type_name: link_type.name,
+                        is_outward: false,
+                    },
+                ));
+            }
+        }
+        link_directions
+    }
+
+    fn on_submit_link_direction(
+        cursive: &mut Cursive,
+        link_direction: &LinkDirection,
+    ) {
+        let linked_issue_key = cursive
+            .call_on_name(&Self::edit_view_name(), |view: &mut EditView| {
+                view.get_content().trim().to_uppercase()
+            })
+            .unwrap();
+        if linked_issue_key.is_empty() {
+            return;
+        }
+
+        let (request_client, issue_key) = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            let request_client = jira_data_guard.client.clone();
+            let selected_issue_key =
+                jira_data_guard.get_selected_task().key.clone();
+
+            (request_client, selected_issue_key)
+        };
+
+        let (inward_issue_key, outward_issue_key) =
+            if link_direction.is_outward {
+                (issue_key.as_str(), linked_issue_key.as_str())
+            } else {
+                (linked_issue_key.as_str(), issue_key.as_str())
+            };
+        let request_result = request_client.read().unwrap().create_issue_link(
+            &link_direction.link_type_name,
+            inward_issue_key,
+            outward_issue_key,
+        );
+
+        if request_result.is_err() {
+            cursive.add_layer(FailedAttemptView::new(
+                format!("Can't link the issue with {}", linked_issue_key)
+                    .as_str(),
+            ));
+            return;
+        }
+
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        InfoView::reload_selected_issue(cursive);
+    }
+}
@@ -1,6 +1,12 @@
+use std::sync::{Arc, RwLock};
+
 use cursive::Cursive;
 
-use crate::jira::common::button::{CallbackWithButton, ClickableCallback};
+use crate::{
+    jira::common::button::{CallbackWithButton, ClickableCallback},
+    jira_data::JiraData,
+    keymap::{KeyAction, KeyChord, Keymap},
+};
 
 use super::enums::TaskActions;
 
@@ -13,12 +19,10 @@ pub struct TasksActionsButtons<'a> {
 impl<'a> TasksActionsButtons<'a> {
     /// Add new button to the structure.
     ///
-    /// TODO: Make possible to configure event param
-    /// from configuration file.
+    /// `event` is the key from the keymap.
     pub fn add_button(
         &mut self,
-        _: &mut Cursive,
-        event: char,
+        event: KeyChord,
         name: &'a str,
         action_fn: fn(&mut Cursive),
         variant: TaskActions,
@@ -29,12 +33,19 @@ impl<'a> TasksActionsButtons<'a> {
 }
 
 /// Build all tasks actions buttons.
+///
+/// Keys are taken from the keymap in `JiraData`.
 pub fn build_buttons<'a>(cursive: &mut Cursive) -> TasksActionsButtons<'a> {
+    let keymap: Keymap = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let jira_data_guard = jira_data.read().unwrap();
+        jira_data_guard.keymap.clone()
+    };
     let mut buttons = TasksActionsButtons::default();
 
     buttons.add_button(
-        cursive,
-        'c',
+        keymap.key(KeyAction::ChangeStatus),
         "Change issue status",
         |cursive: &mut Cursive| {
             let action_view = TaskActions::StatusChange.get_view(cursive);
@@ -44,8 +55,7 @@ pub fn build_buttons<'a>(cursive: &mut Cursive) -> TasksActionsButtons<'a> {
     );
 
     buttons.add_button(
-        cursive,
-        'e',
+        keymap.key(KeyAction::ChangeAssignee),
         "Change issue assignee",
         |cursive: &mut Cursive| {
             let action_view = TaskActions::ChangeAssignee.get_view(cursive);
@@ -55,8 +65,7 @@ pub fn build_buttons<'a>(cursive: &mut Cursive) -> TasksActionsButtons<'a> {
     );
 
     buttons.add_button(
-        cursive,
-        'r',
+        keymap.key(KeyAction::ChangeStoryPoints),
         "Change issue release",
         |cursive: &mut Cursive| {
             let action_view = TaskActions::ChangeStoryPoints.get_view(cursive);
@@ -65,5 +74,86 @@ pub fn build_buttons<'a>(cursive: &mut Cursive) -> TasksActionsButtons<'a> {
         TaskActions::ChangeStoryPoints,
     );
 
+    buttons.add_button(
+        keymap.key(KeyAction::ChangeDescription),
+        "Change issue description",
+        |cursive: &mut Cursive| {
+            let action_view = TaskActions::ChangeDescription.get_view(cursive);
+            cursive.add_layer(action_view);
+        },
+        TaskActions::ChangeDescription,
+    );
+
+    buttons.add_button(
+        keymap.key(KeyAction::AddComment),
+        "Add comment to the issue",
+        |cursive: &mut Cursive| {
+            let action_view = TaskActions::AddComment.get_view(cursive);
+            cursive.add_layer(action_view);
+        },
+        TaskActions::AddComment,
+    );
+
+    buttons.add_button(
+        keymap.key(KeyAction::AddLink),
+        "Link the issue with another one",
+        |cursive: &mut Cursive| {
+            let action_view = TaskActions::AddLink.get_view(cursive);
+            cursive.add_layer(action_view);
+        },
+        TaskActions::AddLink,
+    );
+
+    buttons.add_button(
+        keymap.key(KeyAction::RemoveLink),
+        "Remove link of the issue",
+        |cursive: &mut Cursive| {
+            let action_view = TaskActions::RemoveLink.get_view(cursive);
+            cursive.add_layer(action_view);
+        },
+        TaskActions::RemoveLink,
+    );
+
+    buttons.add_button(
+        keymap.key(KeyAction::DownloadAttachments),
+        "Download attachments of the issue",
+        |cursive: &mut Cursive| {
+            let action_view =
+                TaskActions::DownloadAttachments.get_view(cursive);
+            cursive.add_layer(action_view);
+        },
+        TaskActions::DownloadAttachments,
+    );
+
+    buttons.add_button(
+        keymap.key(KeyAction::UploadAttachment),
+        "Attach file to the issue",
+        |cursive: &mut Cursive| {
+            let action_view = TaskActions::UploadAttachment.get_view(cursive);
+            cursive.add_layer(action_view);
+        },
+        TaskActions::UploadAttachment,
+    );
+
+    buttons.add_button(
+        keymap.key(KeyAction::ShareLink),
+        "Open the issue in browser or copy its link",
+        |cursive: &mut Cursive| {
+            let action_view = TaskActions::ShareLink.get_view(cursive);
+            cursive.add_layer(action_view);
+        },
+        TaskActions::ShareLink,
+    );
+
+    buttons.add_button(
+        keymap.key(KeyAction::CreateBranch),
+        "Create git branch of the issue",
+        |cursive: &mut Cursive| {
+            let action_view = TaskActions::CreateBranch.get_view(cursive);
+            cursive.add_layer(action_view);
+        },
+        TaskActions::CreateBranch,
+    );
+
     buttons
 }
@@ -1,10 +1,11 @@
 use std::sync::{Arc, RwLock};
 
 use cursive::{
+    event::Event,
     view::{Finder, Nameable, Resizable, ViewWrapper},
     views::{
-        Dialog, EditView, LinearLayout, NamedView, ResizedView, SelectView,
-        ViewRef,
+        Dialog, EditView, LinearLayout, NamedView, OnEventView, ResizedView,
+        SelectView, ViewRef,
     },
     Cursive, View,
 };
@@ -12,14 +13,19 @@ use rusji_derive::ViewWrapper;
 
 use crate::{
     jira::{
-        common::views::{
-            ButtonView, ChangeJiraView, JiraViewWithName, JiraWithDialogView,
-            ToggleableView,
+        common::{
+            button::{CallbackSection, ShowOnlyCallback, StaticCallback},
+            views::{
+                ButtonView, ChangeJiraView, JiraViewWithName,
+                JiraWithDialogView, ToggleableView,
+            },
         },
         tasks::views::InfoView,
-        utils::helpers::calculate_view_size,
+        utils::{helpers::calculate_view_size, views::FailedAttemptView},
     },
     jira_data::JiraData,
+    keymap::{KeyAction, KeyChord, Keymap},
+    state::RecentAssignee,
 };
 
 use super::data::JiraUsers;
@@ -68,14 +74,164 @@ impl ButtonView for ChangeAssigneeView {
 impl ChangeAssigneeView {
     pub fn new(cursive: &mut Cursive) -> Self {
         Self::toggle_on_view(cursive);
+        let assignable_users = Self::get_assignable_users(cursive);
+        let keymap: Keymap = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.keymap.clone()
+        };
+        let assign_to_me_key = keymap.key(KeyAction::AssignToMe);
+        let unassign_key = keymap.key(KeyAction::Unassign);
+        let mut inner_layout = OnEventView::new(
+            ChangeAssigneeInnerLayout::new(assignable_users).inner_layout,
+        );
+        for callback in Self::callbacks(&keymap) {
+            inner_layout = inner_layout
+                .on_event(Event::from(callback.event), callback.action_fn);
+        }
+
         Self {
             inner_view: Dialog::new()
                 .title("Assignee search, enter username and press <enter>")
-                .content(ChangeAssigneeInnerLayout::new().inner_layout)
+                .content(inner_layout)
+                .button(
+                    format!("Assign to me <{assign_to_me_key}>"),
+                    Self::assign_to_me,
+                )
+                .button(format!("Unassign <{unassign_key}>"), Self::unassign)
                 .fixed_size(calculate_view_size(cursive, 3, 7))
                 .with_name(Self::main_dialog_name()),
         }
     }
+
+    /// Returns callbacks of the dialog keys, they're listed in the help.
+    pub fn callbacks(keymap: &Keymap) -> Vec<StaticCallback<'_, KeyChord>> {
+        vec![
+            StaticCallback::new(
+                keymap.key(KeyAction::AssignToMe),
+                "assign issue to me",
+                Self::assign_to_me,
+            )
+            .in_section(CallbackSection::ActionDialogs),
+            StaticCallback::new(
+                keymap.key(KeyAction::Unassign),
+                "unassign issue",
+                Self::unassign,
+            )
+            .in_section(CallbackSection::ActionDialogs),
+        ]
+    }
+
+    /// Returns recently used assignees and assignable users
+    /// of the selected project.
+    fn get_assignable_users(
+        cursive: &mut Cursive,
+    ) -> Vec<(String, RecentAssignee)> {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let jira_data_guard = jira_data.read().unwrap();
+
+        let response =
+            jira_data_guard.client.read().unwrap().get_assignable_users(
+                &jira_data_guard.get_selected_project_key(),
+            );
+        let users = response
+            .ok()
+            .and_then(|response| response.parse::<JiraUsers>().ok());
+
+        make_select_items(&jira_data_guard, users)
+    }
+
+    /// Assigns the selected issue to the current user.
+    fn assign_to_me(cursive: &mut Cursive) {
+        let current_user = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.get_current_user().map(RecentAssignee::from)
+        };
+
+        match current_user {
+            Some(current_user) => assign_issue(cursive, Some(current_user)),
+            None => cursive.add_layer(FailedAttemptView::new(
+                "Can't get the current user for some reason. Try again",
+            )),
+        }
+    }
+
+    /// Removes assignee from the selected issue.
+    fn unassign(cursive: &mut Cursive) {
+        assign_issue(cursive, None);
+    }
+}
+
+/// Builds items for `ChangeAssigneeSelectView`.
+///
+/// Recently used assignees are shown before found users.
+fn make_select_items(
+    jira_data: &JiraData,
+    users: Option<JiraUsers>,
+) -> Vec<(String, RecentAssignee)> {
+    let recent_assignees = jira_data.company_state.recent_assignees();
+    let mut select_items: Vec<(String, RecentAssignee)> = recent_assignees
+        .iter()
+        .map(|assignee| {
+            (
+                format!("[recent] {}", assignee.display_text),
+                assignee.clone(),
+            )
+        })
+        .collect();
+
+    for user in users.into_iter().flatten() {
+        let is_recent = recent_assignees
+            .iter()
+            .any(|assignee| assignee.identity == user.identity());
+        if !is_recent {
+            select_items.push((user.display_text(), (&user).into()));
+        }
+    }
+    select_items
+}
+
+/// Updates assignee of the selected issue and closes the view.
+///
+/// `None` assignee unassigns the issue.
+fn assign_issue(cursive: &mut Cursive, assignee: Option<RecentAssignee>) {
+    let (request_client, issue_key) = {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let jira_data_guard = jira_data.read().unwrap();
+        let request_client = jira_data_guard.client.clone();
+        let selected_issue_key =
+            jira_data_guard.get_selected_task().key.clone();
+
+        (request_client, selected_issue_key)
+    };
+
+    let request_result = request_client.read().unwrap().update_issue_assignee(
+        assignee.as_ref().map(|assignee| &assignee.identity),
+        issue_key.as_str(),
+    );
+
+    if request_result.is_err() {
+        cursive.add_layer(FailedAttemptView::new(
+            "Can't change assignee for some reason. Try again",
+        ));
+        return;
+    }
+
+    if let Some(assignee) = assignee {
+        let jira_data: &mut Arc<RwLock<JiraData>> =
+            cursive.user_data().unwrap();
+        let mut jira_data_guard = jira_data.write().unwrap();
+        jira_data_guard.add_recent_assignee(assignee);
+    }
+
+    ChangeAssigneeView::toggle_off_view(cursive);
+    cursive.pop_layer();
+    InfoView::reload_selected_issue(cursive);
 }
 
 struct ChangeAssigneeInnerLayout {
@@ -83,11 +239,11 @@ struct ChangeAssigneeInnerLayout {
 }
 
 impl ChangeAssigneeInnerLayout {
-    fn new() -> Self {
+    fn new(select_items: Vec<(String, RecentAssignee)>) -> Self {
         let change_assignee_inner_layout = LinearLayout::vertical()
             .child(ChangeAssigneeEditView::new("Assignee search"))
             .child(
-                ChangeAssigneeSelectView::new()
+                ChangeAssigneeSelectView::new(select_items)
                     .with_name(ChangeAssigneeSelectView::view_name()),
             );
 
@@ -144,7 +300,7 @@ impl ChangeAssigneeEditView {
     }
 
     fn on_submit_callback(cursive: &mut Cursive, username: &str) {
-        let users = {
+        let select_items = {
             let jira_data: &mut Arc<RwLock<JiraData>> =
                 cursive.user_data().unwrap();
             let jira_data_guard = jira_data.read().unwrap();
@@ -153,34 +309,29 @@ impl ChangeAssigneeEditView {
             let response =
                 request_client.read().unwrap().get_jira_users(username);
 
-            match response {
+            let users = match response {
                 Ok(response) => {
-                    let serialized =
-                        serde_json::from_str::<JiraUsers>(response.get_body());
+                    let serialized = response.parse::<JiraUsers>();
                     match serialized {
                         Ok(serialized) => serialized,
                         Err(_) => return, // TODO: FailedAttemptView
                     }
                 }
                 Err(_) => return, // TODO: FailedAttemptView
-            }
+            };
+            make_select_items(&jira_data_guard, Some(users))
         };
 
         let mut change_assignee_select_view =
             ChangeAssigneeSelectView::get_view(cursive);
 
-        change_assignee_select_view.update_with_data(
-            users
-                .into_iter()
-                .map(|user| format!("{} | {}", user.display_name, user.name))
-                .collect(),
-        );
+        change_assignee_select_view.update_with_data(select_items);
     }
 }
 
 #[derive(ViewWrapper)]
 struct ChangeAssigneeSelectView {
-    pub inner_view: SelectView,
+    pub inner_view: SelectView<RecentAssignee>,
 }
 
 impl JiraViewWithName for ChangeAssigneeSelectView {
@@ -200,55 +351,25 @@ impl JiraViewWithName for ChangeAssigneeSelectView {
 impl ChangeJiraView for ChangeAssigneeSelectView {}
 
 impl ChangeAssigneeSelectView {
-    pub fn new() -> Self {
-        let change_assignee_select_view =
-            SelectView::new().on_submit(Self::on_submit_select_assignee);
+    pub fn new(select_items: Vec<(String, RecentAssignee)>) -> Self {
+        let change_assignee_select_view = SelectView::new()
+            .with_all(select_items)
+            .on_submit(|cursive, assignee: &RecentAssignee| {
+                assign_issue(cursive, Some(assignee.clone()))
+            });
         Self {
             inner_view: change_assignee_select_view,
         }
     }
 
     /// Update inner view with new_data data.
-    pub fn update_with_data(&mut self, new_data: Vec<String>) {
-        self.inner_view.clear();
-        self.inner_view.add_all_str(new_data);
-    }
-
-    fn on_submit_select_assignee(
-        cursive: &mut Cursive,
-        assignee_username: &str,
+    ///
+    /// Each item is a pair of user text and the assignee.
+    pub fn update_with_data(
+        &mut self,
+        new_data: Vec<(String, RecentAssignee)>,
     ) {
-        let (request_client, issue_key) = {
-            let jira_data: &mut Arc<RwLock<JiraData>> =
-                cursive.user_data().unwrap();
-            let jira_data_guard = jira_data.read().unwrap();
-            let request_client = jira_data_guard.client.clone();
-            let selected_issue_key =
-                jira_data_guard.get_selected_task().key.clone();
-
-            (request_client, selected_issue_key)
-        };
-
-        let request_result =
-            request_client.read().unwrap().update_issue_assignee(
-                assignee_username.split(" | ").collect::<Vec<&str>>()[1],
-                issue_key.as_str(),
-            );
-
-        {
-            let jira_data: &mut Arc<RwLock<JiraData>> =
-                cursive.user_data().unwrap();
-            let mut jira_data_guard = jira_data.write().unwrap();
-            jira_data_guard.update_selected_issue();
-        }
-
-        match request_result {
-            Ok(_) => {
-                InfoView::get_view(cursive).update_view_content(cursive);
-                ChangeAssigneeView::toggle_off_view(cursive);
-                cursive.pop_layer();
-            }
-            Err(_) => {}
-        }
+        self.inner_view.clear();
+        self.inner_view.add_all(new_data);
     }
 }
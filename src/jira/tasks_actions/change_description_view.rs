@@ -0,0 +1,152 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, ViewWrapper},
+    views::{
+        Dialog, DummyView, LinearLayout, NamedView, ResizedView, TextArea,
+        TextView,
+    },
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        adf::convert::text_to_request_body,
+        common::views::{ButtonView, JiraViewWithName, ToggleableView},
+        tasks::views::InfoView,
+        utils::{helpers::calculate_view_size, views::FailedAttemptView},
+    },
+    jira_data::JiraData,
+};
+
+/// Main view for changing issue description.
+#[derive(ViewWrapper)]
+pub struct ChangeDescriptionView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for ChangeDescriptionView {}
+
+impl ButtonView for ChangeDescriptionView {
+    fn inner_view(self) -> NamedView<ResizedView<Dialog>> {
+        self.inner_view
+    }
+}
+
+impl JiraViewWithName for ChangeDescriptionView {
+    /// Returns name of the `ChangeDescriptionView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "ChangeDescriptionView".into()
+    }
+
+    /// Returns instance of `ChangeDescriptionView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl ChangeDescriptionView {
+    /// Creates new view with the current description in the editor.
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let (description, is_convertible) = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            let issue = jira_data_guard.get_selected_task();
+            (
+                issue.description_text(),
+                issue.is_description_text_convertible(),
+            )
+        };
+
+        let mut editor_layout = LinearLayout::vertical();
+        if !is_convertible {
+            editor_layout.add_child(TextView::new(
+                "Description has formatting, tables, panels or mentions \
+                which the editor can't keep. Saving changes will replace \
+                them with a plain text.",
+            ));
+            editor_layout.add_child(DummyView);
+        }
+        editor_layout.add_child(
+            TextArea::new()
+                .content(description.clone())
+                .with_name(Self::text_area_name())
+                .full_screen(),
+        );
+
+        let change_description_view = Dialog::new()
+            .title("Edit description")
+            .content(editor_layout)
+            .button("Save", move |cursive| {
+                Self::on_save_description(cursive, &description)
+            })
+            .fixed_size(calculate_view_size(cursive, 5, 7))
+            .with_name(Self::view_name());
+        Self {
+            inner_view: change_description_view,
+        }
+    }
+
+    /// Returns name of the TextArea with description.
+    fn text_area_name() -> String {
+        "ChangeDescriptionTextArea".into()
+    }
+
+    /// Saves edited description.
+    ///
+    /// Nothing is sent if the text wasn't changed, so the description
+    /// isn't rebuilt from the text for nothing.
+    fn on_save_description(cursive: &mut Cursive, original_description: &str) {
+        let description = cursive
+            .call_on_name(&Self::text_area_name(), |view: &mut TextArea| {
+                view.get_content().to_string()
+            })
+            .unwrap();
+
+        if description == original_description {
+            Self::toggle_off_view(cursive);
+            cursive.pop_layer();
+            return;
+        }
+
+        let (request_client, issue_key) = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            let request_client = jira_data_guard.client.clone();
+            let selected_issue_key =
+                jira_data_guard.get_selected_task().key.clone();
+
+            (request_client, selected_issue_key)
+        };
+
+        let request_result = {
+            let request_client_guard = request_client.read().unwrap();
+            request_client_guard.update_issue_description(
+                text_to_request_body(
+                    &description,
+                    request_client_guard.is_adf_api(),
+                ),
+                issue_key.as_str(),
+            )
+        };
+
+        if request_result.is_err() {
+            cursive.add_layer(FailedAttemptView::new(
+                "Can't change description for some reason. Try again",
+            ));
+            return;
+        }
+
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        InfoView::reload_selected_issue(cursive);
+    }
+}
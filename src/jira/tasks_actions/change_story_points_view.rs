@@ -112,10 +112,11 @@ impl ChangeSPEditView {
                 issue_key.as_str(),
             );
 
-        if let Err(_) = request_result {
+        if request_result.is_err() {
             cursive.add_layer(FailedAttemptView::new(
-                "Can't change story points for some reason. Try again".to_string()
-                .as_str(),
+                "Can't change story points for some reason. Try again"
+                    .to_string()
+                    .as_str(),
             ));
             cursive.pop_layer();
             return;
@@ -85,38 +85,20 @@ impl ChangeTransitionActionView {
         let mut select_view = SelectView::<String>::new() // TODO: Rewrite as a separate view.
             .align(INNER_CENTER_TOP_VIEW_ALIGN)
             .on_submit(|cursive: &mut Cursive, transaction_name: &str| {
-                {
-                    Self::change_status(cursive, transaction_name);
-                    cursive.pop_layer();
-
-                    let jira_data: &mut Arc<RwLock<JiraData>> =
-                        cursive.user_data().unwrap();
-                    let mut jira_data_guard = jira_data.write().unwrap();
-                    {
-                        let client = jira_data_guard.client.clone();
-
-                        jira_data_guard
-                            .get_mut_selected_task()
-                            .add_transitions(client)
-                    }
-                }
-
-                {
-                    let jira_data: &mut Arc<RwLock<JiraData>> =
-                        cursive.user_data().unwrap();
-                    let mut jira_data_guard = jira_data.write().unwrap();
-                    jira_data_guard.update_selected_issue();
-                }
-
-                InfoView::get_view(cursive).update_view_content(cursive);
+                Self::change_status(cursive, transaction_name);
+                cursive.pop_layer();
+
+                // Transitions of the new status are loaded with the issue.
+                InfoView::reload_selected_issue(cursive);
             });
 
+        // Transitions are missing if they couldn't be loaded.
         select_view.add_all_str(
             jira_task
                 .transitions
                 .as_ref()
-                .unwrap()
-                .all_transitions_name(),
+                .map(|transitions| transitions.all_transitions_name())
+                .unwrap_or_default(),
         );
 
         Self {
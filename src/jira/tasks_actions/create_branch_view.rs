@@ -0,0 +1,124 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, ViewWrapper},
+    views::{Dialog, EditView, NamedView, ResizedView},
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    git::{branch_name, checkout_branch},
+    jira::{
+        common::views::{ButtonView, JiraViewWithName, ToggleableView},
+        utils::{helpers::calculate_view_size, views::FailedAttemptView},
+    },
+    jira_data::JiraData,
+};
+
+/// Main view for creating git branch of the selected issue.
+///
+/// Branch is created in the repository of the current directory.
+#[derive(ViewWrapper)]
+pub struct CreateBranchView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for CreateBranchView {}
+
+impl ButtonView for CreateBranchView {
+    fn inner_view(self) -> NamedView<ResizedView<Dialog>> {
+        self.inner_view
+    }
+}
+
+impl JiraViewWithName for CreateBranchView {
+    /// Returns name of the `CreateBranchView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "CreateBranchView".into()
+    }
+
+    /// Returns instance of `CreateBranchView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl CreateBranchView {
+    /// Creates new `CreateBranchView`.
+    ///
+    /// Name of the branch is made by the template from the config
+    /// and can be edited before creating.
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let branch = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            let task = jira_data_guard.get_selected_task();
+            let issue_type = task
+                .issue_type
+                .as_ref()
+                .map(|issue_type| issue_type.name.as_str())
+                .unwrap_or_default();
+            branch_name(
+                &jira_data_guard.branch_template,
+                &task.key,
+                &task.summary,
+                issue_type,
+            )
+        };
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Create git branch")
+                .content(
+                    EditView::new()
+                        .content(branch)
+                        .on_submit(Self::on_submit_branch)
+                        .with_name(Self::edit_view_name())
+                        .full_width(),
+                )
+                .button("Create", |cursive| {
+                    let branch = cursive
+                        .call_on_name(
+                            &Self::edit_view_name(),
+                            |edit_view: &mut EditView| edit_view.get_content(),
+                        )
+                        .unwrap();
+                    Self::on_submit_branch(cursive, &branch);
+                })
+                .fixed_size(calculate_view_size(cursive, 2, 7))
+                .with_name(Self::view_name()),
+        }
+    }
+
+    fn edit_view_name() -> String {
+        "CreateBranchEditView".into()
+    }
+
+    fn on_submit_branch(cursive: &mut Cursive, branch: &str) {
+        let branch = branch.trim();
+        let checkout_result = std::env::current_dir()
+            .and_then(|repo_dir| checkout_branch(&repo_dir, branch));
+        match checkout_result {
+            Ok(is_created) => {
+                Self::toggle_off_view(cursive);
+                cursive.pop_layer();
+                let message = if is_created {
+                    format!("Switched to a new branch `{branch}`")
+                } else {
+                    format!("Switched to branch `{branch}`")
+                };
+                cursive.add_layer(Dialog::info(message));
+            }
+            Err(err) => cursive.add_layer(FailedAttemptView::new(&format!(
+                "Can't create the branch: {err}"
+            ))),
+        }
+    }
+}
@@ -1,5 +1,27 @@
+use std::sync::{Arc, RwLock};
+
+use crate::{
+    errors::RusjiResult, jira::tasks::data::IssueLinkType,
+    request_client::client::RequestClient, state::RecentAssignee,
+};
 use serde::{Deserialize, Serialize};
 
+/// All issue link types configured in Jira.
+#[derive(Serialize, Deserialize)]
+pub struct IssueLinkTypes {
+    #[serde(alias = "issueLinkTypes")]
+    issue_link_types: Vec<IssueLinkType>,
+}
+
+impl IntoIterator for IssueLinkTypes {
+    type Item = IssueLinkType;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.issue_link_types.into_iter()
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct JiraUsers(Vec<JiraUser>);
 
@@ -14,21 +36,90 @@ impl IntoIterator for JiraUsers {
     }
 }
 
+/// Jira user.
+///
+/// Jira Cloud identifies users by `accountId` and often omits
+/// legacy `key`, `name` and `emailAddress` fields.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct JiraUser {
     #[serde(alias = "self")]
     pub link: String,
-    pub key: String,
-    pub name: String,
-    #[serde(alias = "emailAddress")]
-    pub email_address: String,
+    #[serde(alias = "accountId", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(alias = "emailAddress", skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
     #[serde(alias = "displayName")]
     pub display_name: String,
+    #[serde(default)]
     pub active: bool,
-    #[serde(alias = "timeZone")]
-    pub time_zone: String,
+    #[serde(alias = "timeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locale: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
 }
+
+/// Field which identifies the user in requests.
+///
+/// Jira Cloud users have `accountId`, Jira Server users have username.
+/// It's serialized as the field, e.g. `{"accountId": "5b10ac8d"}`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum UserIdentity {
+    #[serde(rename = "accountId")]
+    AccountId(String),
+    #[serde(rename = "name")]
+    Name(String),
+}
+
+impl UserIdentity {
+    /// Returns value of the identity field.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AccountId(account_id) => account_id,
+            Self::Name(name) => name,
+        }
+    }
+}
+
+impl JiraUser {
+    /// Requests currently logged in user.
+    pub fn current(
+        request_client: Arc<RwLock<RequestClient>>,
+    ) -> RusjiResult<Self> {
+        let response = request_client.read().unwrap().get_myself()?;
+        response.parse::<JiraUser>()
+    }
+
+    /// Returns identity to use in requests.
+    ///
+    /// It's `accountId` if the user has it and username otherwise.
+    pub fn identity(&self) -> UserIdentity {
+        match (&self.account_id, &self.name) {
+            (Some(account_id), _) => {
+                UserIdentity::AccountId(account_id.clone())
+            }
+            (None, name) => {
+                UserIdentity::Name(name.clone().unwrap_or_default())
+            }
+        }
+    }
+
+    /// Returns text to show user in lists.
+    pub fn display_text(&self) -> String {
+        match self.email_address.as_ref().or(self.name.as_ref()) {
+            Some(details) => format!("{} | {}", self.display_name, details),
+            None => self.display_name.clone(),
+        }
+    }
+}
+
+impl From<&JiraUser> for RecentAssignee {
+    fn from(user: &JiraUser) -> Self {
+        RecentAssignee::new(user.identity(), &user.display_text())
+    }
+}
@@ -0,0 +1,215 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, Scrollable, ViewWrapper},
+    views::{
+        Dialog, EditView, LinearLayout, NamedView, ResizedView, SelectView,
+    },
+    Cursive, View,
+};
+use home::home_dir;
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::views::{ButtonView, JiraViewWithName, ToggleableView},
+        constance::INNER_LEFT_TOP_VIEW_ALIGN,
+        tasks::data::IssueAttachment,
+        utils::{
+            helpers::{
+                calculate_view_size, expand_user_path, open_with_default_app,
+            },
+            views::FailedAttemptView,
+        },
+    },
+    jira_data::JiraData,
+};
+
+/// Main view for downloading attachments of the selected issue.
+#[derive(ViewWrapper)]
+pub struct DownloadAttachmentsView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for DownloadAttachmentsView {}
+
+impl ButtonView for DownloadAttachmentsView {
+    fn inner_view(self) -> NamedView<ResizedView<Dialog>> {
+        self.inner_view
+    }
+}
+
+impl JiraViewWithName for DownloadAttachmentsView {
+    /// Returns name of the `DownloadAttachmentsView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "DownloadAttachmentsView".into()
+    }
+
+    /// Returns instance of `DownloadAttachmentsView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl DownloadAttachmentsView {
+    /// Creates new `DownloadAttachmentsView`.
+    ///
+    /// User enters the directory and chooses one attachment or all of them.
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let attachments: Vec<IssueAttachment> = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.get_selected_task().attachments.clone()
+        };
+
+        let title = if attachments.is_empty() {
+            "The issue has no attachments"
+        } else {
+            "Enter directory and choose attachment to download"
+        };
+        let directory_view = EditView::new()
+            .content(Self::default_directory().display().to_string())
+            .on_submit(|cursive: &mut Cursive, _: &str| {
+                // Focus can't be lost, the select view is in the same layout.
+                let _ = cursive.focus_name(&Self::select_view_name());
+            })
+            .with_name(Self::edit_view_name());
+        let attachments_view = SelectView::<Vec<IssueAttachment>>::new()
+            .align(INNER_LEFT_TOP_VIEW_ALIGN)
+            .with_all(Self::make_select_items(attachments))
+            .on_submit(
+                |cursive: &mut Cursive, attachments: &Vec<IssueAttachment>| {
+                    Self::on_submit_attachments(cursive, attachments)
+                },
+            )
+            .with_name(Self::select_view_name());
+
+        Self {
+            inner_view: Dialog::new()
+                .title(title)
+                .content(
+                    LinearLayout::vertical()
+                        .child(
+                            Dialog::new()
+                                .title("Directory")
+                                .content(directory_view),
+                        )
+                        .child(attachments_view.scrollable()),
+                )
+                .fixed_size(calculate_view_size(cursive, 3, 7))
+                .with_name(Self::view_name()),
+        }
+    }
+
+    /// Returns name of the EditView with directory.
+    fn edit_view_name() -> String {
+        "DownloadAttachmentsEditView".into()
+    }
+
+    /// Returns name of the SelectView with attachments.
+    fn select_view_name() -> String {
+        "DownloadAttachmentsSelectView".into()
+    }
+
+    /// Returns `~/Downloads` if it exists, otherwise home directory.
+    fn default_directory() -> PathBuf {
+        let home_dir = home_dir().unwrap_or_default();
+        let downloads_dir = home_dir.join("Downloads");
+        if downloads_dir.is_dir() {
+            downloads_dir
+        } else {
+            home_dir
+        }
+    }
+
+    /// Makes items for every attachment and one for all of them.
+    fn make_select_items(
+        attachments: Vec<IssueAttachment>,
+    ) -> Vec<(String, Vec<IssueAttachment>)> {
+        if attachments.is_empty() {
+            return Vec::new();
+        }
+        let mut select_items = vec![(
+            format!("All attachments ({})", attachments.len()),
+            attachments.clone(),
+        )];
+        select_items.extend(
+            attachments.into_iter().map(|attachment| {
+                (attachment.display_text(), vec![attachment])
+            }),
+        );
+        select_items
+    }
+
+    fn on_submit_attachments(
+        cursive: &mut Cursive,
+        attachments: &[IssueAttachment],
+    ) {
+        let directory = cursive
+            .call_on_name(&Self::edit_view_name(), |view: &mut EditView| {
+                expand_user_path(&view.get_content())
+            })
+            .unwrap();
+        if directory.as_os_str().is_empty() {
+            return;
+        }
+
+        let download_result = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.download_attachments(attachments, &directory)
+        };
+
+        match download_result {
+            Ok(file_paths) => {
+                Self::toggle_off_view(cursive);
+                cursive.pop_layer();
+                cursive.add_layer(Self::make_downloaded_view(
+                    &directory, file_paths,
+                ));
+            }
+            Err(error) => cursive.add_layer(FailedAttemptView::new(
+                format!("Can't download attachments: {}", error).as_str(),
+            )),
+        }
+    }
+
+    /// Makes dialog with downloaded files.
+    ///
+    /// Submit on the file opens it with `xdg-open`.
+    fn make_downloaded_view(
+        directory: &Path,
+        file_paths: Vec<PathBuf>,
+    ) -> Dialog {
+        let files_view =
+            SelectView::<PathBuf>::new()
+                .align(INNER_LEFT_TOP_VIEW_ALIGN)
+                .with_all(file_paths.into_iter().map(|file_path| {
+                    (file_path.display().to_string(), file_path)
+                }))
+                .on_submit(|cursive: &mut Cursive, file_path: &PathBuf| {
+                    if open_with_default_app(file_path).is_err() {
+                        cursive.add_layer(FailedAttemptView::new(
+                            "Can't open the file with xdg-open",
+                        ));
+                    }
+                });
+        Dialog::new()
+            .title(format!(
+                "Downloaded to {}, press <enter> to open",
+                directory.display()
+            ))
+            .content(files_view.scrollable())
+            .button("Close", |cursive: &mut Cursive| {
+                cursive.pop_layer();
+            })
+    }
+}
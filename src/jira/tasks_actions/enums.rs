@@ -10,9 +10,15 @@ use crate::jira::common::{
 };
 
 use super::{
+    add_comment_view::AddCommentView, add_link_view::AddLinkView,
     change_assignee_views::ChangeAssigneeView,
+    change_description_view::ChangeDescriptionView,
     change_story_points_view::ChangeSPView,
     change_transition_view::ChangeTransitionActionView,
+    create_branch_view::CreateBranchView,
+    download_attachments_view::DownloadAttachmentsView,
+    remove_link_view::RemoveLinkView, share_link_view::ShareLinkView,
+    upload_attachment_view::UploadAttachmentView,
 };
 
 #[derive(Clone, Copy)] // TODO: remove Clone, Copy
@@ -20,6 +26,14 @@ pub enum TaskActions {
     StatusChange,
     ChangeAssignee,
     ChangeStoryPoints,
+    ChangeDescription,
+    AddComment,
+    AddLink,
+    RemoveLink,
+    DownloadAttachments,
+    UploadAttachment,
+    ShareLink,
+    CreateBranch,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -32,17 +46,33 @@ impl FromStr for TaskActions {
             "Change status" => Ok(TaskActions::StatusChange),
             "Change assignee" => Ok(TaskActions::ChangeAssignee),
             "Change story points" => Ok(TaskActions::ChangeStoryPoints),
+            "Change description" => Ok(TaskActions::ChangeDescription),
+            "Add comment" => Ok(TaskActions::AddComment),
+            "Add link" => Ok(TaskActions::AddLink),
+            "Remove link" => Ok(TaskActions::RemoveLink),
+            "Download attachments" => Ok(TaskActions::DownloadAttachments),
+            "Upload attachment" => Ok(TaskActions::UploadAttachment),
+            "Open or copy link" => Ok(TaskActions::ShareLink),
+            "Create git branch" => Ok(TaskActions::CreateBranch),
             _ => Err(TaskActionParseError {}),
         }
     }
 }
 
-impl<'a> From<TaskActions> for &'a str {
+impl From<TaskActions> for &str {
     fn from(action: TaskActions) -> Self {
         match action {
             TaskActions::StatusChange => "Change status",
             TaskActions::ChangeAssignee => "Change assignee",
             TaskActions::ChangeStoryPoints => "Change story points",
+            TaskActions::ChangeDescription => "Change description",
+            TaskActions::AddComment => "Add comment",
+            TaskActions::AddLink => "Add link",
+            TaskActions::RemoveLink => "Remove link",
+            TaskActions::DownloadAttachments => "Download attachments",
+            TaskActions::UploadAttachment => "Upload attachment",
+            TaskActions::ShareLink => "Open or copy link",
+            TaskActions::CreateBranch => "Create git branch",
         }
     }
 }
@@ -56,6 +86,14 @@ impl TaskActions {
             Self::StatusChange.into(),
             Self::ChangeAssignee.into(),
             Self::ChangeStoryPoints.into(),
+            Self::ChangeDescription.into(),
+            Self::AddComment.into(),
+            Self::AddLink.into(),
+            Self::RemoveLink.into(),
+            Self::DownloadAttachments.into(),
+            Self::UploadAttachment.into(),
+            Self::ShareLink.into(),
+            Self::CreateBranch.into(),
         ]
     }
 
@@ -74,6 +112,26 @@ impl TaskActions {
             TaskActions::ChangeStoryPoints => {
                 ChangeSPView::new(cursive).inner_view()
             }
+            TaskActions::ChangeDescription => {
+                ChangeDescriptionView::new(cursive).inner_view()
+            }
+            TaskActions::AddComment => {
+                AddCommentView::new(cursive).inner_view()
+            }
+            TaskActions::AddLink => AddLinkView::new(cursive).inner_view(),
+            TaskActions::RemoveLink => {
+                RemoveLinkView::new(cursive).inner_view()
+            }
+            TaskActions::DownloadAttachments => {
+                DownloadAttachmentsView::new(cursive).inner_view()
+            }
+            TaskActions::UploadAttachment => {
+                UploadAttachmentView::new(cursive).inner_view()
+            }
+            TaskActions::ShareLink => ShareLinkView::new(cursive).inner_view(),
+            TaskActions::CreateBranch => {
+                CreateBranchView::new(cursive).inner_view()
+            }
         }
     }
 }
@@ -1,4 +1,5 @@
 use cursive::{
+    event::Event,
     view::{Finder, Nameable, Resizable, ViewWrapper},
     views::{
         Dialog, NamedView, OnEventView, ResizedView, ScrollView, SelectView,
@@ -82,8 +83,8 @@ impl ActionsView {
         let mut on_event_view = OnEventView::new(select_view);
 
         for button in callback_buttons.buttons.into_iter() {
-            on_event_view =
-                on_event_view.on_event(button.event, button.action_fn)
+            on_event_view = on_event_view
+                .on_event(Event::from(button.event), button.action_fn)
         }
 
         on_event_view
@@ -114,7 +115,7 @@ impl JiraWithDialogView for ActionsView {
     }
 }
 
-impl<'a> ActionsView {
+impl ActionsView {
     /// Returns name of the SelectView in MainActionsView.
     pub fn select_view_name() -> String {
         String::from("ActionsSelectView")
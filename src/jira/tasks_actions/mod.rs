@@ -1,7 +1,15 @@
+pub mod add_comment_view;
+pub mod add_link_view;
 pub mod buttons;
 pub mod change_assignee_views;
+pub mod change_description_view;
 pub mod change_story_points_view;
 pub mod change_transition_view;
+pub mod create_branch_view;
 pub mod data;
+pub mod download_attachments_view;
 pub mod enums;
 pub mod main_view;
+pub mod remove_link_view;
+pub mod share_link_view;
+pub mod upload_attachment_view;
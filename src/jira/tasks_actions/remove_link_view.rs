@@ -0,0 +1,109 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, Scrollable, ViewWrapper},
+    views::{Dialog, NamedView, ResizedView, SelectView},
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::views::{ButtonView, JiraViewWithName, ToggleableView},
+        constance::INNER_LEFT_TOP_VIEW_ALIGN,
+        tasks::views::InfoView,
+        utils::{helpers::calculate_view_size, views::FailedAttemptView},
+    },
+    jira_data::JiraData,
+};
+
+/// Main view for removing links of the selected issue.
+#[derive(ViewWrapper)]
+pub struct RemoveLinkView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for RemoveLinkView {}
+
+impl ButtonView for RemoveLinkView {
+    fn inner_view(self) -> NamedView<ResizedView<Dialog>> {
+        self.inner_view
+    }
+}
+
+impl JiraViewWithName for RemoveLinkView {
+    /// Returns name of the `RemoveLinkView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "RemoveLinkView".into()
+    }
+
+    /// Returns instance of `RemoveLinkView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl RemoveLinkView {
+    /// Creates new `RemoveLinkView`.
+    ///
+    /// Only issue links can be removed,
+    /// parent, epic and subtasks are not listed.
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let issue_links: Vec<(String, String)> = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard
+                .get_selected_task()
+                .relations(None)
+                .into_iter()
+                .filter_map(|relation| {
+                    let link_id = relation.link_id.clone()?;
+                    Some((relation.display_text(), link_id))
+                })
+                .collect()
+        };
+
+        let issue_links_view = SelectView::<String>::new()
+            .align(INNER_LEFT_TOP_VIEW_ALIGN)
+            .with_all(issue_links)
+            .on_submit(|cursive: &mut Cursive, link_id: &str| {
+                Self::on_submit_issue_link(cursive, link_id)
+            });
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Choose link to remove")
+                .content(issue_links_view.scrollable())
+                .fixed_size(calculate_view_size(cursive, 3, 7))
+                .with_name(Self::view_name()),
+        }
+    }
+
+    fn on_submit_issue_link(cursive: &mut Cursive, link_id: &str) {
+        let request_client = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.client.clone()
+        };
+
+        let request_result =
+            request_client.read().unwrap().delete_issue_link(link_id);
+        if request_result.is_err() {
+            cursive.add_layer(FailedAttemptView::new(
+                "Can't remove the link for some reason. Try again",
+            ));
+            return;
+        }
+
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        InfoView::reload_selected_issue(cursive);
+    }
+}
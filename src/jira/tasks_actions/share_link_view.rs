@@ -0,0 +1,134 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, Scrollable, ViewWrapper},
+    views::{Dialog, NamedView, ResizedView, SelectView},
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::views::{ButtonView, JiraViewWithName, ToggleableView},
+        constance::INNER_LEFT_TOP_VIEW_ALIGN,
+        utils::{
+            clipboard::{copy_to_clipboard, markdown_link},
+            helpers::{calculate_view_size, open_in_browser},
+            views::FailedAttemptView,
+        },
+    },
+    jira_data::JiraData,
+};
+
+/// What to do with the link of the issue.
+#[derive(Clone)]
+enum ShareAction {
+    OpenInBrowser(String),
+    Copy(String),
+}
+
+/// Main view for opening the selected issue in browser
+/// or copying its key or link.
+#[derive(ViewWrapper)]
+pub struct ShareLinkView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for ShareLinkView {}
+
+impl ButtonView for ShareLinkView {
+    fn inner_view(self) -> NamedView<ResizedView<Dialog>> {
+        self.inner_view
+    }
+}
+
+impl JiraViewWithName for ShareLinkView {
+    /// Returns name of the `ShareLinkView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "ShareLinkView".into()
+    }
+
+    /// Returns instance of `ShareLinkView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl ShareLinkView {
+    /// Creates new `ShareLinkView`.
+    ///
+    /// Link leads to the issue page on the configured Jira url.
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let (issue_key, summary, browse_url) = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            let task = jira_data_guard.get_selected_task();
+            let browse_url = jira_data_guard
+                .client
+                .read()
+                .unwrap()
+                .browse_url(&task.key)
+                .to_string();
+            (task.key.clone(), task.summary.clone(), browse_url)
+        };
+
+        let actions = vec![
+            (
+                "Open in browser".to_string(),
+                ShareAction::OpenInBrowser(browse_url.clone()),
+            ),
+            (
+                format!("Copy key: {}", issue_key),
+                ShareAction::Copy(issue_key.clone()),
+            ),
+            (
+                format!("Copy URL: {}", browse_url),
+                ShareAction::Copy(browse_url.clone()),
+            ),
+            (
+                "Copy Markdown link".to_string(),
+                ShareAction::Copy(markdown_link(
+                    &format!("{} {}", issue_key, summary),
+                    &browse_url,
+                )),
+            ),
+        ];
+        let actions_view = SelectView::<ShareAction>::new()
+            .align(INNER_LEFT_TOP_VIEW_ALIGN)
+            .with_all(actions)
+            .on_submit(|cursive: &mut Cursive, action: &ShareAction| {
+                Self::on_submit_action(cursive, action)
+            });
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Open or copy issue link")
+                .content(actions_view.scrollable())
+                .fixed_size(calculate_view_size(cursive, 3, 7))
+                .with_name(Self::view_name()),
+        }
+    }
+
+    fn on_submit_action(cursive: &mut Cursive, action: &ShareAction) {
+        let (result, error_text) = match action {
+            ShareAction::OpenInBrowser(url) => {
+                (open_in_browser(url), "Can't open the browser")
+            }
+            ShareAction::Copy(text) => {
+                (copy_to_clipboard(text), "Can't copy to the clipboard")
+            }
+        };
+        if result.is_err() {
+            cursive.add_layer(FailedAttemptView::new(error_text));
+            return;
+        }
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::{Arc, RwLock};
+
+use cursive::{
+    view::{Nameable, Resizable, ViewWrapper},
+    views::{Dialog, EditView, NamedView, ResizedView},
+    Cursive, View,
+};
+use rusji_derive::ViewWrapper;
+
+use crate::{
+    jira::{
+        common::views::{ButtonView, JiraViewWithName, ToggleableView},
+        tasks::views::InfoView,
+        utils::{
+            helpers::{calculate_view_size, expand_user_path},
+            views::FailedAttemptView,
+        },
+    },
+    jira_data::JiraData,
+};
+
+/// Main view for attaching local file to the selected issue.
+#[derive(ViewWrapper)]
+pub struct UploadAttachmentView {
+    inner_view: NamedView<ResizedView<Dialog>>,
+}
+
+impl ToggleableView for UploadAttachmentView {}
+
+impl ButtonView for UploadAttachmentView {
+    fn inner_view(self) -> NamedView<ResizedView<Dialog>> {
+        self.inner_view
+    }
+}
+
+impl JiraViewWithName for UploadAttachmentView {
+    /// Returns name of the `UploadAttachmentView`.
+    ///
+    /// It will used for `.with_name()` method.
+    fn view_name() -> String {
+        "UploadAttachmentView".into()
+    }
+
+    /// Returns instance of `UploadAttachmentView`
+    fn get_view(
+        cursive: &mut cursive::Cursive,
+    ) -> cursive::views::ViewRef<Self> {
+        cursive.find_name(Self::view_name().as_str()).unwrap()
+    }
+}
+
+impl UploadAttachmentView {
+    pub fn new(cursive: &mut Cursive) -> Self {
+        Self::toggle_on_view(cursive);
+        let file_path_view = EditView::new().on_submit(Self::on_submit_path);
+
+        Self {
+            inner_view: Dialog::new()
+                .title("Enter path to the file")
+                .content(file_path_view)
+                .fixed_size(calculate_view_size(cursive, 1, 2))
+                .with_name(Self::view_name()),
+        }
+    }
+
+    fn on_submit_path(cursive: &mut Cursive, file_path: &str) {
+        let file_path = expand_user_path(file_path);
+        if !file_path.is_file() {
+            cursive.add_layer(FailedAttemptView::new(
+                format!("File {} doesn't exist", file_path.display()).as_str(),
+            ));
+            return;
+        }
+
+        let upload_result = {
+            let jira_data: &mut Arc<RwLock<JiraData>> =
+                cursive.user_data().unwrap();
+            let jira_data_guard = jira_data.read().unwrap();
+            jira_data_guard.upload_attachment(&file_path)
+        };
+        if let Err(error) = upload_result {
+            cursive.add_layer(FailedAttemptView::new(
+                format!("Can't upload the file: {}", error).as_str(),
+            ));
+            return;
+        }
+
+        Self::toggle_off_view(cursive);
+        cursive.pop_layer();
+        InfoView::reload_selected_issue(cursive);
+    }
+}
@@ -0,0 +1,633 @@
+use crate::{
+    config::ApiVersion,
+    errors::RusjiError,
+    jira::{
+        auto_refresh::data::{
+            updated_issues_jql, RefreshSummary, UpdatedIssues,
+        },
+        filters::data::JqlFilter,
+        my_work::data::MyWork,
+        projects::data::JiraProjects,
+        tasks::{
+            changelog::ChangelogFilter,
+            data::JiraIssues,
+            ordering::{TaskGroup, TaskListItem, TaskListOrder, TaskSort},
+        },
+        tasks_actions::data::UserIdentity,
+    },
+    jira_data::JiraData,
+};
+
+use super::{
+    make_client, make_jira_data, make_jira_data_with_version, make_temp_dir,
+    mock_server::{MockJiraServer, MockRoute},
+    TEST_CREDENTIALS,
+};
+
+/// Selects `Test project` and loads its tasks from the server.
+fn select_test_project(jira_data: &mut JiraData) {
+    jira_data.set_selected_project("Test project");
+    let tasks = JiraIssues::new(jira_data.client.clone(), "TST");
+    jira_data.update_tasks(tasks);
+}
+
+#[test]
+fn test_update_projects() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let jira_data_guard = jira_data.read().unwrap();
+
+    let mut projects_names = jira_data_guard.get_projects_names();
+    projects_names.sort();
+
+    assert_eq!(projects_names, vec!["Empty project", "Test project"]);
+}
+
+#[test]
+fn test_update_projects_error() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new("GET", "/rest/api/2/project", 401, ""));
+    let mut jira_data =
+        JiraData::new(server.url(), TEST_CREDENTIALS, ApiVersion::V2);
+
+    let jira_projects = JiraProjects::new(jira_data.client.clone());
+    assert!(matches!(
+        jira_projects,
+        Err(RusjiError::BadResponseStatus(401))
+    ));
+    jira_data.update_projects(jira_projects);
+
+    assert!(jira_data.get_projects_names().is_empty());
+}
+
+#[test]
+fn test_update_tasks() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+
+    select_test_project(&mut jira_data_guard);
+
+    let mut tasks_names = jira_data_guard
+        .get_selected_project()
+        .unwrap()
+        .tasks_names()
+        .unwrap();
+    tasks_names.sort();
+    assert_eq!(
+        tasks_names,
+        vec![
+            "TST-1 -- Fix login page",
+            "TST-2 -- Write release notes",
+            "TST-3 -- Upgrade dependencies",
+        ],
+    );
+}
+
+#[test]
+fn test_get_tasks_list() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+
+    jira_data_guard.change_task_list_order(|order| {
+        order.sort = TaskSort::Priority;
+    });
+    assert_eq!(
+        jira_data_guard.get_tasks_list().unwrap(),
+        vec![
+            TaskListItem::Task("TST-2 -- Write release notes".into()),
+            TaskListItem::Task("TST-1 -- Fix login page".into()),
+            TaskListItem::Task("TST-3 -- Upgrade dependencies".into()),
+        ],
+    );
+
+    jira_data_guard.change_task_list_order(|order| {
+        order.sort = TaskSort::Updated;
+        order.group = TaskGroup::Sprint;
+    });
+    assert_eq!(
+        jira_data_guard.get_tasks_list().unwrap(),
+        vec![
+            TaskListItem::Group("Sprint 2 (2)".into()),
+            TaskListItem::Task("TST-2 -- Write release notes".into()),
+            TaskListItem::Task("TST-1 -- Fix login page".into()),
+            TaskListItem::Group("No sprint (1)".into()),
+            TaskListItem::Task("TST-3 -- Upgrade dependencies".into()),
+        ],
+    );
+
+    jira_data_guard.change_task_list_order(|order| {
+        order.sort = TaskSort::Key;
+        order.group = TaskGroup::Epic;
+    });
+    assert_eq!(
+        jira_data_guard.get_tasks_list().unwrap(),
+        vec![
+            TaskListItem::Group("TST-3 (1)".into()),
+            TaskListItem::Task("TST-1 -- Fix login page".into()),
+            TaskListItem::Group("No epic (2)".into()),
+            TaskListItem::Task("TST-2 -- Write release notes".into()),
+            TaskListItem::Task("TST-3 -- Upgrade dependencies".into()),
+        ],
+    );
+
+    // Order is remembered per project.
+    jira_data_guard.set_selected_project("Empty project");
+    assert_eq!(
+        jira_data_guard.get_task_list_order(),
+        TaskListOrder::default()
+    );
+}
+
+#[test]
+fn test_update_tasks_error() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    server.mock(
+        MockRoute::new("GET", "/rest/api/2/search", 503, "")
+            .with_query("jql", "project=TST"),
+    );
+
+    select_test_project(&mut jira_data_guard);
+
+    assert!(jira_data_guard
+        .get_selected_project()
+        .unwrap()
+        .tasks_names()
+        .is_none());
+}
+
+#[test]
+fn test_set_selected_task() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+
+    jira_data_guard.set_selected_task("2");
+    assert_eq!(jira_data_guard.selected_task, "TST-2");
+
+    jira_data_guard.set_selected_task("TST-3 -- Upgrade dependencies");
+    assert_eq!(jira_data_guard.selected_task, "TST-3");
+    assert_eq!(
+        jira_data_guard.get_selected_task().summary,
+        "Upgrade dependencies",
+    );
+}
+
+#[test]
+fn test_find_by_subname() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+
+    let fit_projects: Vec<String> = jira_data_guard
+        .find_project_by_subname("empty")
+        .into_iter()
+        .map(|project| project.text)
+        .collect();
+    assert_eq!(fit_projects, vec!["Empty project"]);
+
+    let fit_tasks = jira_data_guard.find_task_by_subname("tst-3").unwrap();
+    assert_eq!(fit_tasks.len(), 1);
+    assert_eq!(fit_tasks[0].text, "TST-3 -- Upgrade dependencies");
+    assert_eq!(fit_tasks[0].highlights, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_find_task_by_query() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+    let find_keys = |raw_query: &str| -> Vec<String> {
+        jira_data_guard
+            .find_task_by_subname(raw_query)
+            .unwrap()
+            .into_iter()
+            .map(|task| task.text.split(" -- ").next().unwrap().to_string())
+            .collect()
+    };
+
+    assert_eq!(find_keys(""), vec!["TST-1", "TST-2", "TST-3"]);
+    assert_eq!(find_keys("status:progress"), vec!["TST-1"]);
+    assert_eq!(find_keys("assignee:me"), vec!["TST-1"]);
+    assert_eq!(find_keys("label:docs"), vec!["TST-2"]);
+    assert_eq!(find_keys("status:todo notes"), vec!["TST-2"]);
+    assert!(find_keys("status:progress notes").is_empty());
+    // Summary matches are ranked higher than scattered ones.
+    assert_eq!(find_keys("release")[0], "TST-2");
+}
+
+#[test]
+fn test_update_selected_issue() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+    jira_data_guard.set_selected_task("1");
+    server.mock(MockRoute::new(
+        "GET",
+        "/rest/api/2/issue/TST-1",
+        200,
+        &super::mock_server::read_fixture("issue_tst_1.json")
+            .replace("Fix login page", "Fix login page again"),
+    ));
+
+    jira_data_guard.update_selected_issue().unwrap();
+
+    let task = jira_data_guard.get_selected_task();
+    assert_eq!(task.summary, "Fix login page again");
+    assert_eq!(
+        task.transitions.as_ref().unwrap().all_transitions_name(),
+        vec!["To Do", "In Progress", "Done"],
+    );
+}
+
+#[test]
+fn test_update_selected_issue_keeps_cached_issue() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+    jira_data_guard.set_selected_task("1");
+    server.mock(MockRoute::new("GET", "/rest/api/2/issue/TST-1", 500, "{}"));
+
+    assert!(jira_data_guard.update_selected_issue().is_err());
+
+    let task = jira_data_guard.get_selected_task();
+    assert_eq!(task.key, "TST-1");
+    assert_eq!(task.summary, "Fix login page");
+}
+
+#[test]
+fn test_get_current_user() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let jira_data_guard = jira_data.read().unwrap();
+
+    let identity = jira_data_guard.get_current_user().unwrap().identity();
+    assert_eq!(identity, UserIdentity::Name("alice".into()));
+    jira_data_guard.get_current_user().unwrap();
+
+    // The user is loaded with projects.
+    assert_eq!(server.requests_to("GET", "/rest/api/2/myself").len(), 1);
+}
+
+#[test]
+fn test_current_user_is_not_requested_again() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new("GET", "/rest/api/2/myself", 500, ""));
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+
+    for raw_query in ["assignee:m", "assignee:me", "assignee:me login"] {
+        assert_eq!(
+            jira_data_guard.find_task_by_subname(raw_query).unwrap(),
+            vec![]
+        );
+    }
+    assert!(jira_data_guard.get_current_user().is_none());
+
+    assert_eq!(server.requests_to("GET", "/rest/api/2/myself").len(), 1);
+}
+
+#[test]
+fn test_get_current_user_cloud() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data_with_version(&server, ApiVersion::V3);
+    let jira_data_guard = jira_data.read().unwrap();
+
+    let identity = jira_data_guard.get_current_user().unwrap().identity();
+
+    assert_eq!(
+        identity,
+        UserIdentity::AccountId("5b10a2844c20165700ede21g".into()),
+    );
+}
+
+#[test]
+fn test_open_issue() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+
+    let is_project_changed = jira_data_guard.open_issue("TST-6").unwrap();
+
+    assert!(!is_project_changed);
+    assert_eq!(
+        jira_data_guard.get_selected_task().summary,
+        "Add login tests"
+    );
+    assert!(jira_data_guard.get_selected_task().transitions.is_some());
+}
+
+#[test]
+fn test_open_issue_from_another_project() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+
+    let is_project_changed = jira_data_guard.open_issue("EMP-1").unwrap();
+
+    assert!(is_project_changed);
+    assert_eq!(jira_data_guard.selected_project, "Empty project");
+    assert_eq!(jira_data_guard.get_selected_task().key, "EMP-1");
+}
+
+#[test]
+fn test_open_issue_unknown_project() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+
+    let open_result = jira_data_guard.open_issue("ABC-1");
+
+    assert!(matches!(open_result, Err(RusjiError::UnknownProject(_))));
+    assert_eq!(jira_data_guard.selected_project, "Test project");
+}
+
+#[test]
+fn test_get_epic_link_field() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let jira_data_guard = jira_data.read().unwrap();
+
+    let requests_count = server.requests().len();
+
+    assert_eq!(
+        jira_data_guard.get_epic_link_field(),
+        Some("customfield_10101")
+    );
+    assert_eq!(
+        jira_data_guard.get_sprint_field(),
+        Some("customfield_10100")
+    );
+    assert_eq!(server.requests().len(), requests_count);
+}
+
+#[test]
+fn test_toggle_history_tab() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+    jira_data_guard.set_selected_task("TST-1");
+    assert!(jira_data_guard.get_selected_task().changelog.is_none());
+
+    jira_data_guard.toggle_history_tab().unwrap();
+    assert!(jira_data_guard.history_tab.is_some());
+    assert!(jira_data_guard.get_selected_task().changelog.is_some());
+
+    jira_data_guard.toggle_history_tab().unwrap();
+    jira_data_guard.toggle_history_tab().unwrap();
+    jira_data_guard.change_history_tab(|history_tab| history_tab.page = 5);
+    assert_eq!(jira_data_guard.history_tab.unwrap().page, 2);
+    jira_data_guard.change_history_tab(|history_tab| {
+        history_tab.filter = ChangelogFilter::Status;
+    });
+    assert_eq!(jira_data_guard.history_tab.unwrap().page, 1);
+    let requests =
+        server.requests_to("GET", "/rest/api/2/issue/TST-1/changelog");
+    assert_eq!(requests.len(), 2);
+
+    jira_data_guard.set_selected_task("TST-2");
+    assert!(jira_data_guard.history_tab.is_none());
+}
+
+#[test]
+fn test_download_attachments() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+    jira_data_guard.set_selected_task("TST-1");
+    let directory = make_temp_dir("download-attachments").join("new");
+
+    let attachments = jira_data_guard.get_selected_task().attachments.clone();
+    let file_paths = jira_data_guard
+        .download_attachments(&attachments, &directory)
+        .unwrap();
+
+    assert_eq!(
+        file_paths,
+        vec![
+            directory.join("notes.txt"),
+            directory.join("screenshot.png")
+        ]
+    );
+    assert_eq!(
+        std::fs::read_to_string(&file_paths[0]).unwrap(),
+        "Steps to reproduce: 1\n"
+    );
+}
+
+#[test]
+fn test_download_attachments_with_same_names() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+    jira_data_guard.set_selected_task("TST-1");
+    let directory = make_temp_dir("download-attachments-same-names");
+
+    let mut attachments =
+        jira_data_guard.get_selected_task().attachments.clone();
+    attachments.truncate(1);
+    attachments.push(attachments[0].clone());
+    let file_paths = jira_data_guard
+        .download_attachments(&attachments, &directory)
+        .unwrap();
+
+    assert_eq!(
+        file_paths,
+        vec![directory.join("notes.txt"), directory.join("notes (1).txt")]
+    );
+    assert_eq!(
+        std::fs::read_to_string(&file_paths[1]).unwrap(),
+        "Steps to reproduce: 1\n"
+    );
+}
+
+#[test]
+fn test_upload_attachment() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+    select_test_project(&mut jira_data_guard);
+    jira_data_guard.set_selected_task("TST-1");
+    let file_path = make_temp_dir("jira-data-upload").join("report.txt");
+    std::fs::write(&file_path, "Test report").unwrap();
+
+    jira_data_guard.upload_attachment(&file_path).unwrap();
+
+    assert_eq!(
+        server
+            .requests_to("POST", "/rest/api/2/issue/TST-1/attachments")
+            .len(),
+        1
+    );
+    assert!(server
+        .requests_to("GET", "/rest/api/2/issue/TST-1")
+        .is_empty());
+}
+
+#[test]
+fn test_my_work() {
+    let server = MockJiraServer::start();
+    let offline_server = MockJiraServer::start_with_routes(Vec::new());
+    let jira_data = make_jira_data(&server);
+    let jira_data_guard = jira_data.read().unwrap();
+
+    let my_work = MyWork::load(
+        &jira_data_guard.thread_pool,
+        vec![
+            ("Acme".into(), make_client(&server)),
+            ("Offline".into(), make_client(&offline_server)),
+        ],
+    );
+
+    let issues: Vec<(&str, &str)> = my_work
+        .issues
+        .iter()
+        .map(|issue| (issue.company_name.as_str(), issue.issue.key.as_str()))
+        .collect();
+    assert_eq!(issues, vec![("Acme", "EMP-1"), ("Acme", "TST-1")]);
+    assert_eq!(my_work.failed_companies, vec!["Offline"]);
+}
+
+#[test]
+fn test_favourite_filters() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut jira_data_guard = jira_data.write().unwrap();
+
+    let bugs_filter =
+        JqlFilter::new("Open bugs", "project = TST AND issuetype = Bug");
+    assert_eq!(jira_data_guard.get_favourite_filters(), &[bugs_filter]);
+    jira_data_guard.get_favourite_filters();
+    assert_eq!(
+        server
+            .requests_to("GET", "/rest/api/2/filter/favourite")
+            .len(),
+        1
+    );
+
+    let offline_server = MockJiraServer::start_with_routes(Vec::new());
+    let offline_data = make_jira_data(&offline_server);
+    assert!(offline_data
+        .write()
+        .unwrap()
+        .get_favourite_filters()
+        .is_empty());
+}
+
+#[test]
+fn test_apply_updated_issues() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    {
+        let mut jira_data_guard = jira_data.write().unwrap();
+        select_test_project(&mut jira_data_guard);
+        jira_data_guard.set_selected_task("TST-3");
+    }
+    let load_updated = || UpdatedIssues::load(&jira_data, 5).unwrap();
+
+    let updated_issues = load_updated();
+    let requests_count = server.requests().len();
+    let mut jira_data_guard = jira_data.write().unwrap();
+    let summary = jira_data_guard.apply_updated_issues(updated_issues);
+    assert_eq!(server.requests().len(), requests_count);
+    assert_eq!(
+        summary,
+        RefreshSummary {
+            assigned: vec!["TST-2".into()],
+            updated: vec!["TST-3".into(), "TST-7".into()],
+            is_selected_changed: true,
+            is_list_changed: true,
+        }
+    );
+    let selected_task = jira_data_guard.get_selected_task();
+    assert_eq!(selected_task.summary, "Upgrade dependencies to 2023");
+    assert!(selected_task.transitions.is_some());
+    let label = jira_data_guard.get_task_label("TST-7 -- Add dark mode", &[]);
+    assert!(label.source().starts_with("* "));
+    let label = jira_data_guard.get_task_label("TST-1 -- Fix login page", &[]);
+    assert!(!label.source().starts_with("* "));
+
+    assert!(jira_data_guard.mark_issue_seen("TST-7"));
+    assert!(!jira_data_guard.mark_issue_seen("TST-7"));
+    drop(jira_data_guard);
+    let updated_issues = load_updated();
+    assert!(jira_data
+        .write()
+        .unwrap()
+        .apply_updated_issues(updated_issues)
+        .is_empty());
+}
+
+#[test]
+fn test_apply_updated_issues_keeps_failed_data() {
+    let server = MockJiraServer::start();
+    let changelog_path = "/rest/api/2/issue/TST-3/changelog";
+    server.mock(MockRoute::new(
+        "GET",
+        changelog_path,
+        200,
+        &super::mock_server::read_fixture("changelog_tst_1_page_1.json"),
+    ));
+    let jira_data = make_jira_data(&server);
+    {
+        let mut jira_data_guard = jira_data.write().unwrap();
+        select_test_project(&mut jira_data_guard);
+        jira_data_guard.set_selected_task("TST-3");
+        jira_data_guard.update_selected_task_transitions();
+        jira_data_guard.toggle_history_tab().unwrap();
+    }
+    server.mock(MockRoute::new("GET", changelog_path, 500, ""));
+    server.mock(MockRoute::new(
+        "GET",
+        "/rest/api/2/issue/TST-3/transitions",
+        500,
+        "",
+    ));
+
+    let updated_issues = UpdatedIssues::load(&jira_data, 5).unwrap();
+    let mut jira_data_guard = jira_data.write().unwrap();
+    let summary = jira_data_guard.apply_updated_issues(updated_issues);
+
+    assert!(summary.is_selected_changed);
+    let selected_task = jira_data_guard.get_selected_task();
+    assert_eq!(selected_task.summary, "Upgrade dependencies to 2023");
+    assert!(selected_task.transitions.is_some());
+    assert!(selected_task.changelog.is_some());
+}
+
+#[test]
+fn test_updated_issues_of_loaded_projects() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+
+    let updated_issues = UpdatedIssues::load(&jira_data, 5).unwrap();
+    assert_eq!(updated_issues.issues.into_iter().count(), 0);
+    assert!(server.requests_to("GET", "/rest/api/2/search").is_empty());
+
+    select_test_project(&mut jira_data.write().unwrap());
+    assert_eq!(
+        jira_data.read().unwrap().get_loaded_project_keys(),
+        vec!["TST"]
+    );
+    assert_eq!(
+        updated_issues_jql(5, &["TST".into(), "EMP".into()]),
+        "project in (\"TST\", \"EMP\") AND updated >= -5m \
+        ORDER BY updated DESC"
+    );
+}
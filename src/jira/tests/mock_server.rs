@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use serde::Deserialize;
+use url::form_urlencoded;
+
+/// Name of the file with default routes in the fixtures directory.
+const ROUTES_FIXTURE: &str = "routes.json";
+
+/// Returns path to the directory with fixture files.
+pub fn fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+}
+
+/// Reads fixture file by its name.
+pub fn read_fixture(fixture_name: &str) -> String {
+    std::fs::read_to_string(fixtures_path().join(fixture_name))
+        .unwrap_or_else(|_| panic!("Can't read fixture {fixture_name}"))
+}
+
+/// Single route of the mock server.
+///
+/// Route matches a request if method and path are equal
+/// and every pair from `query` is present in the request query.
+#[derive(Deserialize, Clone, Debug)]
+pub struct MockRoute {
+    method: String,
+    path: String,
+    #[serde(default)]
+    query: HashMap<String, String>,
+    #[serde(default = "default_status")]
+    status: u16,
+    #[serde(default)]
+    fixture: Option<String>,
+    #[serde(skip)]
+    body: Option<String>,
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+impl MockRoute {
+    /// Creates new route with inline body.
+    pub fn new(method: &str, path: &str, status: u16, body: &str) -> Self {
+        Self {
+            method: method.into(),
+            path: path.into(),
+            query: HashMap::default(),
+            status,
+            fixture: None,
+            body: Some(body.into()),
+        }
+    }
+
+    /// Adds query pair that must be in the request.
+    pub fn with_query(mut self, key: &str, value: &str) -> Self {
+        self.query.insert(key.into(), value.into());
+        self
+    }
+
+    fn is_match(&self, request: &RecordedRequest) -> bool {
+        self.method == request.method
+            && self.path == request.path
+            && self.query.iter().all(|(key, value)| {
+                request.query.get(key).map(|v| v == value).unwrap_or(false)
+            })
+    }
+
+    fn response_body(&self) -> String {
+        match (&self.body, &self.fixture) {
+            (Some(body), _) => body.clone(),
+            (None, Some(fixture)) => read_fixture(fixture),
+            (None, None) => String::default(),
+        }
+    }
+}
+
+/// Request received by the mock server.
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+/// Local stand-in for Jira REST API.
+///
+/// Listens on random local port and answers with fixture files
+/// described in `tests/fixtures/routes.json`.
+/// Every received request is recorded and can be checked in tests.
+pub struct MockJiraServer {
+    url: String,
+    routes: Arc<Mutex<Vec<MockRoute>>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockJiraServer {
+    /// Starts new server with routes from the fixtures directory.
+    pub fn start() -> Self {
+        let routes = serde_json::from_str::<Vec<MockRoute>>(&read_fixture(
+            ROUTES_FIXTURE,
+        ))
+        .unwrap();
+        Self::start_with_routes(routes)
+    }
+
+    /// Starts new server with passed routes only.
+    pub fn start_with_routes(routes: Vec<MockRoute>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let routes = Arc::new(Mutex::new(routes));
+        let requests = Arc::new(Mutex::new(Vec::default()));
+
+        let thread_routes = routes.clone();
+        let thread_requests = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                Self::handle_connection(
+                    stream,
+                    &thread_routes,
+                    &thread_requests,
+                );
+            }
+        });
+
+        Self {
+            url,
+            routes,
+            requests,
+        }
+    }
+
+    /// Returns base url of the server.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Adds new route.
+    ///
+    /// Routes added later take precedence over earlier ones.
+    pub fn mock(&self, route: MockRoute) {
+        self.routes.lock().unwrap().push(route);
+    }
+
+    /// Returns all requests received by the server.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Returns received requests with passed method and path.
+    pub fn requests_to(
+        &self,
+        method: &str,
+        path: &str,
+    ) -> Vec<RecordedRequest> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request.method == method && request.path == path)
+            .collect()
+    }
+
+    fn handle_connection(
+        mut stream: TcpStream,
+        routes: &Mutex<Vec<MockRoute>>,
+        requests: &Mutex<Vec<RecordedRequest>>,
+    ) {
+        let request = match Self::read_request(&stream) {
+            Some(request) => request,
+            None => return,
+        };
+
+        let route = routes
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|route| route.is_match(&request))
+            .cloned();
+        requests.lock().unwrap().push(request);
+
+        let (status, body) = match route {
+            Some(route) => (route.status, route.response_body()),
+            None => (404, read_fixture("error_issue_not_found.json")),
+        };
+
+        let response = format!(
+            "HTTP/1.1 {status} MOCK\r\n\
+            Content-Type: application/json\r\n\
+            Content-Length: {}\r\n\
+            Connection: close\r\n\r\n{body}",
+            body.len(),
+        );
+        let _ = stream.write_all(response.as_bytes());
+    }
+
+    fn read_request(stream: &TcpStream) -> Option<RecordedRequest> {
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::default();
+        reader.read_line(&mut request_line).ok()?;
+        let mut request_line_parts = request_line.split_whitespace();
+        let method = request_line_parts.next()?.to_string();
+        let target = request_line_parts.next()?.to_string();
+
+        let mut headers = HashMap::default();
+        loop {
+            let mut header_line = String::default();
+            reader.read_line(&mut header_line).ok()?;
+            let header_line = header_line.trim_end();
+            if header_line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header_line.split_once(':') {
+                headers
+                    .insert(name.trim().to_lowercase(), value.trim().into());
+            }
+        }
+
+        let content_length = headers
+            .get("content-length")
+            .and_then(|length: &String| length.parse::<usize>().ok())
+            .unwrap_or_default();
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).ok()?;
+
+        let (path, raw_query) =
+            target.split_once('?').unwrap_or((&target, ""));
+        let query = form_urlencoded::parse(raw_query.as_bytes())
+            .into_owned()
+            .collect();
+
+        Some(RecordedRequest {
+            method,
+            path: path.into(),
+            query,
+            headers,
+            body: String::from_utf8_lossy(&body).into(),
+        })
+    }
+}
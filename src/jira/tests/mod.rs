@@ -0,0 +1,78 @@
+//! End-to-end tests against the local mock Jira server.
+//!
+//! Fixtures for the server live in `tests/fixtures`.
+mod jira_data;
+pub mod mock_server;
+mod request_client;
+mod tui;
+
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use crate::{
+    config::ApiVersion, jira_data::JiraData,
+    request_client::client::RequestClient,
+};
+
+use mock_server::{MockJiraServer, MockRoute};
+
+/// Base64 encoded `test:test`.
+const TEST_CREDENTIALS: &str = "dGVzdDp0ZXN0";
+
+/// Creates `RequestClient` for API version 2 pointed to the mock server.
+fn make_client(server: &MockJiraServer) -> Arc<RwLock<RequestClient>> {
+    make_client_with_version(server, ApiVersion::V2)
+}
+
+/// Creates `RequestClient` pointed to the mock server.
+///
+/// Mock server reports Jira Server, see `mock_cloud_server_info`.
+fn make_client_with_version(
+    server: &MockJiraServer,
+    api_version: ApiVersion,
+) -> Arc<RwLock<RequestClient>> {
+    let mut client =
+        RequestClient::new(TEST_CREDENTIALS.into(), server.url(), api_version);
+    client.detect_deployment_type();
+    Arc::new(RwLock::new(client))
+}
+
+/// Makes the mock server report Jira Cloud in `serverInfo`.
+fn mock_cloud_server_info(server: &MockJiraServer) {
+    server.mock(MockRoute::new(
+        "GET",
+        "/rest/api/2/serverInfo",
+        200,
+        &mock_server::read_fixture("server_info_cloud.json"),
+    ));
+}
+
+/// Creates `JiraData` for API version 2 with projects
+/// loaded from the mock server.
+fn make_jira_data(server: &MockJiraServer) -> Arc<RwLock<JiraData>> {
+    make_jira_data_with_version(server, ApiVersion::V2)
+}
+
+/// Creates `JiraData` with projects and fields loaded
+/// from the mock server.
+fn make_jira_data_with_version(
+    server: &MockJiraServer,
+    api_version: ApiVersion,
+) -> Arc<RwLock<JiraData>> {
+    let mut jira_data =
+        JiraData::new(server.url(), TEST_CREDENTIALS, api_version);
+    jira_data.load_projects_and_fields();
+    Arc::new(RwLock::new(jira_data))
+}
+
+/// Creates empty temporary directory unique for the test.
+fn make_temp_dir(test_name: &str) -> PathBuf {
+    let temp_dir = std::env::temp_dir().join(format!(
+        "rusji-{}-{}",
+        test_name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    temp_dir
+}
@@ -0,0 +1,770 @@
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use serde_json::{json, Value};
+
+use crate::{
+    config::ApiVersion,
+    errors::RusjiError,
+    jira::{
+        adf::convert::text_to_request_body,
+        projects::data::JiraProjects,
+        tasks::{
+            changelog::{ChangelogFilter, IssueChangelog},
+            data::{JiraIssue, JiraIssues},
+        },
+        tasks_actions::data::{JiraUsers, UserIdentity},
+    },
+    request_client::client::{redact_headers, RequestClient},
+};
+
+use super::{
+    make_client, make_client_with_version, make_temp_dir,
+    mock_cloud_server_info,
+    mock_server::{MockJiraServer, MockRoute},
+    TEST_CREDENTIALS,
+};
+
+#[test]
+fn test_get_projects() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let projects: Vec<String> = JiraProjects::new(client)
+        .unwrap()
+        .into_iter()
+        .map(|project| project.key)
+        .collect();
+
+    assert_eq!(projects, vec!["TST", "EMP"]);
+}
+
+#[test]
+fn test_default_headers() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    client.read().unwrap().get_jira_projects().unwrap();
+
+    let request = &server.requests_to("GET", "/rest/api/2/project")[0];
+    assert_eq!(
+        request.headers.get("authorization").unwrap(),
+        &format!("Basic {TEST_CREDENTIALS}"),
+    );
+    assert_eq!(
+        request.headers.get("content-type").unwrap(),
+        "application/json",
+    );
+}
+
+#[test]
+fn test_tasks_pagination() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let issues: Vec<String> = JiraIssues::new(client, "TST")
+        .unwrap()
+        .into_iter()
+        .map(|issue| issue.key)
+        .collect();
+
+    assert_eq!(issues, vec!["TST-1", "TST-2", "TST-3"]);
+
+    let start_at: Vec<String> = server
+        .requests_to("GET", "/rest/api/2/search")
+        .into_iter()
+        .map(|request| request.query.get("startAt").unwrap().clone())
+        .collect();
+    assert_eq!(start_at, vec!["0", "2"]);
+}
+
+#[test]
+fn test_tasks_empty_project() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let issues = JiraIssues::new(client, "EMP").unwrap();
+
+    assert_eq!(issues.into_iter().count(), 0);
+    assert_eq!(server.requests_to("GET", "/rest/api/2/search").len(), 1);
+}
+
+#[test]
+fn test_get_task() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let issue = JiraIssue::new(client, "TST-1").unwrap();
+
+    assert_eq!(issue.summary, "Fix login page");
+    assert_eq!(issue.status.name, "In Progress");
+    assert_eq!(issue.description, "<p>Login page fails on submit</p>");
+    assert_eq!(
+        issue.assignee.unwrap().identity(),
+        UserIdentity::Name("alice".into()),
+    );
+}
+
+#[test]
+fn test_get_issue_changelog() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let changelog = IssueChangelog::new(client.clone(), "TST-1").unwrap();
+
+    assert_eq!(changelog.pages_count(ChangelogFilter::All), 3);
+    assert_eq!(
+        changelog.entries(ChangelogFilter::All, 0)[0],
+        "2023-01-24 10:00 | Bob Roe | assignee: Bob Roe → Alice Doe"
+    );
+    let start_at: Vec<String> = server
+        .requests_to("GET", "/rest/api/2/issue/TST-1/changelog")
+        .into_iter()
+        .map(|request| request.query.get("startAt").unwrap().clone())
+        .collect();
+    assert_eq!(start_at, vec!["0", "12"]);
+
+    // Status changes of both loaded pages are shown together.
+    assert_eq!(changelog.pages_count(ChangelogFilter::Status), 2);
+    let status_entries = changelog.entries(ChangelogFilter::Status, 0);
+    assert_eq!(status_entries.len(), 10);
+    assert!(status_entries.iter().all(|entry| entry.contains("status:")));
+    assert_eq!(
+        changelog.entries(ChangelogFilter::Status, 1),
+        vec![
+            "2023-01-03 10:00 | Alice Doe | status: In Progress → To Do",
+            "2023-01-01 10:00 | Alice Doe | status: To Do → In Progress",
+        ]
+    );
+
+    assert!(JiraIssue::new(client, "TST-1").unwrap().changelog.is_none());
+    let request = &server.requests_to("GET", "/rest/api/2/issue/TST-1")[0];
+    assert_eq!(request.query.get("expand").unwrap(), "renderedFields");
+}
+
+#[test]
+fn test_get_issue_changelog_without_endpoint() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let changelog = IssueChangelog::new(client, "TST-4").unwrap();
+
+    assert_eq!(
+        changelog.entries(ChangelogFilter::All, 0),
+        vec!["2022-12-01 09:30 | Bob Roe | status: In Progress → Done"]
+    );
+    assert_eq!(
+        server
+            .requests_to("GET", "/rest/api/2/issue/TST-4/changelog")
+            .len(),
+        1
+    );
+    let issue_requests = server.requests_to("GET", "/rest/api/2/issue/TST-4");
+    assert_eq!(issue_requests[0].query["expand"], "changelog");
+}
+
+#[test]
+fn test_get_task_not_found() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let issue = JiraIssue::new(client, "TST-404");
+
+    assert!(matches!(issue, Err(RusjiError::BadResponseStatus(404))));
+}
+
+#[test]
+fn test_server_error() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new("GET", "/rest/api/2/project", 500, "{}"));
+    let client = make_client(&server);
+
+    let projects = JiraProjects::new(client);
+
+    assert!(matches!(projects, Err(RusjiError::BadResponseStatus(500))));
+}
+
+#[test]
+fn test_broken_json() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new("GET", "/rest/api/2/project", 200, "[{"));
+    let client = make_client(&server);
+
+    let projects = JiraProjects::new(client);
+
+    assert!(matches!(projects, Err(RusjiError::SerializeError(_))));
+}
+
+#[test]
+fn test_connection_error() {
+    let client = RequestClient::new(
+        TEST_CREDENTIALS.into(),
+        "http://127.0.0.1:1",
+        ApiVersion::V2,
+    );
+
+    let projects = client.get_jira_projects();
+
+    assert!(matches!(projects, Err(RusjiError::RequestError(_))));
+}
+
+#[test]
+fn test_get_users() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let response = client.read().unwrap().get_jira_users("a").unwrap();
+    let users: Vec<String> =
+        serde_json::from_str::<JiraUsers>(response.get_body())
+            .unwrap()
+            .into_iter()
+            .map(|user| user.display_name)
+            .collect();
+
+    assert_eq!(users, vec!["Alice Doe", "Bob Roe"]);
+    let request = &server.requests_to("GET", "/rest/api/2/user/search")[0];
+    assert_eq!(request.query.get("username").unwrap(), "a");
+}
+
+#[test]
+fn test_get_users_encodes_username() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let _ = client.read().unwrap().get_jira_users("a&b #c");
+
+    let request = &server.requests_to("GET", "/rest/api/2/user/search")[0];
+    assert_eq!(request.query.get("username").unwrap(), "a&b #c");
+}
+
+#[test]
+fn test_detect_deployment_type() {
+    let server = MockJiraServer::start();
+
+    assert!(!make_client(&server).read().unwrap().is_cloud());
+    assert!(!make_client_with_version(&server, ApiVersion::V3)
+        .read()
+        .unwrap()
+        .is_cloud());
+    mock_cloud_server_info(&server);
+    assert!(make_client(&server).read().unwrap().is_cloud());
+    assert!(make_client_with_version(&server, ApiVersion::V3)
+        .read()
+        .unwrap()
+        .is_cloud());
+
+    // `serverInfo` of version 2 is used whatever version is configured.
+    assert!(server
+        .requests_to("GET", "/rest/api/3/serverInfo")
+        .is_empty());
+}
+
+#[test]
+fn test_detect_deployment_type_failed() {
+    let server = MockJiraServer::start_with_routes(Vec::new());
+    let client = make_client(&server);
+
+    assert!(!client.read().unwrap().is_cloud());
+}
+
+#[test]
+fn test_get_users_cloud() {
+    let server = MockJiraServer::start();
+    mock_cloud_server_info(&server);
+    let client = make_client_with_version(&server, ApiVersion::V3);
+
+    let response = client.read().unwrap().get_jira_users("b").unwrap();
+    let users: Vec<(String, String)> =
+        serde_json::from_str::<JiraUsers>(response.get_body())
+            .unwrap()
+            .into_iter()
+            .map(|user| (user.identity().as_str().into(), user.display_text()))
+            .collect();
+
+    assert_eq!(
+        users,
+        vec![
+            ("5b10a2844c20165700ede21g".into(), "Alice Doe".into()),
+            (
+                "5b10ac8d82e05b22cc7d4ef5".into(),
+                "Bob Roe | bob@test.atlassian.net".into()
+            ),
+        ]
+    );
+    let request = &server.requests_to("GET", "/rest/api/3/user/search")[0];
+    assert_eq!(request.query.get("query").unwrap(), "b");
+    assert!(!request.query.contains_key("username"));
+}
+
+#[test]
+fn test_update_transition() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    client
+        .read()
+        .unwrap()
+        .update_task_transition("TST-1", "21")
+        .unwrap();
+
+    let request =
+        &server.requests_to("POST", "/rest/api/2/issue/TST-1/transitions")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(body, serde_json::json!({"transition": {"id": "21"}}));
+}
+
+#[test]
+fn test_update_assignee() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    client
+        .read()
+        .unwrap()
+        .update_issue_assignee(
+            Some(&UserIdentity::Name("bob".into())),
+            "TST-1",
+        )
+        .unwrap();
+
+    let request = &server.requests_to("PUT", "/rest/api/2/issue/TST-1")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({"fields": {"assignee": {"name": "bob"}}})
+    );
+}
+
+#[test]
+fn test_update_assignee_cloud() {
+    let server = MockJiraServer::start();
+    mock_cloud_server_info(&server);
+    let client = make_client_with_version(&server, ApiVersion::V3);
+
+    client
+        .read()
+        .unwrap()
+        .update_issue_assignee(
+            Some(&UserIdentity::AccountId("5b10ac8d82e05b22cc7d4ef5".into())),
+            "TST-5",
+        )
+        .unwrap();
+
+    let request = &server.requests_to("PUT", "/rest/api/3/issue/TST-5")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "fields": {"assignee": {"accountId": "5b10ac8d82e05b22cc7d4ef5"}}
+        })
+    );
+}
+
+#[test]
+fn test_update_assignee_cloud_detection_failed() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new("GET", "/rest/api/2/serverInfo", 503, ""));
+    let client = make_client_with_version(&server, ApiVersion::V3);
+    assert!(!client.read().unwrap().is_cloud());
+
+    client
+        .read()
+        .unwrap()
+        .update_issue_assignee(
+            Some(&UserIdentity::AccountId("5b10ac8d82e05b22cc7d4ef5".into())),
+            "TST-5",
+        )
+        .unwrap();
+
+    let request = &server.requests_to("PUT", "/rest/api/3/issue/TST-5")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "fields": {"assignee": {"accountId": "5b10ac8d82e05b22cc7d4ef5"}}
+        })
+    );
+}
+
+#[test]
+fn test_unassign() {
+    let server = MockJiraServer::start();
+
+    make_client(&server)
+        .read()
+        .unwrap()
+        .update_issue_assignee(None, "TST-1")
+        .unwrap();
+    mock_cloud_server_info(&server);
+    make_client_with_version(&server, ApiVersion::V3)
+        .read()
+        .unwrap()
+        .update_issue_assignee(None, "TST-5")
+        .unwrap();
+
+    let request = &server.requests_to("PUT", "/rest/api/2/issue/TST-1")[0];
+    assert_eq!(request.body, r#"{"fields":{"assignee":{"name":null}}}"#);
+    let request = &server.requests_to("PUT", "/rest/api/3/issue/TST-5")[0];
+    assert_eq!(
+        request.body,
+        r#"{"fields":{"assignee":{"accountId":null}}}"#
+    );
+}
+
+#[test]
+fn test_get_assignable_users() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let response = client.read().unwrap().get_assignable_users("TST").unwrap();
+    let users: Vec<String> =
+        serde_json::from_str::<JiraUsers>(response.get_body())
+            .unwrap()
+            .into_iter()
+            .map(|user| user.display_text())
+            .collect();
+
+    assert_eq!(
+        users,
+        vec!["Bob Roe | bob@jira.test", "Carol Poe | carol@jira.test"]
+    );
+}
+
+#[test]
+fn test_issue_relations() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let issue = JiraIssue::new(client, "TST-1").unwrap();
+    let relations: Vec<String> = issue
+        .relations(Some("customfield_10101"))
+        .iter()
+        .map(|relation| relation.display_text())
+        .collect();
+
+    assert_eq!(
+        relations,
+        vec![
+            "epic: TST-3",
+            "subtask: TST-6 -- Add login tests [To Do]",
+            "blocks: TST-2 -- Write release notes [To Do]",
+            "is blocked by: EMP-1 -- Prepare empty project [To Do]",
+        ]
+    );
+}
+
+#[test]
+fn test_issue_attachments() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let issue = JiraIssue::new(client, "TST-1").unwrap();
+    let attachments: Vec<String> = issue
+        .attachments
+        .iter()
+        .map(|attachment| attachment.display_text())
+        .collect();
+
+    assert_eq!(
+        attachments,
+        vec![
+            "notes.txt (22 B) | Bob Roe | 2023-01-05 14:30",
+            "screenshot.png (150.0 KB) | Alice Doe | 2023-01-06 09:05",
+        ]
+    );
+}
+
+#[test]
+fn test_download_attachment() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let content = client
+        .read()
+        .unwrap()
+        .download_attachment(
+            "http://jira.test/secure/attachment/40001/notes.txt",
+        )
+        .unwrap();
+
+    assert_eq!(content, b"Steps to reproduce: 1\n");
+    let request =
+        &server.requests_to("GET", "/secure/attachment/40001/notes.txt")[0];
+    assert_eq!(
+        request.headers.get("authorization").unwrap(),
+        &format!("Basic {TEST_CREDENTIALS}"),
+    );
+}
+
+#[test]
+fn test_download_attachment_not_found() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    let result = client
+        .read()
+        .unwrap()
+        .download_attachment("/secure/attachment/40404/missing.txt");
+
+    assert!(matches!(result, Err(RusjiError::BadResponseStatus(404))));
+}
+
+#[test]
+fn test_upload_attachment() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+    let file_path = make_temp_dir("upload-attachment").join("report.txt");
+    std::fs::write(&file_path, "Test report").unwrap();
+
+    client
+        .read()
+        .unwrap()
+        .upload_attachment("TST-1", &file_path)
+        .unwrap();
+
+    let request =
+        &server.requests_to("POST", "/rest/api/2/issue/TST-1/attachments")[0];
+    assert_eq!(
+        request.headers.get("x-atlassian-token").unwrap(),
+        "no-check"
+    );
+    assert!(request
+        .headers
+        .get("content-type")
+        .unwrap()
+        .starts_with("multipart/form-data"));
+    assert!(request
+        .body
+        .contains(r#"name="file"; filename="report.txt""#));
+    assert!(request.body.contains("Test report"));
+}
+
+#[test]
+fn test_upload_missing_file() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+    let file_path = make_temp_dir("upload-missing").join("missing.txt");
+
+    let result = client
+        .read()
+        .unwrap()
+        .upload_attachment("TST-1", &file_path);
+
+    assert!(matches!(result, Err(RusjiError::FileError(_))));
+}
+
+#[test]
+fn test_create_issue_link() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    client
+        .read()
+        .unwrap()
+        .create_issue_link("Blocks", "TST-1", "TST-3")
+        .unwrap();
+
+    let request = &server.requests_to("POST", "/rest/api/2/issueLink")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "type": {"name": "Blocks"},
+            "inwardIssue": {"key": "TST-1"},
+            "outwardIssue": {"key": "TST-3"}
+        })
+    );
+}
+
+#[test]
+fn test_delete_issue_link() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    client.read().unwrap().delete_issue_link("30001").unwrap();
+
+    assert_eq!(
+        server
+            .requests_to("DELETE", "/rest/api/2/issueLink/30001")
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_update_story_points() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    client
+        .read()
+        .unwrap()
+        .update_issue_story_points(5, "TST-1")
+        .unwrap();
+
+    assert_eq!(
+        server
+            .requests_to("GET", "/rest/api/2/issue/TST-1/editmeta")
+            .len(),
+        1,
+    );
+    let request = &server.requests_to("PUT", "/rest/api/2/issue/TST-1")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({"fields": {"customfield_10106": 5}})
+    );
+}
+
+#[test]
+fn test_update_rejected() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new(
+        "PUT",
+        "/rest/api/2/issue/TST-1",
+        400,
+        r#"{"errorMessages":[],"errors":{"assignee":"User 'nobody' does not exist."}}"#,
+    ));
+    let client = make_client(&server);
+
+    let response = client.read().unwrap().update_issue_assignee(
+        Some(&UserIdentity::Name("nobody".into())),
+        "TST-1",
+    );
+
+    assert!(matches!(response, Err(RusjiError::BadResponseStatus(400))));
+}
+
+#[test]
+fn test_api_version_3() {
+    let server = MockJiraServer::start();
+    let client = make_client_with_version(&server, ApiVersion::V3);
+
+    JiraProjects::new(client.clone()).unwrap();
+    let issue = JiraIssue::new(client, "TST-5").unwrap();
+
+    assert_eq!(server.requests_to("GET", "/rest/api/3/project").len(), 1);
+    assert_eq!(
+        issue.description_adf().unwrap()["content"][0]["type"],
+        "heading"
+    );
+    assert_eq!(
+        issue.description_text(),
+        "## Scope\n\n\
+        Ask @Alice Doe for review\n\n\
+        - endpoints\n\
+        - errors\n\n\
+        ┃ INFO\n\
+        ┃ Public docs only",
+    );
+}
+
+#[test]
+fn test_add_comment() {
+    let server = MockJiraServer::start();
+    let client = make_client(&server);
+
+    client
+        .read()
+        .unwrap()
+        .add_issue_comment(Value::String("Looks good".into()), "TST-1")
+        .unwrap();
+
+    let request =
+        &server.requests_to("POST", "/rest/api/2/issue/TST-1/comment")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(body, serde_json::json!({"body": "Looks good"}));
+}
+
+#[test]
+fn test_add_comment_adf() {
+    let server = MockJiraServer::start();
+    let client = make_client_with_version(&server, ApiVersion::V3);
+
+    let client_guard = client.read().unwrap();
+    client_guard
+        .add_issue_comment(
+            text_to_request_body("Looks good", client_guard.is_adf_api()),
+            "TST-5",
+        )
+        .unwrap();
+
+    let request =
+        &server.requests_to("POST", "/rest/api/3/issue/TST-5/comment")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({"body": {
+            "type": "doc",
+            "version": 1,
+            "content": [{"type": "paragraph", "content": [
+                {"type": "text", "text": "Looks good"}
+            ]}]
+        }})
+    );
+}
+
+#[test]
+fn test_update_description_adf() {
+    let server = MockJiraServer::start();
+    let client = make_client_with_version(&server, ApiVersion::V3);
+
+    let client_guard = client.read().unwrap();
+    client_guard
+        .update_issue_description(
+            text_to_request_body("- one", client_guard.is_adf_api()),
+            "TST-5",
+        )
+        .unwrap();
+
+    let request = &server.requests_to("PUT", "/rest/api/3/issue/TST-5")[0];
+    let body = serde_json::from_str::<Value>(&request.body).unwrap();
+    assert_eq!(
+        body["fields"]["description"]["content"][0]["type"],
+        "bulletList"
+    );
+}
+
+#[test]
+fn test_browse_url() {
+    let client = RequestClient::new(
+        TEST_CREDENTIALS.into(),
+        "https://jira.example.com/",
+        ApiVersion::V2,
+    );
+
+    assert_eq!(
+        client.browse_url("TST-1").as_str(),
+        "https://jira.example.com/browse/TST-1"
+    );
+}
+
+#[test]
+fn test_redact_headers() {
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, HeaderValue::from_static("Basic dGVzdA=="));
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    assert_eq!(
+        redact_headers(&headers),
+        json!({
+            "authorization": "<redacted>",
+            "content-type": "application/json",
+        })
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new(
+        "GET",
+        "/rest/api/2/project",
+        200,
+        r#"{"projects": []}"#,
+    ));
+    let client = make_client(&server);
+
+    assert!(matches!(
+        JiraProjects::new(client),
+        Err(RusjiError::SerializeError(_))
+    ));
+}
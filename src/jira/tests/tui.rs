@@ -0,0 +1,1041 @@
+use crossbeam_channel::{Receiver, Sender};
+use cursive::{
+    backends::puppet::{observed::ObservedScreen, Backend},
+    event::{Event, Key},
+    CursiveRunner, Vec2,
+};
+
+use std::sync::{Arc, RwLock};
+
+use crate::{
+    config::ApiVersion,
+    jira::{
+        auto_refresh::{data::UpdatedIssues, views::apply_updated_issues},
+        filters::data::JqlFilter,
+        my_work::{data::MyWork, views::MyWorkView},
+        screen::{
+            close_jira_screen, make_jira_screen_with_data, save_session,
+        },
+        tasks::views::InfoView,
+    },
+    jira_data::JiraData,
+    keymap::Keymap,
+    state::SessionState,
+};
+
+use super::{
+    make_client, make_jira_data, make_jira_data_with_version, make_temp_dir,
+    mock_server::{MockJiraServer, MockRoute},
+};
+
+/// Cursive app with puppet backend around the Jira screen.
+struct TuiHarness {
+    runner: CursiveRunner<cursive::Cursive>,
+    input: Sender<Option<Event>>,
+    output: Receiver<ObservedScreen>,
+    last_screen: Option<ObservedScreen>,
+}
+
+impl TuiHarness {
+    fn start(server: &MockJiraServer) -> Self {
+        Self::start_with_version(server, ApiVersion::V2)
+    }
+
+    fn start_with_version(
+        server: &MockJiraServer,
+        api_version: ApiVersion,
+    ) -> Self {
+        Self::start_with_keymap(server, api_version, Keymap::default())
+    }
+
+    fn start_with_keymap(
+        server: &MockJiraServer,
+        api_version: ApiVersion,
+        keymap: Keymap,
+    ) -> Self {
+        let jira_data = make_jira_data_with_version(server, api_version);
+        jira_data.write().unwrap().keymap = keymap;
+        Self::start_with_data(jira_data)
+    }
+
+    fn start_with_data(jira_data: Arc<RwLock<JiraData>>) -> Self {
+        let backend = Backend::init(Some(Vec2::new(160, 48)));
+        let input = backend.input();
+        let output = backend.stream();
+        let mut runner = cursive::Cursive::new().into_runner(backend);
+        // Layout is necessary to know the screen size.
+        runner.refresh();
+        make_jira_screen_with_data(&mut runner, jira_data);
+        runner.refresh();
+
+        let mut harness = Self {
+            runner,
+            input,
+            output,
+            last_screen: None,
+        };
+        harness.read_screen();
+        harness
+    }
+
+    /// Sends event to the app and processes it.
+    fn press<E: Into<Event>>(&mut self, event: E) {
+        self.input.send(Some(event.into())).unwrap();
+        self.runner.step();
+        self.runner.refresh();
+        self.read_screen();
+    }
+
+    /// Types every char of the `text`.
+    fn type_text(&mut self, text: &str) {
+        for char in text.chars() {
+            self.press(char);
+        }
+    }
+
+    fn read_screen(&mut self) {
+        if let Some(screen) = self.output.try_iter().last() {
+            self.last_screen = Some(screen);
+        }
+    }
+
+    /// Checks if the text is visible on the screen.
+    fn is_visible(&self, text: &str) -> bool {
+        !self
+            .last_screen
+            .as_ref()
+            .unwrap()
+            .find_occurences(text)
+            .is_empty()
+    }
+
+    /// Opens `TST-1` task of the `Test project`.
+    fn open_test_task(&mut self) {
+        self.open_test_project();
+        self.type_text("TST-1");
+        self.press(Key::Down);
+        self.press(Key::Enter);
+    }
+
+    /// Opens `Test project` and waits for its tasks.
+    fn open_test_project(&mut self) {
+        self.type_text("Test");
+        self.press(Key::Down);
+        self.press(Key::Enter);
+    }
+}
+
+#[test]
+fn test_projects_are_shown() {
+    let server = MockJiraServer::start();
+    let harness = TuiHarness::start(&server);
+
+    assert!(harness.is_visible("Test project"));
+    assert!(harness.is_visible("Empty project"));
+    assert!(harness.is_visible("a - task actions"));
+}
+
+#[test]
+fn test_projects_search() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.type_text("Empt");
+
+    assert!(harness.is_visible("Empty project"));
+    assert!(!harness.is_visible("Test project"));
+}
+
+#[test]
+fn test_select_project_and_task() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+    assert!(harness.is_visible("TST-2 -- Write release notes"));
+    assert!(harness.is_visible("TST-3 -- Upgrade dependencies"));
+
+    harness.type_text("TST-1");
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+
+    assert!(harness.is_visible("Status - In Progress"));
+    assert!(harness.is_visible("Assignee - alice"));
+    assert!(harness.is_visible("Login page fails on submit"));
+    assert_eq!(
+        server
+            .requests_to("GET", "/rest/api/2/issue/TST-1/transitions")
+            .len(),
+        1,
+    );
+}
+
+#[test]
+fn test_tasks_sort_and_group() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+    harness.open_test_project();
+    harness.press(Key::Down);
+    assert!(harness.is_visible("Choose issue (key)"));
+
+    harness.press('s');
+    assert!(harness.is_visible("Choose issue (updated)"));
+
+    harness.press('b');
+    assert!(harness.is_visible("Choose issue (updated, by status)"));
+    assert!(harness.is_visible("In Progress (1)"));
+
+    // Group header can't be opened.
+    harness.press(Key::Home);
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("No task selected"));
+}
+
+#[test]
+fn test_task_indicators() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+    assert!(harness.is_visible("● = TST-1 -- Fix login page │ In Progress"));
+    assert!(harness.is_visible("■ ↑ TST-2 -- Write release notes │ To Do"));
+
+    harness.type_text("TST-1");
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Type - ● Bug"));
+    assert!(harness.is_visible("Priority - = Medium"));
+    assert!(harness.is_visible("Status - In Progress"));
+}
+
+#[test]
+fn test_load_task_not_in_list() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+    harness.type_text("4");
+    harness.press(Key::Enter);
+
+    assert!(harness.is_visible("Old archived task"));
+    assert!(harness.is_visible("TST-4 -- Old archived task"));
+}
+
+#[test]
+fn test_load_missing_task() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+    harness.type_text("404");
+    harness.press(Key::Enter);
+
+    assert!(harness.is_visible("Can't find task with key: 404"));
+}
+
+#[test]
+fn test_tasks_load_error() {
+    let server = MockJiraServer::start();
+    server.mock(
+        MockRoute::new("GET", "/rest/api/2/search", 503, "")
+            .with_query("jql", "project=TST"),
+    );
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+
+    assert!(harness.is_visible("No tasks in this project"));
+}
+
+#[test]
+fn test_change_status() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+    harness.type_text("TST-1");
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+
+    harness.press('a');
+    assert!(harness.is_visible("c - Change status"));
+    harness.press('c');
+    assert!(harness.is_visible("Choose new status"));
+    harness.press(Key::Down);
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+
+    let request =
+        &server.requests_to("POST", "/rest/api/2/issue/TST-1/transitions")[0];
+    assert!(request.body.contains(r#""id":"31""#));
+}
+
+#[test]
+fn test_quit() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.press(Key::Tab);
+    harness.press('q');
+
+    assert!(!harness.runner.is_running());
+}
+
+#[test]
+fn test_custom_keymap() {
+    let server = MockJiraServer::start();
+    let keymap = serde_json::from_str::<Keymap>(
+        r#"{"quit": "ctrl+q", "task_actions": "x", "change_status": "s"}"#,
+    )
+    .unwrap();
+    let mut harness =
+        TuiHarness::start_with_keymap(&server, ApiVersion::V2, keymap);
+
+    assert!(harness.is_visible("| x - task actions |"));
+    assert!(harness.is_visible("| ctrl+q - quit |"));
+    harness.open_test_task();
+    harness.press('x');
+    assert!(harness.is_visible("s - Change status"));
+    harness.press('s');
+    assert!(harness.is_visible("Choose new status"));
+    harness.press(Key::Esc);
+    harness.press(Key::Esc);
+
+    harness.press(Key::Tab);
+    harness.press('q');
+    assert!(harness.runner.is_running());
+    harness.press(Event::CtrlChar('q'));
+    assert!(!harness.runner.is_running());
+}
+
+#[test]
+fn test_adf_description() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start_with_version(&server, ApiVersion::V3);
+
+    harness.open_test_project();
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+
+    assert!(harness.is_visible("Ask @Alice Doe for review"));
+    assert!(harness.is_visible("• endpoints"));
+    assert!(harness.is_visible("┃ Public docs only"));
+}
+
+#[test]
+fn test_unchanged_adf_description_is_not_saved() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start_with_version(&server, ApiVersion::V3);
+
+    harness.open_test_project();
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+    harness.press('a');
+    harness.press('d');
+
+    assert!(harness.is_visible("Edit description"));
+    assert!(harness.is_visible("which the editor can't keep"));
+
+    harness.press(Key::Tab);
+    harness.press(Key::Enter);
+
+    assert!(!harness.is_visible("Edit description"));
+    assert!(server
+        .requests_to("PUT", "/rest/api/3/issue/TST-5")
+        .is_empty());
+}
+
+#[test]
+fn test_add_comment() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+    harness.type_text("TST-1");
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+    harness.press('a');
+    harness.press('n');
+    harness.type_text("Done here");
+    harness.press(Key::Tab);
+    harness.press(Key::Enter);
+
+    let request =
+        &server.requests_to("POST", "/rest/api/2/issue/TST-1/comment")[0];
+    assert_eq!(request.body, r#"{"body":"Done here"}"#);
+}
+
+#[test]
+fn test_assignable_users() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('a');
+    harness.press('e');
+
+    assert!(harness.is_visible("Bob Roe | bob@jira.test"));
+    assert!(harness.is_visible("Carol Poe | carol@jira.test"));
+    harness.press(Key::Tab);
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+
+    let request = &server.requests_to("PUT", "/rest/api/2/issue/TST-1")[0];
+    assert_eq!(request.body, r#"{"fields":{"assignee":{"name":"carol"}}}"#);
+}
+
+#[test]
+fn test_assign_to_me_and_recent_assignees() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('a');
+    harness.press('e');
+    harness.press(Event::CtrlChar('a'));
+
+    let request = &server.requests_to("PUT", "/rest/api/2/issue/TST-1")[0];
+    assert_eq!(request.body, r#"{"fields":{"assignee":{"name":"alice"}}}"#);
+    assert!(!harness.is_visible("Assignee search"));
+
+    harness.press('a');
+    harness.press('e');
+    assert!(harness.is_visible("[recent] Alice Doe | alice@jira.test"));
+}
+
+#[test]
+fn test_assign_to_me_without_current_user() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new("GET", "/rest/api/2/myself", 500, ""));
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('a');
+    harness.press('e');
+    harness.press(Event::CtrlChar('a'));
+
+    assert!(harness.is_visible("Can't get the current user"));
+    assert!(server
+        .requests_to("PUT", "/rest/api/2/issue/TST-1")
+        .is_empty());
+}
+
+#[test]
+fn test_unassign() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('a');
+    harness.press('e');
+    harness.press(Event::CtrlChar('u'));
+
+    let request = &server.requests_to("PUT", "/rest/api/2/issue/TST-1")[0];
+    assert_eq!(request.body, r#"{"fields":{"assignee":{"name":null}}}"#);
+}
+
+#[test]
+fn test_unassign_with_keymap() {
+    let server = MockJiraServer::start();
+    let keymap =
+        serde_json::from_str::<Keymap>(r#"{"unassign": "ctrl+n"}"#).unwrap();
+    let mut harness =
+        TuiHarness::start_with_keymap(&server, ApiVersion::V2, keymap);
+
+    harness.open_test_task();
+    harness.press('a');
+    harness.press('e');
+    assert!(harness.is_visible("Unassign <ctrl+n>"));
+    harness.press(Event::CtrlChar('n'));
+
+    let request = &server.requests_to("PUT", "/rest/api/2/issue/TST-1")[0];
+    assert_eq!(request.body, r#"{"fields":{"assignee":{"name":null}}}"#);
+}
+
+#[test]
+fn test_failed_assign_does_not_reload_issue() {
+    let server = MockJiraServer::start();
+    server.mock(MockRoute::new("PUT", "/rest/api/2/issue/TST-1", 500, ""));
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    let issue_requests_count =
+        server.requests_to("GET", "/rest/api/2/issue/TST-1").len();
+    harness.press('a');
+    harness.press('e');
+    harness.press(Event::CtrlChar('u'));
+
+    assert!(harness.is_visible("Can't change assignee"));
+    assert_eq!(
+        server.requests_to("GET", "/rest/api/2/issue/TST-1").len(),
+        issue_requests_count
+    );
+}
+
+#[test]
+fn test_failed_reload_keeps_cached_issue() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    server.mock(MockRoute::new("GET", "/rest/api/2/issue/TST-1", 500, ""));
+    harness.press('a');
+    harness.press('e');
+    harness.press(Event::CtrlChar('u'));
+
+    assert_eq!(
+        server.requests_to("PUT", "/rest/api/2/issue/TST-1").len(),
+        1
+    );
+    assert!(harness.is_visible("The task is changed, but can't be reloaded"));
+    assert!(!harness.is_visible("Assignee search"));
+    assert!(harness.is_visible("Fix login page"));
+}
+
+#[test]
+fn test_issue_relations() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+
+    assert!(harness.is_visible("epic: TST-3"));
+    assert!(harness.is_visible("subtask: TST-6 -- Add login tests [To Do]"));
+    assert!(harness.is_visible("blocks: TST-2 -- Write release notes [To Do]"));
+    assert!(harness.is_visible("is blocked by: EMP-1"));
+}
+
+#[test]
+fn test_open_subtask() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press(Key::Tab);
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+
+    assert!(harness.is_visible("Cover login page with tests"));
+    assert!(harness.is_visible("parent: TST-1 -- Fix login page"));
+}
+
+#[test]
+fn test_add_link() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('a');
+    harness.press('l');
+    harness.type_text("tst-3");
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("is blocked by"));
+    assert!(harness.is_visible("relates to"));
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+
+    let request = &server.requests_to("POST", "/rest/api/2/issueLink")[0];
+    assert_eq!(
+        request.body,
+        r#"{"type":{"name":"Blocks"},"inwardIssue":{"key":"TST-3"},"outwardIssue":{"key":"TST-1"}}"#
+    );
+    assert!(!harness.is_visible("Enter issue key and choose link type"));
+}
+
+#[test]
+fn test_remove_link() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('a');
+    harness.press('u');
+    assert!(harness.is_visible("Choose link to remove"));
+    harness.press(Key::Enter);
+
+    assert_eq!(
+        server
+            .requests_to("DELETE", "/rest/api/2/issueLink/30001")
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_history_tab() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('h');
+
+    assert!(harness.is_visible("History, page 1 of 3"));
+    assert!(harness.is_visible(
+        "2023-01-24 10:00 | Bob Roe | assignee: Bob Roe → Alice Doe"
+    ));
+    harness.press(Key::Tab);
+    harness.press(Key::Right);
+    harness.press(Key::Right);
+    harness.press(Key::Enter);
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("History, page 3 of 3"));
+    assert!(harness.is_visible(
+        "2023-01-01 10:00 | Alice Doe | status: To Do → In Progress"
+    ));
+
+    harness.press(Key::Left);
+    harness.press(Key::Left);
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Filter: status"));
+    assert!(harness.is_visible("History, page 1 of 2"));
+    assert!(!harness.is_visible("assignee: Bob Roe → Alice Doe"));
+
+    harness.press('h');
+    assert!(harness.is_visible("Login page fails on submit"));
+}
+
+#[test]
+fn test_attachments() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    assert!(harness.is_visible("notes.txt (22 B) | Bob Roe"));
+
+    harness.press('a');
+    harness.press('w');
+    assert!(harness.is_visible("All attachments (2)"));
+    harness.press(Key::Esc);
+
+    let file_path = make_temp_dir("tui-upload").join("report.txt");
+    std::fs::write(&file_path, "Test report").unwrap();
+    harness.press('a');
+    harness.press('t');
+    harness.type_text(&file_path.display().to_string());
+    harness.press(Key::Enter);
+
+    assert_eq!(
+        server
+            .requests_to("POST", "/rest/api/2/issue/TST-1/attachments")
+            .len(),
+        1
+    );
+    assert!(!harness.is_visible("Enter path to the file"));
+}
+
+#[test]
+fn test_list_navigation_keys() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+    // Order of tasks in the list isn't fixed, so shown issues are compared.
+    let shown_issue = |harness: &TuiHarness| {
+        ["TST-1", "TST-2", "TST-3"]
+            .into_iter()
+            .find(|key| harness.is_visible(&format!("Issue - {key}")))
+    };
+
+    harness.open_test_project();
+    harness.press(Key::Down);
+    harness.press('g');
+    harness.press(Key::Enter);
+    let first_issue = shown_issue(&harness);
+    assert!(first_issue.is_some());
+
+    harness.press('j');
+    harness.press(Key::Enter);
+    let second_issue = shown_issue(&harness);
+    assert!(second_issue.is_some());
+    assert_ne!(second_issue, first_issue);
+
+    harness.press('G');
+    harness.press(Key::Enter);
+    let last_issue = shown_issue(&harness);
+    assert!(last_issue.is_some());
+    assert_ne!(last_issue, first_issue);
+    assert_ne!(last_issue, second_issue);
+
+    harness.press('k');
+    harness.press(Key::Enter);
+    assert_eq!(shown_issue(&harness), second_issue);
+}
+
+#[test]
+fn test_search_mode_keys() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.type_text("q");
+    assert!(harness.runner.is_running());
+    harness.press(Key::Backspace);
+
+    // Esc leaves the search, so global keys work again.
+    harness.press(Key::Esc);
+    harness.press('j');
+    harness.press('/');
+    harness.type_text("Empt");
+    assert!(harness.is_visible("Empty project"));
+    assert!(!harness.is_visible("Test project"));
+
+    harness.press(Key::Esc);
+    harness.press('q');
+    assert!(!harness.runner.is_running());
+}
+
+#[test]
+fn test_command_line() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press(':');
+    assert!(harness.is_visible("Enter command"));
+    harness.type_text("fly");
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Unknown command `fly`"));
+    harness.press(Key::Enter);
+
+    for _ in 0.."fly".len() {
+        harness.press(Key::Backspace);
+    }
+    harness.type_text("open tst-6");
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Cover login page with tests"));
+
+    harness.press(Event::CtrlChar('d'));
+    harness.press(Event::CtrlChar('u'));
+    assert!(harness.is_visible("Cover login page with tests"));
+
+    harness.press(':');
+    harness.type_text("quit");
+    harness.press(Key::Enter);
+    assert!(!harness.runner.is_running());
+}
+
+#[test]
+fn test_command_palette() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.press(Key::Esc);
+    harness.press(Event::CtrlChar('p'));
+    assert!(harness.is_visible("Command palette"));
+    assert!(harness.is_visible("a - task actions (global)"));
+    assert!(harness.is_visible("c - Change issue status (task actions)"));
+
+    harness.type_text("assignee tasks");
+    assert!(harness.is_visible("m - I'm assignee tasks (menu)"));
+    for _ in 0.."assignee tasks".len() {
+        harness.press(Key::Backspace);
+    }
+    harness.type_text("chst");
+    assert!(!harness.is_visible("a - task actions (global)"));
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Please select task"));
+    harness.press(Key::Enter);
+
+    harness.open_test_task();
+    harness.press(Event::CtrlChar('p'));
+    harness.type_text("chst");
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Choose new status"));
+}
+
+#[test]
+fn test_themes_menu() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+    harness.open_test_project();
+    harness.press(Key::Down);
+
+    harness.press('m');
+    assert!(harness.is_visible("Change theme"));
+    harness.press(Key::Home);
+    for _ in 0..4 {
+        harness.press(Key::Down);
+    }
+    harness.press(Key::Enter);
+
+    assert!(harness.is_visible("Choose theme"));
+    assert!(harness.is_visible("light"));
+    assert!(harness.is_visible("high_contrast"));
+}
+
+#[test]
+fn test_tasks_search_by_fields() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+    harness.type_text("label:docs");
+    assert!(harness.is_visible("TST-2 -- Write release notes"));
+    assert!(!harness.is_visible("TST-1 -- Fix login page"));
+    assert!(!harness.is_visible("TST-3 -- Upgrade dependencies"));
+}
+
+#[test]
+fn test_session_restore() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    jira_data
+        .write()
+        .unwrap()
+        .company_state
+        .set_session(SessionState {
+            project: Some("Test project".into()),
+            issue: Some("TST-1".into()),
+            search_text: "login".into(),
+            selected_row: 0,
+        });
+    let mut harness = TuiHarness::start_with_data(jira_data.clone());
+
+    assert!(harness.is_visible("Choose issue (key)"));
+    assert!(harness.is_visible("Issue - TST-1"));
+    assert!(harness.is_visible("Fix login page"));
+
+    save_session(&mut harness.runner);
+    assert_eq!(
+        jira_data.read().unwrap().company_state.session(),
+        &SessionState {
+            project: Some("Test project".into()),
+            issue: Some("TST-1".into()),
+            search_text: "login".into(),
+            selected_row: 0,
+        }
+    );
+}
+
+#[test]
+fn test_close_and_reopen_jira_screen() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    let mut harness = TuiHarness::start_with_data(jira_data.clone());
+    harness.open_test_task();
+    assert!(harness.is_visible("Fix login page"));
+
+    close_jira_screen(&mut harness.runner);
+    harness.runner.refresh();
+    harness.read_screen();
+    assert!(!harness.is_visible("Choose project"));
+    let requests_count = server.requests().len();
+
+    make_jira_screen_with_data(&mut harness.runner, jira_data);
+    harness.runner.refresh();
+    harness.read_screen();
+    assert!(harness.is_visible("Fix login page"));
+    // Only transitions of the issue are requested, tasks are cached.
+    let new_requests: Vec<String> = server.requests()[requests_count..]
+        .iter()
+        .map(|request| request.path.clone())
+        .collect();
+    assert!(new_requests.iter().all(|path| path.contains("transitions")));
+
+    // Global callbacks aren't added twice.
+    harness.press('s');
+    assert!(harness.is_visible("Choose issue (updated)"));
+}
+
+#[test]
+fn test_my_work_view() {
+    let server = MockJiraServer::start();
+    let offline_server = MockJiraServer::start_with_routes(Vec::new());
+    let jira_data = make_jira_data(&server);
+    jira_data.write().unwrap().company_name = "Acme".into();
+    let my_work = MyWork::load(
+        &jira_data.read().unwrap().thread_pool,
+        vec![
+            ("Acme".into(), make_client(&server)),
+            ("Offline".into(), make_client(&offline_server)),
+        ],
+    );
+    let mut harness = TuiHarness::start_with_data(jira_data);
+
+    let my_work_view = MyWorkView::new(&mut harness.runner, my_work);
+    harness.runner.add_layer(my_work_view);
+    harness.runner.refresh();
+    harness.read_screen();
+    assert!(harness.is_visible("My work in all companies"));
+    assert!(harness.is_visible("[Acme]     EMP-1 -- Prepare empty project"));
+    assert!(harness.is_visible("[Acme] ● = TST-1 -- Fix login page"));
+    assert!(harness.is_visible("Can't load: Offline"));
+
+    // Issue of the current company is opened without switching.
+    harness.press(Key::Enter);
+    assert!(!harness.is_visible("My work in all companies"));
+    assert!(harness.is_visible("Issue - EMP-1"));
+}
+
+#[test]
+fn test_recent_issues_and_bookmarks() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('f');
+    assert!(harness.is_visible("Task information ★"));
+    InfoView::open_issue(&mut harness.runner, "EMP-1");
+    harness.runner.refresh();
+    harness.read_screen();
+    assert!(harness.is_visible("Issue - EMP-1"));
+    assert!(!harness.is_visible("Task information ★"));
+
+    harness.press('o');
+    assert!(harness.is_visible("Recent issues and bookmarks"));
+    assert!(harness.is_visible("Bookmarks"));
+    assert!(harness.is_visible("1. TST-1 -- Fix login page"));
+    assert!(harness.is_visible("Recent"));
+    assert!(harness.is_visible("2. EMP-1 -- Prepare empty project"));
+
+    // Issue of another project is opened by its number.
+    harness.press('1');
+    assert!(!harness.is_visible("Recent issues and bookmarks"));
+    assert!(harness.is_visible("Issue - TST-1"));
+    assert!(harness.is_visible("Task information ★"));
+}
+
+#[test]
+fn test_share_link_view() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('a');
+    assert!(harness.is_visible("y - Open or copy link"));
+    harness.press('y');
+
+    assert!(harness.is_visible("Open or copy issue link"));
+    assert!(harness.is_visible("Open in browser"));
+    assert!(harness.is_visible("Copy key: TST-1"));
+    assert!(harness
+        .is_visible(&format!("Copy URL: {}/browse/TST-1", server.url())));
+    assert!(harness.is_visible("Copy Markdown link"));
+}
+
+#[test]
+fn test_create_branch_view() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_task();
+    harness.press('a');
+    assert!(harness.is_visible("g - Create git branch"));
+    harness.press('g');
+
+    assert!(harness.is_visible("Create git branch"));
+    assert!(harness.is_visible("TST-1-fix-login-page"));
+}
+
+#[test]
+fn test_filters_menu() {
+    let server = MockJiraServer::start();
+    let jira_data = make_jira_data(&server);
+    jira_data.write().unwrap().saved_filters = vec![JqlFilter::new(
+        "My bugs",
+        "project = TST AND issuetype = Bug",
+    )];
+    let mut harness = TuiHarness::start_with_data(jira_data);
+    harness.open_test_project();
+    harness.press(Key::Down);
+
+    harness.press('m');
+    assert!(harness.is_visible("Save JQL filter"));
+    assert!(harness.is_visible("Filter: My bugs"));
+    assert!(harness.is_visible("Jira filter: Open bugs"));
+    harness.press(Key::End);
+    harness.press(Key::Enter);
+
+    assert!(harness.is_visible("Open bugs"));
+    assert!(harness.is_visible("● = TST-1 -- Fix login page"));
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Issue - TST-1"));
+}
+
+#[test]
+fn test_auto_refresh() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+    harness.open_test_project();
+    harness.press(Key::Down);
+    harness.press(':');
+    harness.type_text("open TST-3");
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Upgrade dependencies"));
+
+    let jira_data = harness
+        .runner
+        .user_data::<Arc<RwLock<JiraData>>>()
+        .unwrap()
+        .clone();
+    let updated_issues = UpdatedIssues::load(&jira_data, 5).unwrap();
+    apply_updated_issues(&mut harness.runner, updated_issues);
+    harness.runner.refresh();
+    harness.read_screen();
+
+    assert!(
+        harness.is_visible("Assigned to you: TST-2 | Updated: TST-3, TST-7")
+    );
+    assert!(harness.is_visible("Upgrade dependencies to 2023"));
+    assert!(harness.is_visible("* ■ ↑ TST-2 -- Write release notes"));
+    assert!(harness.is_visible("*     TST-7 -- Add dark mode"));
+
+    harness.press(':');
+    harness.type_text("open TST-7");
+    harness.press(Key::Enter);
+    assert!(!harness.is_visible("*     TST-7 -- Add dark mode"));
+    assert!(harness.is_visible("│     TST-7 -- Add dark mode"));
+    assert!(harness.is_visible("* ■ ↑ TST-2 -- Write release notes"));
+}
+
+#[test]
+fn test_help_view() {
+    let server = MockJiraServer::start();
+    let keymap =
+        serde_json::from_str::<Keymap>(r#"{"sort_tasks": "S"}"#).unwrap();
+    let mut harness =
+        TuiHarness::start_with_keymap(&server, ApiVersion::V2, keymap);
+
+    assert!(harness.is_visible("| ? - help |"));
+    harness.open_test_project();
+    harness.press(Key::Down);
+    harness.press('?');
+    assert!(harness.is_visible("Key bindings"));
+    for section in ["Global", "Task list", "Task information", "Task actions"]
+    {
+        assert!(harness.is_visible(section));
+    }
+    assert!(harness.is_visible("ctrl+p  command palette"));
+    assert!(harness.is_visible("S  change tasks sort"));
+    assert!(harness.is_visible("ctrl+d  scroll description down"));
+    assert!(harness.is_visible("c  Change status"));
+    harness.press(Key::End);
+    assert!(harness.is_visible("Action dialogs"));
+    assert!(harness.is_visible("ctrl+u  unassign issue"));
+    assert!(harness.is_visible("1  open issue 1"));
+    assert!(harness.is_visible(":open KEY, o KEY  open issue by its key"));
+
+    harness.press(Key::Esc);
+    assert!(!harness.is_visible("Key bindings"));
+}
+
+#[test]
+fn test_rich_description() {
+    let server = MockJiraServer::start();
+    let mut harness = TuiHarness::start(&server);
+
+    harness.open_test_project();
+    harness.type_text("TST-2");
+    harness.press(Key::Down);
+    harness.press(Key::Enter);
+
+    assert!(harness.is_visible("Issue - TST-2"));
+    assert!(harness.is_visible("ask @Alice Doe after TST-1"));
+    assert!(harness.is_visible("┌ bash"));
+    assert!(harness.is_visible("│     --sign"));
+    assert!(harness.is_visible("┃ Release day"));
+    assert!(harness.is_visible("┌───────────┬───────┐"));
+    assert!(harness.is_visible("│ Changelog │ Bob   │"));
+
+    // The link to TST-1 is the only focusable part of the task information.
+    harness.press(Key::Tab);
+    harness.press(Key::Enter);
+    assert!(harness.is_visible("Issue - TST-1"));
+}
@@ -0,0 +1,109 @@
+use std::env;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+/// Copies the text to the clipboard.
+///
+/// OSC 52 escape sequence is written to the terminal, so copying
+/// works over SSH too. Terminal doesn't report if it supports the
+/// sequence, so `wl-copy` or `xclip` are run as well when present.
+/// Error is returned only if both ways failed.
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let terminal_result = copy_with_terminal(text);
+    let tool_result = copy_with_tool(text);
+    terminal_result.or(tool_result)
+}
+
+/// Returns OSC 52 escape sequence which puts the text to the clipboard.
+///
+/// tmux passes the sequence to the outer terminal
+/// only if it's wrapped into the passthrough sequence.
+fn osc52_sequence(text: &str, is_tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", base64::encode(text));
+    if is_tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+/// Writes OSC 52 sequence to the controlling terminal.
+fn copy_with_terminal(text: &str) -> io::Result<()> {
+    let mut terminal = OpenOptions::new().write(true).open("/dev/tty")?;
+    let is_tmux = env::var_os("TMUX").is_some();
+    terminal.write_all(osc52_sequence(text, is_tmux).as_bytes())?;
+    terminal.flush()
+}
+
+/// Passes the text to `wl-copy` on Wayland or to `xclip` on X11.
+fn copy_with_tool(text: &str) -> io::Result<()> {
+    let mut tools: Vec<(&str, &[&str])> = Vec::new();
+    if env::var_os("WAYLAND_DISPLAY").is_some() {
+        tools.push(("wl-copy", &[]));
+    }
+    if env::var_os("DISPLAY").is_some() {
+        tools.push(("xclip", &["-selection", "clipboard"]));
+    }
+
+    let mut last_error = io::Error::new(
+        io::ErrorKind::NotFound,
+        "No clipboard tool for the session",
+    );
+    for (program, args) in tools {
+        match run_with_input(program, args, text) {
+            Ok(()) => return Ok(()),
+            Err(err) => last_error = err,
+        }
+    }
+    Err(last_error)
+}
+
+fn run_with_input(
+    program: &str,
+    args: &[&str],
+    input: &str,
+) -> io::Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(io::Error::other(format!("{program} failed: {status}")));
+    }
+    Ok(())
+}
+
+/// Returns Markdown link, brackets in the text are escaped.
+pub fn markdown_link(text: &str, url: &str) -> String {
+    let text = text.replace('[', "\\[").replace(']', "\\]");
+    format!("[{}]({})", text, url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52_sequence("TST-1", false), "\x1b]52;c;VFNULTE=\x07");
+        assert_eq!(
+            osc52_sequence("TST-1", true),
+            "\x1bPtmux;\x1b\x1b]52;c;VFNULTE=\x07\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_markdown_link() {
+        assert_eq!(
+            markdown_link("TST-1 [UI] Fix login", "https://jira/browse/TST-1"),
+            "[TST-1 \\[UI\\] Fix login](https://jira/browse/TST-1)"
+        );
+    }
+}
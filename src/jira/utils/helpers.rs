@@ -1,4 +1,16 @@
-use cursive::Cursive;
+use std::env;
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+
+use cursive::{
+    theme::{Effect, Style},
+    utils::markup::StyledString,
+    Cursive,
+};
+use home::home_dir;
 
 pub fn calculate_view_size(
     cursive: &mut Cursive,
@@ -11,3 +23,187 @@ pub fn calculate_view_size(
         screen_size.y * numerator / denominator,
     )
 }
+
+/// Converts path entered by user into `PathBuf`.
+///
+/// Leading `~` is replaced with the home directory.
+pub fn expand_user_path(path: &str) -> PathBuf {
+    let path = path.trim();
+    match (path.strip_prefix('~'), home_dir()) {
+        (Some(rest), Some(home_dir)) => {
+            home_dir.join(rest.trim_start_matches('/'))
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Opens the file in the default application with `xdg-open`.
+///
+/// The application is waited in the background,
+/// so the interface isn't blocked.
+pub fn open_with_default_app(file_path: &Path) -> io::Result<()> {
+    spawn_in_background("xdg-open", file_path.as_os_str())
+}
+
+/// Opens the url in the browser from `$BROWSER` or with `xdg-open`.
+///
+/// `$BROWSER` can be a list of browsers separated by `:`,
+/// the first one is used.
+pub fn open_in_browser(url: &str) -> io::Result<()> {
+    let browser = env::var("BROWSER").ok().and_then(|browsers| {
+        browsers
+            .split(':')
+            .map(str::trim)
+            .find(|browser| !browser.is_empty())
+            .map(String::from)
+    });
+    spawn_in_background(
+        browser.as_deref().unwrap_or("xdg-open"),
+        OsStr::new(url),
+    )
+}
+
+/// Runs the program without output and waits it in the background.
+fn spawn_in_background(program: &str, arg: &OsStr) -> io::Result<()> {
+    let mut child = Command::new(program)
+        .arg(arg)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Returns score of the fuzzy match of `pattern` in `text`.
+///
+/// Chars of the pattern must be found in the text in the same order,
+/// case and spaces in the pattern are ignored.
+/// Consecutive chars and chars at the start of words give more score,
+/// gaps between chars reduce it. The best placement of the chars is used.
+/// `None` means the text doesn't match.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<i64> {
+    fuzzy_match_indices(pattern, text).map(|(score, _)| score)
+}
+
+/// Same as `fuzzy_match`, but also returns indices of the matched chars
+/// of the `text` to highlight them.
+pub fn fuzzy_match_indices(
+    pattern: &str,
+    text: &str,
+) -> Option<(i64, Vec<usize>)> {
+    const WORD_START_BONUS: i64 = 6;
+    const CONSECUTIVE_BONUS: i64 = 8;
+    const MAX_GAP_PENALTY: usize = 3;
+
+    let to_lower = |char: char| char.to_lowercase().next().unwrap_or(char);
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|char| !char.is_whitespace())
+        .map(to_lower)
+        .collect();
+    if pattern.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let text: Vec<char> = text.chars().map(to_lower).collect();
+    let char_bonus = |idx: usize| {
+        if idx == 0 || !text[idx - 1].is_alphanumeric() {
+            1 + WORD_START_BONUS
+        } else {
+            1
+        }
+    };
+
+    // Best score of the matched pattern prefix ending at every text char
+    // with index of the previous matched char.
+    let mut table: Vec<Vec<Option<(i64, usize)>>> = vec![text
+        .iter()
+        .enumerate()
+        .map(|(idx, char)| (*char == pattern[0]).then(|| (char_bonus(idx), 0)))
+        .collect()];
+    for pattern_char in &pattern[1..] {
+        let prev_scores = table.last().unwrap();
+        let scores: Vec<Option<(i64, usize)>> = (0..text.len())
+            .map(|idx| {
+                if text[idx] != *pattern_char {
+                    return None;
+                }
+                (0..idx)
+                    .filter_map(|prev_idx| {
+                        let (prev_score, _) = prev_scores[prev_idx]?;
+                        let gap = idx - prev_idx - 1;
+                        let score = if gap == 0 {
+                            prev_score + CONSECUTIVE_BONUS
+                        } else {
+                            prev_score - gap.min(MAX_GAP_PENALTY) as i64
+                        };
+                        Some((score, prev_idx))
+                    })
+                    .max_by_key(|(score, _)| *score)
+                    .map(|(score, prev_idx)| {
+                        (score + char_bonus(idx), prev_idx)
+                    })
+            })
+            .collect();
+        table.push(scores);
+    }
+
+    let (mut idx, (score, _)) = table
+        .last()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, cell)| cell.map(|cell| (idx, cell)))
+        .max_by_key(|(_, (score, _))| *score)?;
+    let mut indices = vec![0; pattern.len()];
+    for (row_idx, row) in table.iter().enumerate().rev() {
+        indices[row_idx] = idx;
+        idx = row[idx].unwrap().1;
+    }
+    Some((score, indices))
+}
+
+/// Makes text with highlighted chars, e.g. matched by the search.
+pub fn highlight_chars(text: &str, indices: &[usize]) -> StyledString {
+    let highlight_style = Style::from(Effect::Bold).combine(Effect::Underline);
+    let mut styled_text = StyledString::new();
+    for (idx, char) in text.chars().enumerate() {
+        if indices.contains(&idx) {
+            styled_text.append_styled(char.to_string(), highlight_style);
+        } else {
+            styled_text.append_plain(char.to_string());
+        }
+    }
+    styled_text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("", "Change status"), Some(0));
+        assert!(fuzzy_match("chst", "Change status").is_some());
+        assert!(fuzzy_match("CH ST", "change status").is_some());
+        assert!(fuzzy_match("stch", "Change status").is_none());
+        assert!(fuzzy_match("quit", "Quit").is_some());
+
+        // Consecutive chars and word starts are better than scattered ones.
+        assert!(
+            fuzzy_match("status", "Change status").unwrap()
+                > fuzzy_match("status", "Set the assignee to us").unwrap()
+        );
+        assert!(
+            fuzzy_match("cs", "Change status").unwrap()
+                > fuzzy_match("cs", "Discussion").unwrap()
+        );
+        assert_eq!(
+            fuzzy_match_indices("chst", "Change issue status"),
+            Some((29, vec![0, 1, 13, 14]))
+        );
+        assert!(
+            fuzzy_match("chst", "Change issue status").unwrap()
+                > fuzzy_match("chst", "search in the current list").unwrap()
+        );
+    }
+}
@@ -1,10 +1,8 @@
 use cursive::{view::ViewWrapper, views::Dialog, Cursive, View};
 use rusji_derive::ViewWrapper;
+use serde_json::json;
 
-#[derive(ViewWrapper)]
-pub(crate) struct TryAgainView {
-    inner_view: Dialog,
-}
+use crate::logger;
 
 #[derive(ViewWrapper)]
 pub(crate) struct FailedAttemptView {
@@ -12,7 +10,9 @@ pub(crate) struct FailedAttemptView {
 }
 
 impl FailedAttemptView {
+    /// Creates dialog with the error, the error is logged too.
     pub fn new(error_text: &str) -> Self {
+        logger::error("ui_error", json!({ "message": error_text }));
         Self {
             inner_view: Dialog::new().title(error_text).button(
                 "Exit",
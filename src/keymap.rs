@@ -0,0 +1,529 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{Error, Result};
+use std::str::FromStr;
+
+use cursive::event::{Event, Key};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Named keys which can be used in key chords.
+const NAMED_KEYS: [(&str, Key); 14] = [
+    ("esc", Key::Esc),
+    ("enter", Key::Enter),
+    ("tab", Key::Tab),
+    ("backspace", Key::Backspace),
+    ("del", Key::Del),
+    ("ins", Key::Ins),
+    ("up", Key::Up),
+    ("down", Key::Down),
+    ("left", Key::Left),
+    ("right", Key::Right),
+    ("home", Key::Home),
+    ("end", Key::End),
+    ("pageup", Key::PageUp),
+    ("pagedown", Key::PageDown),
+];
+
+/// Key with optional modifier, like `q`, `ctrl+a` or `esc`.
+///
+/// It's stored in the config as a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyChord(Event);
+
+impl From<KeyChord> for Event {
+    fn from(key_chord: KeyChord) -> Self {
+        key_chord.0
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    /// Parses key chord from the string.
+    ///
+    /// Single char is used as is, so `Q` and `q` are different keys.
+    /// Named keys (`esc`, `f1`, etc.) and modifiers are case insensitive.
+    fn from_str(raw_chord: &str) -> std::result::Result<Self, Self::Err> {
+        let (modifier, raw_key) = match raw_chord.rsplit_once('+') {
+            Some((modifier, raw_key)) if !raw_key.is_empty() => {
+                (Some(modifier.to_lowercase()), raw_key)
+            }
+            _ => (None, raw_chord),
+        };
+
+        let mut chars = raw_key.chars();
+        let event = match (chars.next(), chars.next()) {
+            (Some(char), None) => match modifier.as_deref() {
+                None => Event::Char(char),
+                Some("ctrl") => Event::CtrlChar(char.to_ascii_lowercase()),
+                Some("alt") => Event::AltChar(char),
+                _ => return Err(format!("Unknown key `{raw_chord}`")),
+            },
+            _ => {
+                let key = parse_named_key(raw_key)
+                    .ok_or_else(|| format!("Unknown key `{raw_chord}`"))?;
+                match modifier.as_deref() {
+                    None => Event::Key(key),
+                    Some("ctrl") => Event::Ctrl(key),
+                    Some("alt") => Event::Alt(key),
+                    Some("shift") => Event::Shift(key),
+                    _ => return Err(format!("Unknown key `{raw_chord}`")),
+                }
+            }
+        };
+        Ok(Self(event))
+    }
+}
+
+fn parse_named_key(raw_key: &str) -> Option<Key> {
+    let raw_key = raw_key.to_lowercase();
+    if let Some(number) = raw_key.strip_prefix('f') {
+        if let Ok(number) = number.parse::<u8>() {
+            return (1..=12).contains(&number).then(|| Key::from_f(number));
+        }
+    }
+    NAMED_KEYS
+        .iter()
+        .find(|(name, _)| *name == raw_key)
+        .map(|(_, key)| *key)
+}
+
+fn named_key_text(key: Key) -> String {
+    if let Some((name, _)) = NAMED_KEYS.iter().find(|(_, named)| *named == key)
+    {
+        return name.to_string();
+    }
+    (1..=12)
+        .find(|number| Key::from_f(*number) == key)
+        .map(|number| format!("f{number}"))
+        .unwrap_or_else(|| format!("{key:?}").to_lowercase())
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Event::Char(char) => write!(formatter, "{char}"),
+            Event::CtrlChar(char) => write!(formatter, "ctrl+{char}"),
+            Event::AltChar(char) => write!(formatter, "alt+{char}"),
+            Event::Key(key) => write!(formatter, "{}", named_key_text(*key)),
+            Event::Ctrl(key) => {
+                write!(formatter, "ctrl+{}", named_key_text(*key))
+            }
+            Event::Alt(key) => {
+                write!(formatter, "alt+{}", named_key_text(*key))
+            }
+            Event::Shift(key) => {
+                write!(formatter, "shift+{}", named_key_text(*key))
+            }
+            event => write!(formatter, "{event:?}"),
+        }
+    }
+}
+
+impl Serialize for KeyChord {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyChord {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw_chord = String::deserialize(deserializer)?;
+        raw_chord.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Where the key binding works.
+///
+/// Keys of different contexts can be the same, but dialogs handle
+/// their keys before global keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyContext {
+    /// Keys handled everywhere on the Jira screen.
+    Global,
+    /// Keys of the task actions list.
+    TaskActions,
+    /// Keys of the assignee dialog.
+    AssigneeDialog,
+    /// Keys of the recent issues dialog.
+    RecentIssues,
+}
+
+impl KeyContext {
+    /// Returns all contexts.
+    pub fn all() -> [Self; 4] {
+        [
+            Self::Global,
+            Self::TaskActions,
+            Self::AssigneeDialog,
+            Self::RecentIssues,
+        ]
+    }
+
+    /// Returns keys of the context which can't be changed in the keymap.
+    pub fn fixed_keys(self) -> Vec<KeyChord> {
+        match self {
+            // Numbered issues are opened with `1`-`9`.
+            Self::RecentIssues => ('1'..='9')
+                .map(|number| KeyChord(Event::Char(number)))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns name of the context used in keymap errors.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Global => "global keys",
+            Self::TaskActions => "task actions",
+            Self::AssigneeDialog => "assignee dialog",
+            Self::RecentIssues => "recent issues dialog",
+        }
+    }
+
+    /// Returns true if keys of the context are handled by a dialog,
+    /// so global keys can't work there.
+    fn is_dialog(self) -> bool {
+        matches!(self, Self::AssigneeDialog | Self::RecentIssues)
+    }
+}
+
+/// All actions which can be bound to keys in the config.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAction {
+    FocusProjects,
+    FocusTasks,
+    Search,
+    Command,
+    CommandPalette,
+    CloseView,
+    MoveDown,
+    MoveUp,
+    MoveTop,
+    MoveBottom,
+    PageDown,
+    PageUp,
+    SortTasks,
+    GroupTasks,
+    RecentIssues,
+    ToggleBookmark,
+    Help,
+    TaskActions,
+    History,
+    Menu,
+    Quit,
+    ChangeStatus,
+    ChangeAssignee,
+    ChangeStoryPoints,
+    ChangeDescription,
+    AddComment,
+    AddLink,
+    RemoveLink,
+    DownloadAttachments,
+    UploadAttachment,
+    ShareLink,
+    CreateBranch,
+    AssignToMe,
+    Unassign,
+}
+
+impl KeyAction {
+    /// Returns all actions.
+    pub fn all() -> [Self; 34] {
+        [
+            Self::FocusProjects,
+            Self::FocusTasks,
+            Self::Search,
+            Self::Command,
+            Self::CommandPalette,
+            Self::CloseView,
+            Self::MoveDown,
+            Self::MoveUp,
+            Self::MoveTop,
+            Self::MoveBottom,
+            Self::PageDown,
+            Self::PageUp,
+            Self::SortTasks,
+            Self::GroupTasks,
+            Self::RecentIssues,
+            Self::ToggleBookmark,
+            Self::Help,
+            Self::TaskActions,
+            Self::History,
+            Self::Menu,
+            Self::Quit,
+            Self::ChangeStatus,
+            Self::ChangeAssignee,
+            Self::ChangeStoryPoints,
+            Self::ChangeDescription,
+            Self::AddComment,
+            Self::AddLink,
+            Self::RemoveLink,
+            Self::DownloadAttachments,
+            Self::UploadAttachment,
+            Self::ShareLink,
+            Self::CreateBranch,
+            Self::AssignToMe,
+            Self::Unassign,
+        ]
+    }
+
+    /// Returns key used if the config doesn't override it.
+    pub fn default_key(self) -> KeyChord {
+        let event = match self {
+            Self::FocusProjects => Event::Char('p'),
+            Self::FocusTasks => Event::Char('i'),
+            Self::Search => Event::Char('/'),
+            Self::Command => Event::Char(':'),
+            Self::CommandPalette => Event::CtrlChar('p'),
+            Self::CloseView => Event::Key(Key::Esc),
+            Self::MoveDown => Event::Char('j'),
+            Self::MoveUp => Event::Char('k'),
+            Self::MoveTop => Event::Char('g'),
+            Self::MoveBottom => Event::Char('G'),
+            Self::PageDown => Event::CtrlChar('d'),
+            Self::PageUp => Event::CtrlChar('u'),
+            Self::SortTasks => Event::Char('s'),
+            Self::GroupTasks => Event::Char('b'),
+            Self::RecentIssues => Event::Char('o'),
+            Self::ToggleBookmark => Event::Char('f'),
+            Self::Help => Event::Char('?'),
+            Self::TaskActions => Event::Char('a'),
+            Self::History => Event::Char('h'),
+            Self::Menu => Event::Char('m'),
+            Self::Quit => Event::Char('q'),
+            Self::ChangeStatus => Event::Char('c'),
+            Self::ChangeAssignee => Event::Char('e'),
+            Self::ChangeStoryPoints => Event::Char('r'),
+            Self::ChangeDescription => Event::Char('d'),
+            Self::AddComment => Event::Char('n'),
+            Self::AddLink => Event::Char('l'),
+            Self::RemoveLink => Event::Char('u'),
+            Self::DownloadAttachments => Event::Char('w'),
+            Self::UploadAttachment => Event::Char('t'),
+            Self::ShareLink => Event::Char('y'),
+            Self::CreateBranch => Event::Char('g'),
+            Self::AssignToMe => Event::CtrlChar('a'),
+            Self::Unassign => Event::CtrlChar('u'),
+        };
+        KeyChord(event)
+    }
+
+    pub fn context(self) -> KeyContext {
+        match self {
+            Self::ChangeStatus
+            | Self::ChangeAssignee
+            | Self::ChangeStoryPoints
+            | Self::ChangeDescription
+            | Self::AddComment
+            | Self::AddLink
+            | Self::RemoveLink
+            | Self::DownloadAttachments
+            | Self::UploadAttachment
+            | Self::ShareLink
+            | Self::CreateBranch => KeyContext::TaskActions,
+            Self::AssignToMe | Self::Unassign => KeyContext::AssigneeDialog,
+            _ => KeyContext::Global,
+        }
+    }
+
+    /// Returns true if the global action is used in dialogs too.
+    ///
+    /// Other global actions change the task list or the task
+    /// information, so dialogs can use their keys.
+    fn is_used_in_dialogs(self) -> bool {
+        self.context() == KeyContext::Global
+            && !matches!(
+                self,
+                Self::PageDown
+                    | Self::PageUp
+                    | Self::SortTasks
+                    | Self::GroupTasks
+                    | Self::ToggleBookmark
+                    | Self::History
+            )
+    }
+
+    /// Returns true if the actions can't have the same key.
+    fn is_conflicting(self, other: Self) -> bool {
+        self.context() == other.context()
+            || self.is_used_in_dialogs() && other.context().is_dialog()
+            || other.is_used_in_dialogs() && self.context().is_dialog()
+    }
+
+    /// Returns name of the action used in the config.
+    pub fn config_name(self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.as_str().map(String::from))
+            .unwrap_or_default()
+    }
+
+    /// Returns action by its name in the config.
+    pub fn from_config_name(config_name: &str) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|action| action.config_name() == config_name)
+    }
+}
+
+/// Key bindings from the `keymap` section of the config.
+///
+/// Only overridden keys are stored,
+/// other actions use their default keys.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(transparent)]
+pub struct Keymap {
+    bindings: BTreeMap<KeyAction, KeyChord>,
+}
+
+impl Keymap {
+    /// Returns key bound to the action.
+    pub fn key(&self, action: KeyAction) -> KeyChord {
+        self.bindings
+            .get(&action)
+            .cloned()
+            .unwrap_or_else(|| action.default_key())
+    }
+
+    /// Checks that keys which work at the same time are different.
+    ///
+    /// Dialogs handle their keys before global keys, so global actions
+    /// used in dialogs can't have keys of the dialogs. Chars are typed
+    /// as text in search fields, so `close_view` can't be a char.
+    pub fn validate(&self) -> Result<()> {
+        let actions = KeyAction::all();
+        for (idx, action) in actions.iter().enumerate() {
+            let conflicting_action = actions[idx + 1..].iter().find(|other| {
+                action.is_conflicting(**other)
+                    && self.key(**other) == self.key(*action)
+            });
+            if let Some(conflicting_action) = conflicting_action {
+                return Err(Error::other(format!(
+                    "Key `{}` is bound to both `{}` and `{}` in the keymap",
+                    self.key(*action),
+                    action.config_name(),
+                    conflicting_action.config_name(),
+                )));
+            }
+        }
+
+        let dialogs = KeyContext::all()
+            .into_iter()
+            .filter(|context| context.is_dialog());
+        for dialog in dialogs {
+            let conflicting_action = actions.iter().find(|action| {
+                action.is_used_in_dialogs()
+                    && dialog.fixed_keys().contains(&self.key(**action))
+            });
+            if let Some(conflicting_action) = conflicting_action {
+                return Err(Error::other(format!(
+                    "Key `{}` of `{}` is used by the {}",
+                    self.key(*conflicting_action),
+                    conflicting_action.config_name(),
+                    dialog.name(),
+                )));
+            }
+        }
+
+        let close_view_key = self.key(KeyAction::CloseView);
+        if matches!(close_view_key.0, Event::Char(_)) {
+            return Err(Error::other(format!(
+                "Key `{close_view_key}` of `close_view` is typed as text \
+                 in search fields, use a named key or a modifier"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_key_chord() {
+        for raw_chord in
+            ["q", "Q", "ctrl+a", "alt+x", "esc", "f5", "shift+tab"]
+        {
+            let key_chord = raw_chord.parse::<KeyChord>().unwrap();
+            assert_eq!(key_chord.to_string(), raw_chord);
+        }
+        assert_eq!(
+            "Ctrl+Enter".parse::<KeyChord>().unwrap(),
+            KeyChord(Event::Ctrl(Key::Enter))
+        );
+        assert_eq!(
+            "+".parse::<KeyChord>().unwrap(),
+            KeyChord(Event::Char('+'))
+        );
+        assert!("hyper+a".parse::<KeyChord>().is_err());
+        assert!("escape".parse::<KeyChord>().is_err());
+    }
+
+    #[test]
+    fn test_keymap_from_config() {
+        let keymap = serde_json::from_str::<Keymap>(
+            r#"{"quit": "ctrl+q", "change_status": "s"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(keymap.key(KeyAction::Quit).to_string(), "ctrl+q");
+        assert_eq!(keymap.key(KeyAction::ChangeStatus).to_string(), "s");
+        assert_eq!(keymap.key(KeyAction::Menu).to_string(), "m");
+        assert!(keymap.validate().is_ok());
+        assert!(serde_json::from_str::<Keymap>(r#"{"fly": "f"}"#).is_err());
+    }
+
+    #[test]
+    fn test_keymap_conflicts() {
+        let keymap =
+            serde_json::from_str::<Keymap>(r#"{"menu": "q"}"#).unwrap();
+        assert_eq!(
+            keymap.validate().unwrap_err().to_string(),
+            "Key `q` is bound to both `menu` and `quit` in the keymap"
+        );
+
+        // Task actions list has its own keys.
+        let keymap =
+            serde_json::from_str::<Keymap>(r#"{"add_link": "q"}"#).unwrap();
+        assert!(keymap.validate().is_ok());
+
+        // Dialogs handle their keys before global keys.
+        let keymap =
+            serde_json::from_str::<Keymap>(r#"{"close_view": "ctrl+a"}"#)
+                .unwrap();
+        assert_eq!(
+            keymap.validate().unwrap_err().to_string(),
+            "Key `ctrl+a` is bound to both `close_view` and `assign_to_me` \
+             in the keymap"
+        );
+        let keymap =
+            serde_json::from_str::<Keymap>(r#"{"move_down": "1"}"#).unwrap();
+        assert_eq!(
+            keymap.validate().unwrap_err().to_string(),
+            "Key `1` of `move_down` is used by the recent issues dialog"
+        );
+        let keymap =
+            serde_json::from_str::<Keymap>(r#"{"close_view": "x"}"#).unwrap();
+        assert!(keymap.validate().is_err());
+
+        // Scrolling of the description isn't used in dialogs.
+        assert!(Keymap::default().validate().is_ok());
+        let keymap =
+            serde_json::from_str::<Keymap>(r#"{"sort_tasks": "1"}"#).unwrap();
+        assert!(keymap.validate().is_ok());
+    }
+}
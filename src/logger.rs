@@ -0,0 +1,312 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{Result, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::utils::build_logs_path;
+
+/// Name of the current log file, rotated files get `.1`, `.2`, ...
+const LOG_FILE_NAME: &str = "rusji.log";
+
+/// Size of the log file in bytes after which it's rotated.
+const LOG_FILE_LIMIT: u64 = 1024 * 1024;
+
+/// Amount of rotated log files which are kept.
+const LOG_FILES_KEPT: usize = 3;
+
+/// Max amount of chars around the error in the JSON snippet.
+const SNIPPET_RADIUS: usize = 40;
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+/// Level of the log records, each level includes the previous ones.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    /// Failed requests, unexpected responses and errors shown in the app.
+    Error,
+    /// Every request to Jira.
+    Info,
+    /// Query strings and sizes of the responses.
+    Debug,
+}
+
+impl LogLevel {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Info => "info",
+            Self::Debug => "debug",
+        }
+    }
+}
+
+struct Logger {
+    level: LogLevel,
+    file: Mutex<LogFile>,
+}
+
+/// Starts writing records of the level to `~/.rusji/logs/rusji.log`.
+///
+/// Nothing is logged until it's called.
+pub fn init(level: LogLevel) -> Result<()> {
+    let logs_path = build_logs_path()?;
+    fs::create_dir_all(&logs_path)?;
+    let file = LogFile::open(
+        Path::new(&logs_path).join(LOG_FILE_NAME),
+        LOG_FILE_LIMIT,
+        LOG_FILES_KEPT,
+    )?;
+    let _ = LOGGER.set(Logger {
+        level,
+        file: Mutex::new(file),
+    });
+    Ok(())
+}
+
+/// Returns true if records of the level are written.
+pub fn is_enabled(level: LogLevel) -> bool {
+    LOGGER.get().is_some_and(|logger| level <= logger.level)
+}
+
+/// Writes the record as a JSON line.
+///
+/// `fields` are added to the time, level and event of the record,
+/// e.g. `{"event": "request", "level": "info", "time": "...", ...}`.
+/// Failed write is ignored, logs must not break the app.
+pub fn log(level: LogLevel, event: &str, fields: Value) {
+    let Some(logger) = LOGGER.get() else {
+        return;
+    };
+    if level > logger.level {
+        return;
+    }
+    let record = make_record(SystemTime::now(), level, event, fields);
+    if let Ok(mut file) = logger.file.lock() {
+        let _ = file.write_line(&record.to_string());
+    }
+}
+
+/// Writes the error record.
+pub fn error(event: &str, fields: Value) {
+    log(LogLevel::Error, event, fields);
+}
+
+fn make_record(
+    time: SystemTime,
+    level: LogLevel,
+    event: &str,
+    fields: Value,
+) -> Value {
+    let mut record = Map::new();
+    record.insert("time".into(), format_timestamp(time).into());
+    record.insert("level".into(), level.name().into());
+    record.insert("event".into(), event.into());
+    match fields {
+        Value::Object(fields) => record.extend(fields),
+        Value::Null => {}
+        fields => {
+            record.insert("data".into(), fields);
+        }
+    }
+    Value::Object(record)
+}
+
+/// Returns the time in UTC, e.g. `2023-11-14T22:13:20.123Z`.
+fn format_timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (year, month, day) = date_from_days(seconds / 86400);
+    let day_seconds = seconds % 86400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        day_seconds / 3600,
+        day_seconds % 3600 / 60,
+        day_seconds % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Converts days since 1970-01-01 to the date of the Gregorian calendar.
+///
+/// Years start in March here, so the leap day is the last one.
+fn date_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Returns part of the JSON around the place where parsing failed.
+pub fn json_snippet(json: &str, err: &serde_json::Error) -> String {
+    let line = json
+        .lines()
+        .nth(err.line().saturating_sub(1))
+        .unwrap_or_default();
+    let column = err.column().saturating_sub(1);
+    line.chars()
+        .skip(column.saturating_sub(SNIPPET_RADIUS))
+        .take(SNIPPET_RADIUS * 2)
+        .collect()
+}
+
+/// Log file which is rotated when it gets too big.
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    size_limit: u64,
+    files_kept: usize,
+}
+
+impl LogFile {
+    fn open(
+        path: PathBuf,
+        size_limit: u64,
+        files_kept: usize,
+    ) -> Result<Self> {
+        let file = Self::open_file(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file,
+            size,
+            size_limit,
+            files_kept,
+        })
+    }
+
+    fn open_file(path: &Path) -> Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    /// Appends the line, the file is rotated first
+    /// if the line doesn't fit into the size limit.
+    fn write_line(&mut self, line: &str) -> Result<()> {
+        let line_size = line.len() as u64 + 1;
+        if self.size > 0 && self.size + line_size > self.size_limit {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{line}")?;
+        self.size += line_size;
+        Ok(())
+    }
+
+    /// Renames `rusji.log` to `rusji.log.1`, `rusji.log.1`
+    /// to `rusji.log.2` and so on, the oldest file is removed.
+    fn rotate(&mut self) -> Result<()> {
+        for index in (1..self.files_kept).rev() {
+            let rotated_path = self.rotated_path(index);
+            if rotated_path.exists() {
+                fs::rename(rotated_path, self.rotated_path(index + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated_path(1))?;
+        self.file = Self::open_file(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut file_name =
+            self.path.file_name().unwrap_or_default().to_owned();
+        file_name.push(format!(".{index}"));
+        self.path.with_file_name(file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_format_timestamp() {
+        let timestamp = |millis: u64| {
+            format_timestamp(UNIX_EPOCH + Duration::from_millis(millis))
+        };
+        assert_eq!(timestamp(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(timestamp(951_782_400_000), "2000-02-29T00:00:00.000Z");
+        assert_eq!(timestamp(1_700_000_000_123), "2023-11-14T22:13:20.123Z");
+    }
+
+    #[test]
+    fn test_make_record() {
+        let record = make_record(
+            UNIX_EPOCH,
+            LogLevel::Info,
+            "request",
+            json!({"method": "GET", "status": 200}),
+        );
+        assert_eq!(
+            record,
+            json!({
+                "time": "1970-01-01T00:00:00.000Z",
+                "level": "info",
+                "event": "request",
+                "method": "GET",
+                "status": 200,
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_snippet() {
+        let json = "{\n  \"key\": \"TST-1\",\n  \"fields\": 12\n}";
+        let err = serde_json::from_str::<Value>(&json.replace("12", "1x"))
+            .unwrap_err();
+        assert_eq!(
+            json_snippet(&json.replace("12", "1x"), &err),
+            "  \"fields\": 1x"
+        );
+    }
+
+    #[test]
+    fn test_log_file_rotation() {
+        let logs_dir = env::temp_dir()
+            .join(format!("rusji-logs-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&logs_dir);
+        fs::create_dir_all(&logs_dir).unwrap();
+        let path = logs_dir.join(LOG_FILE_NAME);
+
+        let mut log_file = LogFile::open(path.clone(), 10, 2).unwrap();
+        for line in ["first", "second", "third", "fourth"] {
+            log_file.write_line(line).unwrap();
+        }
+        let read = |file_name: &str| {
+            fs::read_to_string(logs_dir.join(file_name)).unwrap()
+        };
+        assert_eq!(read("rusji.log"), "fourth\n");
+        assert_eq!(read("rusji.log.1"), "third\n");
+        assert_eq!(read("rusji.log.2"), "second\n");
+        assert!(!logs_dir.join("rusji.log.3").exists());
+
+        fs::remove_dir_all(&logs_dir).unwrap();
+    }
+}
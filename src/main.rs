@@ -1,22 +1,84 @@
+mod commands;
 mod config;
 mod constance;
 mod errors;
+mod git;
 mod jira;
+mod keymap;
+mod logger;
 mod screen;
 mod startup;
+mod state;
 mod theme;
 mod utils;
 
 pub use crate::config::*;
-pub use crate::jira::*;
+pub use crate::jira::{jira_data, request_client};
 pub use crate::screen::*;
 pub use crate::startup::*;
 
+use clap::Parser;
+use commands::{run_command, Command};
+use logger::LogLevel;
+use serde_json::json;
+
+/// Console app for integration with Jira.
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Opens the company right away instead of the start screen,
+    /// commands are run with this company too.
+    #[arg(long, global = true)]
+    company: Option<String>,
+    /// Writes detailed log to `~/.rusji/logs/`,
+    /// even if logging is disabled in the config.
+    #[arg(long, global = true)]
+    verbose: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
 fn main() {
+    let args = Args::parse();
     if let Err(err) = startup() {
         println!("Something went wrong {err}");
         return;
     }
-    let config = Config::new().unwrap();
-    start_screen(config);
+    let config = match Config::new() {
+        Ok(config) => config,
+        Err(err) => {
+            println!("Something went wrong {err}");
+            return;
+        }
+    };
+    let log_level = if args.verbose {
+        Some(LogLevel::Debug)
+    } else {
+        config.get_log_level()
+    };
+    if let Some(log_level) = log_level {
+        if let Err(err) = logger::init(log_level) {
+            eprintln!("Can't open log file: {err}");
+        }
+    }
+    if let Some(command) = &args.command {
+        if let Err(err) =
+            run_command(command, &config, args.company.as_deref())
+        {
+            logger::error(
+                "command_error",
+                json!({"message": err.to_string()}),
+            );
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    if let Some(company_name) = &args.company {
+        if config.get_jira_by_company(company_name).is_err() {
+            println!("Can't find company `{company_name}` in the config");
+            return;
+        }
+    }
+    start_screen(config, args.company);
 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
-use crate::jira::screen::make_jira_screen;
-use crate::theme::make_dark_theme;
+use crate::jira::screen::{close_jira_screen, make_jira_screen, save_session};
+use crate::theme::{AppTheme, DEFAULT_THEME};
 use crate::Config;
 use cursive::view::{Nameable, Resizable};
 use cursive::views::{
@@ -27,10 +27,21 @@ impl CursiveUserData<'static> {
     }
 }
 
-pub fn start_screen(config: Config) {
+/// Runs the app.
+///
+/// `company_name` is opened right away, otherwise the company
+/// from the config is opened if the start screen is skipped.
+pub fn start_screen(config: Config, company_name: Option<String>) {
     let mut cursive = cursive::default();
-    cursive.set_theme(make_dark_theme());
-
+    // Broken user theme shouldn't block the app.
+    let app_theme = AppTheme::load(config.get_theme())
+        .ok()
+        .or_else(|| AppTheme::builtin(DEFAULT_THEME))
+        .unwrap();
+    cursive.set_theme(app_theme.theme);
+
+    let company_name = company_name
+        .or_else(|| config.get_startup_company().map(String::from));
     let c_user_data = CursiveUserData::new(config);
     cursive.set_user_data(c_user_data);
 
@@ -40,6 +51,7 @@ pub fn start_screen(config: Config) {
         SelectView::<String>::new().on_submit(on_select_company);
     exist_companies_select_view
         .add_all_str(c_user_data.config.companies_names());
+    select_last_company(&mut exist_companies_select_view, &c_user_data.config);
 
     let mut delete_exist_companies_select_view =
         SelectView::<String>::new().on_submit(delete_company);
@@ -69,7 +81,25 @@ pub fn start_screen(config: Config) {
         .padding_lrtb(1, 1, 1, 1);
 
     cursive.add_layer(start_screen_dialog.min_width(25).max_width(50));
+    if let Some(company_name) = company_name {
+        on_select_company(&mut cursive, &company_name);
+    }
     cursive.run();
+    save_session(&mut cursive);
+}
+
+/// Moves selection of the companies list to the last opened company.
+fn select_last_company(select_view: &mut SelectView, config: &Config) {
+    let last_company_idx =
+        config.get_last_company().and_then(|last_company| {
+            config
+                .companies_names()
+                .iter()
+                .position(|company_name| company_name == last_company)
+        });
+    if let Some(idx) = last_company_idx {
+        let _ = select_view.set_selection(idx);
+    }
 }
 
 fn add_new_company_screen(cursive: &mut Cursive, _: &str) {
@@ -78,6 +108,7 @@ fn add_new_company_screen(cursive: &mut Cursive, _: &str) {
         "Jira URL",
         "Jira username/login",
         "Jira Password",
+        "Jira API version (2 or 3)",
     ];
 
     let mut edit_layout = LinearLayout::vertical();
@@ -90,7 +121,7 @@ fn add_new_company_screen(cursive: &mut Cursive, _: &str) {
         edit_layout.add_child(DummyView);
     }
 
-    let mut c_user_data: &mut CursiveUserData = cursive.user_data().unwrap();
+    let c_user_data: &mut CursiveUserData = cursive.user_data().unwrap();
     c_user_data.to_find_names = views_names;
 
     let add_new_company_dialog = Dialog::new()
@@ -124,12 +155,20 @@ fn add_new_company(cursive: &mut Cursive) {
         input_company_data.get("Company name").unwrap(),
         input_company_data.get("Jira username/login").unwrap(),
         input_company_data.get("Jira Password").unwrap(),
+        input_company_data.get("Jira API version (2 or 3)").unwrap(),
     );
 
-    if is_add_success.is_ok() {
-        c_user_data.refresh_config();
-        cursive.set_user_data(c_user_data);
-        success_dialog(cursive, "Company added successfully!")
+    match is_add_success {
+        Ok(_) => {
+            c_user_data.refresh_config();
+            cursive.set_user_data(c_user_data);
+            success_dialog(cursive, "Company added successfully!")
+        }
+        Err(err) => {
+            cursive.set_user_data(c_user_data);
+            cursive
+                .add_layer(Dialog::info(format!("Can't add company: {err}")));
+        }
     }
 }
 
@@ -152,6 +191,7 @@ pub fn set_start_screen(cursive: &mut Cursive) {
         SelectView::<String>::new().on_submit(on_select_company);
     exist_companies_select_view
         .add_all_str(c_user_data.config.companies_names());
+    select_last_company(&mut exist_companies_select_view, &c_user_data.config);
 
     let mut delete_exist_companies_select_view =
         SelectView::<String>::new().on_submit(delete_company);
@@ -184,7 +224,38 @@ pub fn set_start_screen(cursive: &mut Cursive) {
     cursive.add_layer(start_screen_dialog.min_width(25).max_width(50));
 }
 
+/// Closes the Jira screen and shows the start screen.
+///
+/// Data of the closed company is kept, so it's opened again instantly.
+pub fn switch_company(cursive: &mut Cursive) {
+    let config = match Config::new() {
+        Ok(config) => config,
+        Err(err) => {
+            cursive.add_layer(Dialog::info(format!(
+                "Can't switch company: {err}"
+            )));
+            return;
+        }
+    };
+    close_jira_screen(cursive);
+    cursive.set_user_data(CursiveUserData::new(config));
+    set_start_screen(cursive);
+}
+
+/// Closes the Jira screen and opens the company instead.
+pub fn open_company(cursive: &mut Cursive, company_name: &str) {
+    close_jira_screen(cursive);
+    if let Ok(mut config) = Config::new() {
+        // Last company is only a hint, so failed save isn't critical.
+        let _ = config.set_last_company(company_name);
+    }
+    make_jira_screen(cursive, company_name);
+}
+
 fn on_select_company(cursive: &mut Cursive, company_name: &str) {
+    let c_user_data: &mut CursiveUserData = cursive.user_data().unwrap();
+    // Last company is only a hint, so failed save isn't critical.
+    let _ = c_user_data.config.set_last_company(company_name);
     cursive.pop_layer();
     make_jira_screen(cursive, company_name);
 }
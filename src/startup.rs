@@ -7,6 +7,8 @@ use std::fs::File;
 pub fn startup() -> std::io::Result<String> {
     let path_to_app_dir = build_full_app_path()?;
     create_dir_all(&path_to_app_dir)?;
+    create_dir_all(format!("{}/{}", path_to_app_dir, APP_STATE_DIRECTORY))?;
+    create_dir_all(format!("{}/{}", path_to_app_dir, APP_THEMES_DIRECTORY))?;
     create_new_app_config(&path_to_app_dir)?;
     Ok("All configuration files created successfully.".to_string())
 }
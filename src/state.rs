@@ -0,0 +1,302 @@
+use crate::jira::tasks::ordering::TaskListOrder;
+use crate::jira::tasks_actions::data::UserIdentity;
+use crate::utils::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Result;
+
+/// Max amount of recently used assignees stored for a company.
+const RECENT_ASSIGNEES_LIMIT: usize = 5;
+
+/// Max amount of recently viewed issues stored for a company.
+const RECENT_ISSUES_LIMIT: usize = 10;
+
+/// Structure for the assignee which was used recently.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecentAssignee {
+    pub identity: UserIdentity,
+    pub display_text: String,
+}
+
+impl RecentAssignee {
+    pub fn new(identity: UserIdentity, display_text: &str) -> Self {
+        Self {
+            identity,
+            display_text: display_text.into(),
+        }
+    }
+}
+
+/// Issue which was viewed recently or bookmarked.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecentIssue {
+    /// Issue key, e.g. `TST-1`.
+    pub key: String,
+    pub summary: String,
+}
+
+impl RecentIssue {
+    pub fn new(key: &str, summary: &str) -> Self {
+        Self {
+            key: key.into(),
+            summary: summary.into(),
+        }
+    }
+}
+
+/// Place in the app where the user stopped working with the company.
+///
+/// It's restored when the company is opened again.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionState {
+    /// Name of the selected project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Key of the selected issue, e.g. `TST-1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<String>,
+    /// Text of the tasks search field.
+    #[serde(default)]
+    pub search_text: String,
+    /// Selected row of the tasks list.
+    #[serde(default)]
+    pub selected_row: usize,
+}
+
+/// Local state of the company which isn't a part of the config.
+///
+/// Every company has its own file in the app state directory.
+/// State created with `default()` isn't stored anywhere.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct CompanyState {
+    #[serde(default)]
+    recent_assignees: Vec<RecentAssignee>,
+    /// Order of the tasks list by project key.
+    #[serde(default)]
+    task_list_orders: BTreeMap<String, TaskListOrder>,
+    #[serde(default)]
+    session: SessionState,
+    /// Issues opened in the task information, the last one is the first.
+    #[serde(default)]
+    recent_issues: Vec<RecentIssue>,
+    #[serde(default)]
+    bookmarks: Vec<RecentIssue>,
+    #[serde(skip_serializing, skip_deserializing)]
+    state_path: Option<String>,
+}
+
+impl CompanyState {
+    /// Loads state of the company.
+    ///
+    /// Returns empty state if the file doesn't exist or can't be read.
+    pub fn load(company_name: &str) -> Self {
+        let state_path = match build_company_state_path(company_name) {
+            Ok(path) => path,
+            Err(_) => return Self::default(),
+        };
+        let mut state = std::fs::read_to_string(&state_path)
+            .ok()
+            .and_then(|raw_state| {
+                serde_json::from_str::<CompanyState>(&raw_state).ok()
+            })
+            .unwrap_or_default();
+        state.state_path = Some(state_path);
+        state
+    }
+
+    /// Writes state to the file of the company.
+    pub fn save(&self) -> Result<()> {
+        match &self.state_path {
+            Some(path) => {
+                std::fs::write(path, serde_json::to_string_pretty(&self)?)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns recently used assignees, the last used is the first.
+    pub fn recent_assignees(&self) -> &[RecentAssignee] {
+        &self.recent_assignees
+    }
+
+    /// Moves assignee to the top of recently used assignees.
+    pub fn add_recent_assignee(&mut self, assignee: RecentAssignee) {
+        self.recent_assignees
+            .retain(|recent| recent.identity != assignee.identity);
+        self.recent_assignees.insert(0, assignee);
+        self.recent_assignees.truncate(RECENT_ASSIGNEES_LIMIT);
+    }
+
+    /// Returns order of the tasks list of the project.
+    pub fn task_list_order(&self, project_key: &str) -> TaskListOrder {
+        self.task_list_orders
+            .get(project_key)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn set_task_list_order(
+        &mut self,
+        project_key: &str,
+        order: TaskListOrder,
+    ) {
+        self.task_list_orders.insert(project_key.into(), order);
+    }
+
+    /// Returns the last session with the company.
+    pub fn session(&self) -> &SessionState {
+        &self.session
+    }
+
+    pub fn set_session(&mut self, session: SessionState) {
+        self.session = session;
+    }
+
+    /// Returns recently viewed issues, the last viewed is the first.
+    pub fn recent_issues(&self) -> &[RecentIssue] {
+        &self.recent_issues
+    }
+
+    /// Moves issue to the top of recently viewed issues.
+    ///
+    /// Returns false if the issue is already there.
+    pub fn add_recent_issue(&mut self, issue: RecentIssue) -> bool {
+        if self.recent_issues.first() == Some(&issue) {
+            return false;
+        }
+        self.recent_issues.retain(|recent| recent.key != issue.key);
+        self.recent_issues.insert(0, issue);
+        self.recent_issues.truncate(RECENT_ISSUES_LIMIT);
+        true
+    }
+
+    /// Returns bookmarked issues in the order they were added.
+    pub fn bookmarks(&self) -> &[RecentIssue] {
+        &self.bookmarks
+    }
+
+    pub fn is_bookmarked(&self, issue_key: &str) -> bool {
+        self.bookmarks
+            .iter()
+            .any(|bookmark| bookmark.key == issue_key)
+    }
+
+    /// Adds issue to bookmarks or removes it if it's already there.
+    ///
+    /// Returns true if the issue is bookmarked now.
+    pub fn toggle_bookmark(&mut self, issue: RecentIssue) -> bool {
+        if self.is_bookmarked(&issue.key) {
+            self.bookmarks.retain(|bookmark| bookmark.key != issue.key);
+            return false;
+        }
+        self.bookmarks.push(issue);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira::tasks::ordering::{TaskGroup, TaskSort};
+
+    #[test]
+    fn test_add_recent_assignee() {
+        let mut state = CompanyState::default();
+        for name in ["alice", "bob", "carol", "dave", "eve", "frank"] {
+            state.add_recent_assignee(RecentAssignee::new(
+                UserIdentity::Name(name.into()),
+                name,
+            ));
+        }
+        state.add_recent_assignee(RecentAssignee::new(
+            UserIdentity::Name("carol".into()),
+            "Carol",
+        ));
+
+        let identities: Vec<&str> = state
+            .recent_assignees()
+            .iter()
+            .map(|assignee| assignee.identity.as_str())
+            .collect();
+
+        assert_eq!(identities, vec!["carol", "frank", "eve", "dave", "bob"]);
+        assert_eq!(state.recent_assignees()[0].display_text, "Carol");
+    }
+
+    #[test]
+    fn test_add_recent_issue() {
+        let mut state = CompanyState::default();
+        for number in 1..=12 {
+            let key = format!("TST-{number}");
+            state.add_recent_issue(RecentIssue::new(&key, "Task"));
+        }
+
+        assert!(!state.add_recent_issue(RecentIssue::new("TST-12", "Task")));
+        assert!(state.add_recent_issue(RecentIssue::new("TST-5", "Login")));
+
+        let keys: Vec<&str> = state
+            .recent_issues()
+            .iter()
+            .map(|issue| issue.key.as_str())
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "TST-5", "TST-12", "TST-11", "TST-10", "TST-9", "TST-8",
+                "TST-7", "TST-6", "TST-4", "TST-3"
+            ]
+        );
+        assert_eq!(state.recent_issues()[0].summary, "Login");
+    }
+
+    #[test]
+    fn test_toggle_bookmark() {
+        let mut state = CompanyState::default();
+
+        assert!(state.toggle_bookmark(RecentIssue::new("TST-2", "Second")));
+        assert!(state.toggle_bookmark(RecentIssue::new("EMP-1", "Empty")));
+        assert!(state.is_bookmarked("TST-2"));
+        assert!(!state.toggle_bookmark(RecentIssue::new("TST-2", "Second")));
+        assert!(!state.is_bookmarked("TST-2"));
+        assert_eq!(state.bookmarks(), &[RecentIssue::new("EMP-1", "Empty")]);
+    }
+
+    #[test]
+    fn test_task_list_order() {
+        let state = serde_json::from_str::<CompanyState>(
+            r#"{"task_list_orders": {"TST": {"sort": "priority"}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            state.task_list_order("TST"),
+            TaskListOrder {
+                sort: TaskSort::Priority,
+                group: TaskGroup::None,
+            }
+        );
+        assert_eq!(state.task_list_order("EMP"), TaskListOrder::default());
+    }
+
+    #[test]
+    fn test_session() {
+        let state = serde_json::from_str::<CompanyState>(
+            r#"{"session": {"project": "Test project", "issue": "TST-2"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            state.session(),
+            &SessionState {
+                project: Some("Test project".into()),
+                issue: Some("TST-2".into()),
+                search_text: String::default(),
+                selected_row: 0,
+            }
+        );
+        assert_eq!(
+            CompanyState::default().session(),
+            &SessionState::default()
+        );
+    }
+}
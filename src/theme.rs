@@ -1,5 +1,18 @@
+use std::collections::BTreeMap;
+use std::io::{Error, Result};
+use std::path::Path;
+
 use cursive::theme::{BaseColor::*, Color::*, PaletteColor::*};
-use cursive::theme::{BorderStyle, Palette, Theme};
+use cursive::theme::{BorderStyle, Color, Palette, Theme};
+use serde::Deserialize;
+
+use crate::utils::build_themes_path;
+
+/// Theme used if the config doesn't choose one.
+pub const DEFAULT_THEME: &str = "dark";
+
+/// Names of the themes built into the app.
+pub const BUILTIN_THEMES: [&str; 3] = ["dark", "light", "high_contrast"];
 
 pub fn make_dark_theme() -> Theme {
     let mut palette = Palette::default();
@@ -22,3 +35,346 @@ pub fn make_dark_theme() -> Theme {
         palette,
     }
 }
+
+pub fn make_light_theme() -> Theme {
+    let mut palette = Palette::default();
+    let colors = vec![
+        (Background, Light(White)),
+        (Shadow, Dark(White)),
+        (View, Light(White)),
+        (Primary, Dark(Black)),
+        (Secondary, Dark(Blue)),
+        (Tertiary, Dark(Blue)),
+        (TitlePrimary, Dark(Blue)),
+        (TitleSecondary, Dark(Blue)),
+        (Highlight, Light(Blue)),
+        (HighlightInactive, Dark(White)),
+        (HighlightText, Dark(Black)),
+    ];
+    palette.extend(colors);
+    Theme {
+        shadow: true,
+        borders: BorderStyle::Simple,
+        palette,
+    }
+}
+
+/// Theme with black background and bright colors only.
+pub fn make_high_contrast_theme() -> Theme {
+    let mut palette = Palette::default();
+    let colors = vec![
+        (Background, Dark(Black)),
+        (Shadow, Dark(Black)),
+        (View, Dark(Black)),
+        (Primary, Light(White)),
+        (Secondary, Light(White)),
+        (Tertiary, Light(Yellow)),
+        (TitlePrimary, Light(Yellow)),
+        (TitleSecondary, Light(Yellow)),
+        (Highlight, Light(White)),
+        (HighlightInactive, Light(Black)),
+        (HighlightText, Dark(Black)),
+    ];
+    palette.extend(colors);
+    Theme {
+        shadow: false,
+        borders: BorderStyle::Outset,
+        palette,
+    }
+}
+
+/// Colors of the issue indicators: statuses, priorities and issue types.
+///
+/// Indicators have text or glyphs too, so they are readable
+/// on terminals without colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SemanticColors {
+    pub status_to_do: Color,
+    pub status_in_progress: Color,
+    pub status_done: Color,
+    pub priority_high: Color,
+    pub priority_medium: Color,
+    pub priority_low: Color,
+    pub issue_bug: Color,
+    pub assignee: Color,
+}
+
+impl Default for SemanticColors {
+    /// Colors for the dark theme.
+    fn default() -> Self {
+        Self {
+            status_to_do: Light(Blue),
+            status_in_progress: Light(Yellow),
+            status_done: Light(Green),
+            priority_high: Light(Red),
+            priority_medium: Light(Yellow),
+            priority_low: Light(Cyan),
+            issue_bug: Light(Red),
+            assignee: Dark(Cyan),
+        }
+    }
+}
+
+impl SemanticColors {
+    pub fn light() -> Self {
+        Self {
+            status_to_do: Dark(Blue),
+            status_in_progress: Dark(Magenta),
+            status_done: Dark(Green),
+            priority_high: Dark(Red),
+            priority_medium: Dark(Yellow),
+            priority_low: Dark(Cyan),
+            issue_bug: Dark(Red),
+            assignee: Dark(Blue),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            status_to_do: Light(Cyan),
+            status_in_progress: Light(Yellow),
+            status_done: Light(Green),
+            priority_high: Light(Red),
+            priority_medium: Light(Yellow),
+            priority_low: Light(White),
+            issue_bug: Light(Red),
+            assignee: Light(Magenta),
+        }
+    }
+
+    /// Sets color by its name in the theme file, e.g. `status_done`.
+    fn set_color(&mut self, name: &str, color: Color) -> Result<()> {
+        let field = match name {
+            "status_to_do" => &mut self.status_to_do,
+            "status_in_progress" => &mut self.status_in_progress,
+            "status_done" => &mut self.status_done,
+            "priority_high" => &mut self.priority_high,
+            "priority_medium" => &mut self.priority_medium,
+            "priority_low" => &mut self.priority_low,
+            "issue_bug" => &mut self.issue_bug,
+            "assignee" => &mut self.assignee,
+            _ => return Err(Error::other(format!("Unknown color `{name}`"))),
+        };
+        *field = color;
+        Ok(())
+    }
+}
+
+/// Theme of the app: cursive theme and colors of the indicators.
+#[derive(Debug, Clone)]
+pub struct AppTheme {
+    pub theme: Theme,
+    pub colors: SemanticColors,
+}
+
+impl AppTheme {
+    /// Returns built-in theme by its name.
+    pub fn builtin(theme_name: &str) -> Option<Self> {
+        let (theme, colors) = match theme_name {
+            "dark" => (make_dark_theme(), SemanticColors::default()),
+            "light" => (make_light_theme(), SemanticColors::light()),
+            "high_contrast" => {
+                (make_high_contrast_theme(), SemanticColors::high_contrast())
+            }
+            _ => return None,
+        };
+        Some(Self { theme, colors })
+    }
+
+    /// Loads theme by its name.
+    ///
+    /// User theme is read from `<name>.toml` or `<name>.json`
+    /// in the themes directory, built-in themes can't be overridden.
+    pub fn load(theme_name: &str) -> Result<Self> {
+        if let Some(theme) = Self::builtin(theme_name) {
+            return Ok(theme);
+        }
+        let themes_path = build_themes_path()?;
+        let read_theme = |extension: &str| {
+            let theme_path = Path::new(&themes_path)
+                .join(format!("{theme_name}.{extension}"));
+            std::fs::read_to_string(theme_path).ok()
+        };
+        let app_theme = if let Some(raw_theme) = read_theme("toml") {
+            Self::from_toml(&raw_theme)
+        } else if let Some(raw_theme) = read_theme("json") {
+            Self::from_json(&raw_theme)
+        } else {
+            return Err(Error::other(format!(
+                "Can't find theme `{theme_name}`"
+            )));
+        };
+        app_theme.map_err(|err| {
+            Error::other(format!("Can't read theme `{theme_name}`: {err}"))
+        })
+    }
+
+    /// Parses user theme in JSON, see `ThemeFile` for the format.
+    pub fn from_json(raw_theme: &str) -> Result<Self> {
+        Self::from_theme_file(serde_json::from_str::<ThemeFile>(raw_theme)?)
+    }
+
+    /// Parses user theme in TOML, see `ThemeFile` for the format.
+    pub fn from_toml(raw_theme: &str) -> Result<Self> {
+        let theme_file =
+            toml::from_str::<ThemeFile>(raw_theme).map_err(Error::other)?;
+        Self::from_theme_file(theme_file)
+    }
+
+    fn from_theme_file(theme_file: ThemeFile) -> Result<Self> {
+        let base_name = theme_file.base.as_deref().unwrap_or(DEFAULT_THEME);
+        let mut app_theme = Self::builtin(base_name).ok_or_else(|| {
+            Error::other(format!("Unknown base theme `{base_name}`"))
+        })?;
+
+        if let Some(shadow) = theme_file.shadow {
+            app_theme.theme.shadow = shadow;
+        }
+        if let Some(borders) = theme_file.borders {
+            app_theme.theme.borders = BorderStyle::from(&borders);
+        }
+        for (name, raw_color) in &theme_file.palette {
+            let color = parse_color(raw_color)?;
+            app_theme
+                .theme
+                .palette
+                .set_basic_color(name, color)
+                .map_err(|_| {
+                    Error::other(format!("Unknown palette color `{name}`"))
+                })?;
+        }
+        for (name, raw_color) in &theme_file.colors {
+            app_theme.colors.set_color(name, parse_color(raw_color)?)?;
+        }
+        Ok(app_theme)
+    }
+
+    /// Returns names of built-in and user themes.
+    pub fn available_names() -> Vec<String> {
+        let mut user_themes: Vec<String> = build_themes_path()
+            .and_then(std::fs::read_dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| {
+                        let path = entry.ok()?.path();
+                        let extension = path.extension()?;
+                        if extension != "toml" && extension != "json" {
+                            return None;
+                        }
+                        Some(path.file_stem()?.to_string_lossy().into_owned())
+                    })
+                    .filter(|name| !BUILTIN_THEMES.contains(&name.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        user_themes.sort();
+        user_themes.dedup();
+
+        BUILTIN_THEMES
+            .iter()
+            .map(|name| name.to_string())
+            .chain(user_themes)
+            .collect()
+    }
+}
+
+/// User theme from the themes directory.
+///
+/// Every field is optional, missing ones are taken from the base theme:
+/// ```toml
+/// base = "light"
+/// borders = "outset"
+///
+/// [palette]
+/// highlight = "#005f87"
+/// primary = "black"
+///
+/// [colors]
+/// status_done = "light green"
+/// ```
+/// The same fields are used in JSON.
+/// Colors are names like `red` or `light red`, `default` or `#rrggbb`.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    /// Name of the built-in theme to start from, `dark` by default.
+    base: Option<String>,
+    shadow: Option<bool>,
+    /// `simple`, `outset` or `none`.
+    borders: Option<String>,
+    /// Cursive palette colors, e.g. `background` or `title_primary`.
+    #[serde(default)]
+    palette: BTreeMap<String, String>,
+    /// Colors of the indicators, see `SemanticColors`.
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+}
+
+fn parse_color(raw_color: &str) -> Result<Color> {
+    // Cursive panics on 3 chars which aren't digits.
+    let is_low_res = raw_color.len() == 3;
+    if is_low_res && !raw_color.chars().all(|char| char.is_ascii_digit()) {
+        return Err(Error::other(format!("Unknown color `{raw_color}`")));
+    }
+    Color::parse(&raw_color.to_lowercase())
+        .ok_or_else(|| Error::other(format!("Unknown color `{raw_color}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_from_json() {
+        let app_theme = AppTheme::from_json(
+            r##"{
+                "base": "light",
+                "borders": "none",
+                "palette": {"highlight": "#005f87"},
+                "colors": {"status_done": "light green"}
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(app_theme.theme.borders, BorderStyle::None);
+        assert_eq!(app_theme.theme.palette[Highlight], Rgb(0x00, 0x5f, 0x87));
+        assert_eq!(app_theme.theme.palette[Primary], Dark(Black));
+        assert_eq!(app_theme.colors.status_done, Light(Green));
+        assert_eq!(app_theme.colors.status_to_do, Dark(Blue));
+    }
+
+    #[test]
+    fn test_theme_from_toml() {
+        let app_theme = AppTheme::from_toml(
+            r##"
+                shadow = false
+
+                [palette]
+                background = "black"
+
+                [colors]
+                priority_high = "#ff0000"
+            "##,
+        )
+        .unwrap();
+
+        assert!(!app_theme.theme.shadow);
+        assert_eq!(app_theme.theme.palette[Background], Dark(Black));
+        assert_eq!(app_theme.colors.priority_high, Rgb(0xff, 0x00, 0x00));
+        assert_eq!(app_theme.colors.status_done, Light(Green));
+        assert!(AppTheme::from_toml("font = \"mono\"").is_err());
+    }
+
+    #[test]
+    fn test_theme_from_json_errors() {
+        for raw_theme in [
+            r#"{"base": "neon"}"#,
+            r#"{"palette": {"glow": "red"}}"#,
+            r#"{"colors": {"status_done": "greenish"}}"#,
+            r#"{"colors": {"status_done": "abc"}}"#,
+            r#"{"font": "mono"}"#,
+        ] {
+            assert!(AppTheme::from_json(raw_theme).is_err(), "{raw_theme}");
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::constance::*;
 use home::home_dir;
 use std::io::{Error, ErrorKind, Result};
+use std::ops::Range;
 
 pub fn build_full_app_path() -> Result<String> {
     let home_dir = home_dir();
@@ -13,9 +14,109 @@ pub fn build_full_app_path() -> Result<String> {
     }
 }
 
+/// Builds path to the file with local state of the company.
+///
+/// See `company_file_name` about the name of the file.
+pub fn build_company_state_path(company_name: &str) -> Result<String> {
+    Ok(format!(
+        "{}/{}/{}.json",
+        build_full_app_path()?,
+        APP_STATE_DIRECTORY,
+        company_file_name(company_name)
+    ))
+}
+
+/// Percent-encodes company name, so it can be used as a file name.
+///
+/// ASCII letters, digits, `-` and `_` are kept, other bytes become
+/// `%XX`, so different names never share a file.
+fn company_file_name(company_name: &str) -> String {
+    company_name
+        .bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Builds path to the directory with user themes.
+pub fn build_themes_path() -> Result<String> {
+    Ok(format!(
+        "{}/{}",
+        build_full_app_path()?,
+        APP_THEMES_DIRECTORY
+    ))
+}
+
+/// Builds path to the directory with log files.
+pub fn build_logs_path() -> Result<String> {
+    Ok(format!("{}/{}", build_full_app_path()?, APP_LOGS_DIRECTORY))
+}
+
 pub fn build_app_config_path() -> Result<String> {
     match build_full_app_path() {
         Ok(path) => Ok(format!("{}/{}", path, APP_CONFIG,)),
         Err(err) => Err(err),
     }
 }
+
+/// Finds the first issue key in the text and returns its byte range.
+///
+/// Key is a word in upper case which starts with a letter,
+/// followed by `-` and a number, like keys of Jira issues.
+pub fn find_issue_key(text: &str) -> Option<Range<usize>> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let is_key_char = |char: char| {
+        char.is_ascii_uppercase() || char.is_ascii_digit() || char == '_'
+    };
+    let is_word_char = |char: char| char.is_alphanumeric() || char == '_';
+    let mut start = 0;
+    while start < chars.len() {
+        let is_word_start = start == 0 || !is_word_char(chars[start - 1].1);
+        if !is_word_start || !chars[start].1.is_ascii_uppercase() {
+            start += 1;
+            continue;
+        }
+        let mut dash = start;
+        while dash < chars.len() && is_key_char(chars[dash].1) {
+            dash += 1;
+        }
+        let mut end = dash + 1;
+        while end < chars.len() && chars[end].1.is_ascii_digit() {
+            end += 1;
+        }
+        let is_key = dash < chars.len()
+            && chars[dash].1 == '-'
+            && end > dash + 1
+            && (end == chars.len() || !is_word_char(chars[end].1));
+        if is_key {
+            let end_idx = chars.get(end).map_or(text.len(), |(idx, _)| *idx);
+            return Some(chars[start].0..end_idx);
+        }
+        start = dash.max(start + 1);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_company_file_name() {
+        assert_eq!(company_file_name("Acme_Inc-2"), "Acme_Inc-2");
+        assert_eq!(company_file_name("Acme Inc"), "Acme%20Inc");
+        assert_eq!(company_file_name("Acme.Inc"), "Acme%2EInc");
+        assert_eq!(company_file_name("../Ö"), "%2E%2E%2F%C3%96");
+    }
+
+    #[test]
+    fn test_find_issue_key() {
+        assert_eq!(find_issue_key("See TST-12."), Some(4..10));
+        assert_eq!(find_issue_key("Ö TST-1"), Some(3..8));
+        assert_eq!(find_issue_key("xTST-1 TST-2x"), None);
+    }
+}